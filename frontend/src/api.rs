@@ -84,7 +84,7 @@ pub fn inferred_github_repo_urls() -> Option<(String, String)> {
 
     if first_segment.is_empty() || matches!(
         first_segment,
-        "depute" | "comparer" | "exporter" | "methodologie" | "stats-globales" | "reseau" | "positions-groupes" | "index.html"
+        "depute" | "scrutin" | "scrutins" | "groupe" | "comparer" | "exporter" | "methodologie" | "stats-globales" | "reseau" | "positions-groupes" | "index.html"
     ) {
         return None;
     }
@@ -359,6 +359,257 @@ pub async fn fetch_dossiers_min_v2() -> Result<DossiersMin, ApiError> {
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Scrutins — index compact + détail par scrutin
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub async fn fetch_scrutins_index_v2() -> Result<ScrutinsIndex, ApiError> {
+    let url = format!("{}/data/scrutins/index.json", base_url());
+    let resp = Request::get(&url)
+        .send().await
+        .map_err(|e| ApiError::NetworkError(e.to_string()))?;
+
+    let code = resp.status() as u16;
+    match code {
+        404 => Err(ApiError::NotFound("scrutins/index.json".to_string())),
+        code if code >= 500 => Err(ApiError::ServerError(code, "HTTP error".to_string())),
+        code if code >= 400 => Err(ApiError::ServerError(code, format!("HTTP {}", code))),
+        _ if is_spa_fallback(&resp) => Err(ApiError::NotFound("scrutins/index.json".to_string())),
+        _ => resp
+            .json::<ScrutinsIndex>()
+            .await
+            .map_err(|e| ApiError::ParseError(e.to_string())),
+    }
+}
+
+pub async fn fetch_scrutin_detail_v2(scrutin_id: &str) -> Result<ScrutinDetail, ApiError> {
+    let file = safe_file_stem_client(scrutin_id);
+    if file.is_empty() {
+        return Err(ApiError::Other(format!("Identifiant de scrutin invalide: {scrutin_id}")));
+    }
+    let url = format!("{}/data/scrutins/detail/{}.json", base_url(), file);
+    let resp = Request::get(&url)
+        .send().await
+        .map_err(|e| ApiError::NetworkError(e.to_string()))?;
+
+    let code = resp.status() as u16;
+    match code {
+        404 => Err(ApiError::NotFound(format!("scrutins/detail/{file}.json"))),
+        code if code >= 500 => Err(ApiError::ServerError(code, "HTTP error".to_string())),
+        code if code >= 400 => Err(ApiError::ServerError(code, format!("HTTP {}", code))),
+        _ if is_spa_fallback(&resp) => Err(ApiError::NotFound(format!("scrutins/detail/{file}.json"))),
+        _ => resp
+            .json::<ScrutinDetail>()
+            .await
+            .map_err(|e| ApiError::ParseError(e.to_string())),
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Groupes — fiche par groupe parlementaire
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub async fn fetch_groupe_detail_v2(groupe_id: &str) -> Result<GroupeDetail, ApiError> {
+    let file = safe_file_stem_client(groupe_id);
+    if file.is_empty() {
+        return Err(ApiError::Other(format!("Identifiant de groupe invalide: {groupe_id}")));
+    }
+    let url = format!("{}/data/groupes/{}.json", base_url(), file);
+    let resp = Request::get(&url)
+        .send().await
+        .map_err(|e| ApiError::NetworkError(e.to_string()))?;
+
+    let code = resp.status() as u16;
+    match code {
+        404 => Err(ApiError::NotFound(format!("groupes/{file}.json"))),
+        code if code >= 500 => Err(ApiError::ServerError(code, "HTTP error".to_string())),
+        code if code >= 400 => Err(ApiError::ServerError(code, format!("HTTP {}", code))),
+        _ if is_spa_fallback(&resp) => Err(ApiError::NotFound(format!("groupes/{file}.json"))),
+        _ => resp
+            .json::<GroupeDetail>()
+            .await
+            .map_err(|e| ApiError::ParseError(e.to_string())),
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Séries hebdomadaires — par député + aperçu pour le tableau d'accueil
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub async fn fetch_series_index_v2() -> Result<SeriesIndex, ApiError> {
+    let url = format!("{}/data/series/index.json", base_url());
+    let resp = Request::get(&url)
+        .send().await
+        .map_err(|e| ApiError::NetworkError(e.to_string()))?;
+
+    let code = resp.status() as u16;
+    match code {
+        404 => Err(ApiError::NotFound("series/index.json".to_string())),
+        code if code >= 500 => Err(ApiError::ServerError(code, "HTTP error".to_string())),
+        code if code >= 400 => Err(ApiError::ServerError(code, format!("HTTP {}", code))),
+        _ if is_spa_fallback(&resp) => Err(ApiError::NotFound("series/index.json".to_string())),
+        _ => resp
+            .json::<SeriesIndex>()
+            .await
+            .map_err(|e| ApiError::ParseError(e.to_string())),
+    }
+}
+
+pub async fn fetch_depute_series_v2(deputy_id: &str) -> Result<DeputeSeries, ApiError> {
+    let file = safe_file_stem_client(deputy_id);
+    if file.is_empty() {
+        return Err(ApiError::Other(format!("Identifiant de député invalide: {deputy_id}")));
+    }
+    let url = format!("{}/data/series/deputes/{}.json", base_url(), file);
+    let resp = Request::get(&url)
+        .send().await
+        .map_err(|e| ApiError::NetworkError(e.to_string()))?;
+
+    let code = resp.status() as u16;
+    match code {
+        404 => Err(ApiError::NotFound(format!("series/deputes/{file}.json"))),
+        code if code >= 500 => Err(ApiError::ServerError(code, "HTTP error".to_string())),
+        code if code >= 400 => Err(ApiError::ServerError(code, format!("HTTP {}", code))),
+        _ if is_spa_fallback(&resp) => Err(ApiError::NotFound(format!("series/deputes/{file}.json"))),
+        _ => resp
+            .json::<DeputeSeries>()
+            .await
+            .map_err(|e| ApiError::ParseError(e.to_string())),
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Questions au Gouvernement — une liste par député
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub async fn fetch_depute_questions_v2(deputy_id: &str) -> Result<DeputeQuestions, ApiError> {
+    let file = safe_file_stem_client(deputy_id);
+    if file.is_empty() {
+        return Err(ApiError::Other(format!("Identifiant de député invalide: {deputy_id}")));
+    }
+    let url = format!("{}/data/questions/deputes/{}.json", base_url(), file);
+    let resp = Request::get(&url)
+        .send().await
+        .map_err(|e| ApiError::NetworkError(e.to_string()))?;
+
+    let code = resp.status() as u16;
+    match code {
+        404 => Err(ApiError::NotFound(format!("questions/deputes/{file}.json"))),
+        code if code >= 500 => Err(ApiError::ServerError(code, "HTTP error".to_string())),
+        code if code >= 400 => Err(ApiError::ServerError(code, format!("HTTP {}", code))),
+        _ if is_spa_fallback(&resp) => Err(ApiError::NotFound(format!("questions/deputes/{file}.json"))),
+        _ => resp
+            .json::<DeputeQuestions>()
+            .await
+            .map_err(|e| ApiError::ParseError(e.to_string())),
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Compteurs journaliers — fenêtres [début, fin] personnalisées
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub async fn fetch_daily_activity_v2() -> Result<DailyActivityFile, ApiError> {
+    let url = format!("{}/{}", base_url(), Period::Custom.json_file());
+    let resp = Request::get(&url)
+        .send().await
+        .map_err(|e| ApiError::NetworkError(e.to_string()))?;
+
+    let code = resp.status() as u16;
+    match code {
+        404 => Err(ApiError::NotFound(Period::Custom.json_file().to_string())),
+        code if code >= 500 => Err(ApiError::ServerError(code, "HTTP error".to_string())),
+        code if code >= 400 => Err(ApiError::ServerError(code, format!("HTTP {}", code))),
+        _ if is_spa_fallback(&resp) => Err(ApiError::NotFound(Period::Custom.json_file().to_string())),
+        _ => resp
+            .json::<DailyActivityFile>()
+            .await
+            .map_err(|e| ApiError::ParseError(e.to_string())),
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Sessions parlementaires — index + DeputeStats par session
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub async fn fetch_sessions_index_v2() -> Result<SessionsIndex, ApiError> {
+    let url = format!("{}/data/sessions/index.json", base_url());
+    let resp = Request::get(&url)
+        .send().await
+        .map_err(|e| ApiError::NetworkError(e.to_string()))?;
+
+    let code = resp.status() as u16;
+    match code {
+        404 => Err(ApiError::NotFound("sessions/index.json".to_string())),
+        code if code >= 500 => Err(ApiError::ServerError(code, "HTTP error".to_string())),
+        code if code >= 400 => Err(ApiError::ServerError(code, format!("HTTP {}", code))),
+        _ if is_spa_fallback(&resp) => Err(ApiError::NotFound("sessions/index.json".to_string())),
+        _ => resp
+            .json::<SessionsIndex>()
+            .await
+            .map_err(|e| ApiError::ParseError(e.to_string())),
+    }
+}
+
+pub async fn fetch_session_stats_v2(session_id: &str) -> Result<Vec<DeputeStats>, ApiError> {
+    // Identifiants générés par le pipeline ("SO-2024-2025", "SE-2025")
+    if session_id.is_empty() || !session_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err(ApiError::Other(format!("Identifiant de session invalide: {session_id}")));
+    }
+    let url = format!("{}/data/sessions/deputes_{}.json", base_url(), session_id);
+    let resp = Request::get(&url)
+        .send().await
+        .map_err(|e| ApiError::NetworkError(e.to_string()))?;
+
+    let code = resp.status() as u16;
+    match code {
+        404 => Err(ApiError::NotFound(format!("sessions/deputes_{session_id}.json"))),
+        code if code >= 500 => Err(ApiError::ServerError(code, "HTTP error".to_string())),
+        code if code >= 400 => Err(ApiError::ServerError(code, format!("HTTP {}", code))),
+        _ if is_spa_fallback(&resp) => Err(ApiError::NotFound(format!("sessions/deputes_{session_id}.json"))),
+        _ => resp
+            .json::<Vec<DeputeStats>>()
+            .await
+            .map_err(|e| ApiError::ParseError(e.to_string())),
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Manifeste — compatibilité entre les données déployées et ce build
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub async fn fetch_manifest_v2() -> Result<Manifest, ApiError> {
+    let url = format!("{}/data/{}", base_url(), MANIFEST_FILE);
+    let resp = Request::get(&url)
+        .send().await
+        .map_err(|e| ApiError::NetworkError(e.to_string()))?;
+
+    let code = resp.status() as u16;
+    match code {
+        404 => Err(ApiError::NotFound(MANIFEST_FILE.to_string())),
+        code if code >= 500 => Err(ApiError::ServerError(code, "HTTP error".to_string())),
+        code if code >= 400 => Err(ApiError::ServerError(code, format!("HTTP {}", code))),
+        _ if is_spa_fallback(&resp) => Err(ApiError::NotFound(MANIFEST_FILE.to_string())),
+        _ => resp
+            .json::<Manifest>()
+            .await
+            .map_err(|e| ApiError::ParseError(e.to_string())),
+    }
+}
+
+/// Vérifie au démarrage que les données suivent la version de contrat compilée dans ce build
+/// (cas typique : données fraîches déployées sur un `dist/` plus ancien).
+pub async fn check_data_compat() -> DataCompat {
+    match fetch_manifest_v2().await {
+        Ok(m) if m.is_compatible() => DataCompat::Compatible,
+        Ok(m) => DataCompat::Incompatible { attendu: DATA_SCHEMA_VERSION, trouve: Some(m.schema_version) },
+        // Manifeste illisible : son propre format a changé
+        Err(ApiError::ParseError(_)) => DataCompat::Incompatible { attendu: DATA_SCHEMA_VERSION, trouve: None },
+        Err(ApiError::NotFound(_)) => DataCompat::ManifestAbsent,
+        Err(_) => DataCompat::Inconnu,
+    }
+}
+
 // ============= ANCIENNES FONCTIONS (pour compatibilité) =============
 // Ces fonctions appellent les v2 et convertissent ApiError en String
 // Aucun code existant ne doit changer
@@ -399,6 +650,42 @@ pub async fn fetch_dossiers_min() -> Result<DossiersMin, String> {
     fetch_dossiers_min_v2().await.map_err(|e| e.to_string())
 }
 
+pub async fn fetch_scrutins_index() -> Result<ScrutinsIndex, String> {
+    fetch_scrutins_index_v2().await.map_err(|e| e.to_string())
+}
+
+pub async fn fetch_scrutin_detail(scrutin_id: &str) -> Result<ScrutinDetail, String> {
+    fetch_scrutin_detail_v2(scrutin_id).await.map_err(|e| e.to_string())
+}
+
+pub async fn fetch_groupe_detail(groupe_id: &str) -> Result<GroupeDetail, String> {
+    fetch_groupe_detail_v2(groupe_id).await.map_err(|e| e.to_string())
+}
+
+pub async fn fetch_series_index() -> Result<SeriesIndex, String> {
+    fetch_series_index_v2().await.map_err(|e| e.to_string())
+}
+
+pub async fn fetch_depute_series(deputy_id: &str) -> Result<DeputeSeries, String> {
+    fetch_depute_series_v2(deputy_id).await.map_err(|e| e.to_string())
+}
+
+pub async fn fetch_depute_questions(deputy_id: &str) -> Result<DeputeQuestions, String> {
+    fetch_depute_questions_v2(deputy_id).await.map_err(|e| e.to_string())
+}
+
+pub async fn fetch_daily_activity() -> Result<DailyActivityFile, String> {
+    fetch_daily_activity_v2().await.map_err(|e| e.to_string())
+}
+
+pub async fn fetch_sessions_index() -> Result<SessionsIndex, String> {
+    fetch_sessions_index_v2().await.map_err(|e| e.to_string())
+}
+
+pub async fn fetch_session_stats(session_id: String) -> Result<Vec<DeputeStats>, String> {
+    fetch_session_stats_v2(&session_id).await.map_err(|e| e.to_string())
+}
+
 fn safe_file_stem_client(raw: &str) -> String {
     let mut out = String::with_capacity(raw.len());
     let mut prev_dash = false;
@@ -416,14 +703,17 @@ fn safe_file_stem_client(raw: &str) -> String {
     out.trim_matches('-').to_string()
 }
 
-/// Génère un CSV depuis les stats en mémoire (évite la dépendance aux fichiers CSV statiques sur mobile)
-pub fn stats_to_csv(stats: &[DeputeStats]) -> String {
+/// Génère un CSV depuis les stats en mémoire (évite la dépendance aux fichiers CSV statiques sur mobile).
+/// Sur une période personnalisée, les rapports (non dérivables des compteurs journaliers) restent vides.
+pub fn stats_to_csv(stats: &[DeputeStats], period: Period) -> String {
+    let rapports = |n: u32| if period == Period::Custom { String::new() } else { n.to_string() };
     let mut out = String::with_capacity(stats.len() * 200);
-    out.push_str("deputy_id,nom,prenom,groupe_abrev,groupe_nom,parti_rattachement,dept,circo,period_start,period_end,scrutins_eligibles,votes_exprimes,non_votant,absent,participation_rate,pour_count,contre_count,abst_count,amd_authored,amd_adopted,amd_adoption_rate,amd_cosigned,interventions_count,interventions_chars,top_dossier_id,top_dossier_titre,top_dossier_score\n");
+    out.push_str("deputy_id,nom,prenom,groupe_abrev,groupe_nom,parti_rattachement,dept,circo,period_start,period_end,scrutins_eligibles,votes_exprimes,non_votant,absent,participation_rate,pour_count,contre_count,abst_count,amd_authored,amd_adopted,amd_adoption_rate,amd_cosigned,interventions_count,interventions_chars,top_dossier_id,top_dossier_titre,top_dossier_score,loyalty_votes,dissent_count,loyalty_rate,scrutins_solennels,votes_solennels_exprimes,participation_rate_solennel,scrutins_ordinaires,votes_ordinaires_exprimes,participation_rate_ordinaire,mises_au_point,votes_par_delegation,questions_ecrites,questions_orales,questions_gouvernement,questions_repondues,questions_answer_rate,commission_permanente,commission_qualite,commission_reunions,commission_presences,commission_excuses,commission_presence_rate,rapporteur_fond,rapporteur_avis,rapporteur_special\n");
     for s in stats {
         let top = s.top_dossiers.first();
+        let commission = commission_permanente(&s.commissions);
         out.push_str(&format!(
-            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{:.4},{},{},{},{},{},{},{},{},{},{},{},{}\n",
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{:.4},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}\n",
             s.deputy_id,
             csv_escape(&s.nom), csv_escape(&s.prenom),
             csv_opt(&s.groupe_abrev), csv_opt(&s.groupe_nom),
@@ -441,6 +731,21 @@ pub fn stats_to_csv(stats: &[DeputeStats]) -> String {
             top.map(|t| t.dossier_id.as_str()).unwrap_or(""),
             top.map(|t| csv_escape(&t.titre)).unwrap_or_default(),
             top.map(|t| t.score.to_string()).unwrap_or_default(),
+            s.loyalty_votes, s.dissent_count,
+            s.loyalty_rate.map(|r| format!("{r:.4}")).unwrap_or_default(),
+            s.scrutins_solennels, s.votes_solennels_exprimes,
+            s.participation_rate_solennel.map(|r| format!("{r:.4}")).unwrap_or_default(),
+            s.scrutins_ordinaires, s.votes_ordinaires_exprimes,
+            s.participation_rate_ordinaire.map(|r| format!("{r:.4}")).unwrap_or_default(),
+            s.mises_au_point, s.votes_par_delegation,
+            s.questions_ecrites, s.questions_orales, s.questions_gouvernement,
+            s.questions_repondues,
+            s.questions_answer_rate.map(|r| format!("{r:.4}")).unwrap_or_default(),
+            commission.map(|c| csv_escape(&c.libelle)).unwrap_or_default(),
+            commission.map(|c| csv_escape(&c.qualite)).unwrap_or_default(),
+            s.commission_reunions, s.commission_presences, s.commission_excuses,
+            s.commission_presence_rate.map(|r| format!("{r:.4}")).unwrap_or_default(),
+            rapports(s.rapporteur_fond), rapports(s.rapporteur_avis), rapports(s.rapporteur_special),
         ));
     }
     out
@@ -2,6 +2,10 @@ use leptos::*;
 use leptos_router::*;
 
 use crate::api::{fetch_status, inferred_github_repo_urls};
+use crate::models::DataCompat;
+use crate::store::use_store;
+use crate::utils::legislature_label;
+
 #[component]
 pub fn Layout(children: Children) -> impl IntoView {
     let status_res = create_resource(|| (), |_| fetch_status());
@@ -11,6 +15,14 @@ pub fn Layout(children: Children) -> impl IntoView {
     let (theme, set_theme) = create_signal(String::from("dark"));
     let (mobile_nav_open, set_mobile_nav_open) = create_signal(false);
 
+    // Données incompatibles avec ce build : bandeau à la place des pages (évite les erreurs de parsing)
+    let data_compat = use_store().data_compat;
+    let incompatible = create_memo(move |_| match data_compat.get() {
+        Some(DataCompat::Incompatible { attendu, trouve }) => Some((attendu, trouve)),
+        _ => None,
+    });
+    let content = children();
+
     let toggle_theme = move |_| {
         let next = if theme.get() == "dark" {
             "light"
@@ -59,7 +71,9 @@ pub fn Layout(children: Children) -> impl IntoView {
                             </span>
                             <span style="font-weight:700;font-size:0.95rem;color:var(--text-primary)">Activité Députés</span>
                             <span class="desktop-only" style="font-size:0.62rem;padding:0.12rem 0.35rem;border-radius:999px;border:1px solid rgba(245,158,11,.35);color:var(--warning);background:rgba(245,158,11,.08);font-weight:600;">"BETA"</span>
-                            <span class="desktop-only" style="font-size:0.65rem;color:var(--text-muted);font-weight:400;margin-top:2px;">"17e législature"</span>
+                            <span class="desktop-only" style="font-size:0.65rem;color:var(--text-muted);font-weight:400;margin-top:2px;">
+                                {move || status_res.get().and_then(|r| r.ok()).map(|s| legislature_label(s.legislature))}
+                            </span>
                         </A>
                         <nav class="desktop-nav" style="display:flex;gap:0;padding-left:1rem;border-left:1px solid var(--bg-border);" aria-label="Navigation principale">
                             <NavLink path=crate::app_path!("/home") label="Accueil" />
@@ -67,6 +81,7 @@ pub fn Layout(children: Children) -> impl IntoView {
                             <NavLink path=crate::app_path!("/exporter") label="Exporter" />
                             <NavLink path=crate::app_path!("/stats-globales") label="Stats globales" />
                             <NavLink path=crate::app_path!("/amendements") label="Amendements" />
+                            <NavLink path=crate::app_path!("/scrutins") label="Scrutins" />
                             <NavLink path=crate::app_path!("/reseau") label="Réseau" />
                             <NavLink path=crate::app_path!("/positions-groupes") label="Positions groupes" />
                             <NavLink path=crate::app_path!("/methodologie") label="Méthode & Sources" />
@@ -102,6 +117,7 @@ pub fn Layout(children: Children) -> impl IntoView {
                         <NavLink path=crate::app_path!("/exporter") label="Exporter" />
                         <NavLink path=crate::app_path!("/stats-globales") label="Stats globales" />
                         <NavLink path=crate::app_path!("/amendements") label="Amendements" />
+                        <NavLink path=crate::app_path!("/scrutins") label="Scrutins" />
                         <NavLink path=crate::app_path!("/reseau") label="Réseau" />
                         <NavLink path=crate::app_path!("/positions-groupes") label="Positions groupes" />
                         <NavLink path=crate::app_path!("/methodologie") label="Méthode & Sources" />
@@ -110,7 +126,10 @@ pub fn Layout(children: Children) -> impl IntoView {
             </header>
 
             <main style="flex:1;max-width:1400px;margin:0 auto;padding:1.5rem;width:100%;">
-                {children()}
+                {move || match incompatible.get() {
+                    Some((attendu, trouve)) => view! { <DataIncompatibleBanner attendu=attendu trouve=trouve /> }.into_view(),
+                    None => content.clone().into_view(),
+                }}
             </main>
 
             <footer style="border-top:1px solid var(--bg-border);padding:1.1rem 1.5rem;text-align:center;background:var(--bg-secondary);">
@@ -166,3 +185,19 @@ fn NavLink(path: &'static str, label: &'static str) -> impl IntoView {
         </A>
     }
 }
+
+#[component]
+fn DataIncompatibleBanner(attendu: u32, trouve: Option<u32>) -> impl IntoView {
+    let trouve_label = trouve.map(|v| format!("v{v}")).unwrap_or_else(|| "illisible".to_string());
+    view! {
+        <div role="alert" style="max-width:640px;margin:3rem auto;padding:1.25rem 1.5rem;background:var(--bg-secondary);border:1px solid var(--danger);border-left:4px solid var(--danger);border-radius:10px;">
+            <h1 style="font-size:1.15rem;font-weight:700;margin:0 0 0.6rem 0;color:var(--danger);">"Données incompatibles"</h1>
+            <p style="margin:0 0 0.6rem 0;font-size:0.85rem;color:var(--text-primary);line-height:1.6;">
+                {format!("Les données publiées (schéma {trouve_label}) ne correspondent pas à cette version du site (schéma v{attendu}).")}
+            </p>
+            <p style="margin:0;font-size:0.8rem;color:var(--text-muted);line-height:1.6;">
+                "Rechargez la page sans cache (Ctrl+F5). Si le message persiste, une mise à jour du site est en cours de déploiement ; réessayez dans quelques minutes."
+            </p>
+        </div>
+    }
+}
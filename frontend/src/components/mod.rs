@@ -3,4 +3,5 @@ pub mod skeleton;
 pub mod kpi_card;
 pub mod period_selector;
 pub mod rate_bar;
+pub mod sparkline;
 pub mod tooltip;
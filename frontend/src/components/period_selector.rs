@@ -1,6 +1,10 @@
+use chrono::{Duration, NaiveDate, Utc};
 use leptos::*;
 use crate::models::Period;
-use crate::store::use_store;
+use crate::store::{use_store, AppStore};
+
+/// Fenêtre proposée au premier clic sur "Dates" : 90 derniers jours.
+const DEFAULT_CUSTOM_DAYS: i64 = 90;
 
 #[component]
 pub fn PeriodSelector(
@@ -8,12 +12,24 @@ pub fn PeriodSelector(
     set_period: WriteSignal<Period>,
 ) -> impl IntoView {
     let store = use_store();
+    let store_for_picker = store.clone();
+    let store_for_sessions = store.clone();
 
     view! {
-        <div style="display:flex;gap:0.25rem;background:var(--bg-secondary);border:1px solid var(--bg-border);border-radius:7px;padding:3px;">
-            <PeriodBtn label="30 j" p=Period::P30 current=period set=set_period store=store.clone() />
-            <PeriodBtn label="180 j" p=Period::P180 current=period set=set_period store=store.clone() />
-            <PeriodBtn label="Législature" p=Period::LEG current=period set=set_period store=store.clone() />
+        <div style="display:flex;align-items:center;gap:0.5rem;flex-wrap:wrap;">
+            <div style="display:flex;gap:0.25rem;background:var(--bg-secondary);border:1px solid var(--bg-border);border-radius:7px;padding:3px;">
+                <PeriodBtn label="30 j" p=Period::P30 current=period set=set_period store=store.clone() />
+                <PeriodBtn label="180 j" p=Period::P180 current=period set=set_period store=store.clone() />
+                <PeriodBtn label="Législature" p=Period::LEG current=period set=set_period store=store.clone() />
+                <PeriodBtn label="Sessions" p=Period::Session current=period set=set_period store=store.clone() />
+                <PeriodBtn label="Dates" p=Period::Custom current=period set=set_period store=store.clone() />
+            </div>
+            <Show when=move || period.get() == Period::Session>
+                <SessionPicker store=store_for_sessions.clone() />
+            </Show>
+            <Show when=move || period.get() == Period::Custom>
+                <DateRangePicker store=store_for_picker.clone() />
+            </Show>
         </div>
     }
 }
@@ -24,7 +40,7 @@ fn PeriodBtn(
     p: Period,
     current: ReadSignal<Period>,
     set: WriteSignal<Period>,
-    store: crate::store::AppStore,
+    store: AppStore,
 ) -> impl IntoView {
     view! {
         <button
@@ -33,6 +49,17 @@ fn PeriodBtn(
                 if p == Period::LEG && !store.is_leg_loaded() {
                     store.load_leg();
                 }
+                if p == Period::Session {
+                    store.load_sessions();
+                }
+                // Fenêtre personnalisée : reprendre la dernière choisie, sinon les 90 derniers jours
+                if p == Period::Custom {
+                    let (start, end) = store.custom_range.get_untracked().unwrap_or_else(|| {
+                        let today = Utc::now().date_naive();
+                        (today - Duration::days(DEFAULT_CUSTOM_DAYS), today)
+                    });
+                    store.set_custom_range(start, end);
+                }
                 set.set(p)
             }
             style=move || {
@@ -48,3 +75,132 @@ fn PeriodBtn(
         </button>
     }
 }
+
+/// Choix des bornes de `Period::Custom`, limitées à [début de législature, aujourd'hui].
+#[component]
+fn DateRangePicker(store: AppStore) -> impl IntoView {
+    let status = store.status;
+    let min_date = move || {
+        status
+            .get()
+            .and_then(|r| r.ok())
+            .and_then(|s| s.legislature_start)
+            .map(|d| d.to_string())
+            .unwrap_or_default()
+    };
+    let max_date = Utc::now().date_naive().to_string();
+    let range = store.custom_range;
+    let bound = move |end: bool| {
+        range
+            .get()
+            .map(|(s, e)| (if end { e } else { s }).to_string())
+            .unwrap_or_default()
+    };
+
+    let store_from = store.clone();
+    let on_from = move |ev: ev::Event| {
+        if let (Ok(start), Some((_, end))) = (
+            NaiveDate::parse_from_str(&event_target_value(&ev), "%Y-%m-%d"),
+            range.get_untracked(),
+        ) {
+            store_from.set_custom_range(start, end);
+        }
+    };
+    let store_to = store.clone();
+    let on_to = move |ev: ev::Event| {
+        if let (Ok(end), Some((start, _))) = (
+            NaiveDate::parse_from_str(&event_target_value(&ev), "%Y-%m-%d"),
+            range.get_untracked(),
+        ) {
+            store_to.set_custom_range(start, end);
+        }
+    };
+
+    view! {
+        <div style="display:flex;align-items:center;gap:0.35rem;font-size:0.75rem;color:var(--text-muted);">
+            <label for="period-custom-from">"Du"</label>
+            <input
+                id="period-custom-from"
+                type="date"
+                min=min_date
+                max=max_date.clone()
+                prop:value=move || bound(false)
+                on:change=on_from
+                style="font-size:0.75rem;padding:0.2rem 0.35rem;"
+            />
+            <label for="period-custom-to">"au"</label>
+            <input
+                id="period-custom-to"
+                type="date"
+                min=min_date
+                max=max_date
+                prop:value=move || bound(true)
+                on:change=on_to
+                style="font-size:0.75rem;padding:0.2rem 0.35rem;"
+            />
+        </div>
+    }
+}
+
+/// Liste des sessions (la plus récente présélectionnée au premier affichage).
+#[component]
+fn SessionPicker(store: AppStore) -> impl IntoView {
+    let sessions_index = store.sessions_index;
+    let session_id = store.session_id;
+    let sessions = create_memo(move |_| {
+        sessions_index
+            .get()
+            .and_then(|r| r.get())
+            .map(|r| r.map(|idx| idx.sessions))
+    });
+
+    let store_default = store.clone();
+    create_effect(move |_| {
+        if session_id.get_untracked().is_some() {
+            return;
+        }
+        if let Some(Ok(list)) = sessions.get() {
+            if let Some(first) = list.first() {
+                store_default.set_session(first.id.clone());
+            }
+        }
+    });
+
+    let store_change = store.clone();
+    view! {
+        <div style="display:flex;align-items:center;gap:0.35rem;font-size:0.75rem;color:var(--text-muted);">
+            {move || match sessions.get() {
+                None => view! { <span>"Chargement des sessions…"</span> }.into_view(),
+                Some(Err(e)) => view! { <span style="color:var(--danger);">{format!("Sessions indisponibles : {e}")}</span> }.into_view(),
+                Some(Ok(list)) if list.is_empty() => view! { <span>"Aucune session"</span> }.into_view(),
+                Some(Ok(list)) => {
+                    let store_change = store_change.clone();
+                    view! {
+                        <select
+                            class="amd-select"
+                            aria-label="Session parlementaire"
+                            on:change=move |ev| store_change.set_session(event_target_value(&ev))
+                            style="font-size:0.75rem;"
+                        >
+                            {list.into_iter().map(|s| {
+                                let id = s.id.clone();
+                                let label = format!(
+                                    "{} ({} → {}{})",
+                                    s.label,
+                                    s.start.format("%d/%m/%Y"),
+                                    s.end.format("%d/%m/%Y"),
+                                    if s.en_cours { ", en cours" } else { "" }
+                                );
+                                view! {
+                                    <option value=s.id selected=move || session_id.get().as_deref() == Some(id.as_str())>
+                                        {label}
+                                    </option>
+                                }
+                            }).collect_view()}
+                        </select>
+                    }.into_view()
+                }
+            }}
+        </div>
+    }
+}
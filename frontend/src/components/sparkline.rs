@@ -0,0 +1,66 @@
+use leptos::*;
+
+/// Mini-courbe SVG ; les valeurs `None` (semaine sans donnée) interrompent le tracé.
+#[component]
+pub fn Sparkline(
+    values: Vec<Option<f64>>,
+    /// Borne haute de l'axe (défaut : maximum des valeurs).
+    #[prop(optional_no_strip)]
+    max: Option<f64>,
+    #[prop(default = 120)]
+    width: u32,
+    #[prop(default = 28)]
+    height: u32,
+    #[prop(default = "var(--accent)")]
+    color: &'static str,
+    #[prop(optional)]
+    title: Option<String>,
+) -> impl IntoView {
+    let max = max
+        .unwrap_or_else(|| values.iter().flatten().copied().fold(0.0, f64::max))
+        .max(f64::EPSILON);
+    let n = values.len();
+    let (w, h) = (width as f64, height as f64);
+    let pad = 2.0;
+    let x_of = |i: usize| if n > 1 { pad + i as f64 * (w - 2.0 * pad) / (n - 1) as f64 } else { w / 2.0 };
+    let y_of = |v: f64| h - pad - (v / max).clamp(0.0, 1.0) * (h - 2.0 * pad);
+
+    // Segments continus ; un point isolé est dessiné en cercle
+    let mut path = String::new();
+    let mut dots = Vec::new();
+    let mut prev_some = false;
+    for (i, v) in values.iter().enumerate() {
+        match v {
+            Some(v) => {
+                let cmd = if prev_some { 'L' } else { 'M' };
+                path.push_str(&format!("{cmd}{:.1},{:.1} ", x_of(i), y_of(*v)));
+                let next_some = values.get(i + 1).is_some_and(|n| n.is_some());
+                if !prev_some && !next_some {
+                    dots.push((x_of(i), y_of(*v)));
+                }
+                prev_some = true;
+            }
+            None => prev_some = false,
+        }
+    }
+
+    let base_y = h - pad;
+    let label = title.unwrap_or_else(|| "Évolution hebdomadaire".to_string());
+
+    view! {
+        <span title=label.clone() style="display:inline-block;line-height:0;">
+        <svg
+            width=width
+            height=height
+            viewBox=format!("0 0 {width} {height}")
+            role="img"
+            aria-label=label
+            style="display:block;overflow:visible;"
+        >
+            <line x1="0" y1=base_y x2=w y2=base_y stroke="var(--bg-border)" stroke-width="1" />
+            <path d=path fill="none" stroke=color stroke-width="1.5" stroke-linejoin="round" stroke-linecap="round" />
+            {dots.into_iter().map(|(x, y)| view! { <circle cx=x cy=y r="1.6" fill=color /> }).collect_view()}
+        </svg>
+        </span>
+    }
+}
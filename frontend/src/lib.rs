@@ -12,9 +12,9 @@ use wasm_bindgen::prelude::*;
 use components::layout::Layout;
 use pages::{
     amendements::AmendementsPage,
-    comparer::ComparerPage, depute::DeputePage, exporter::ExportPage, home::HomePage,
+    comparer::ComparerPage, depute::DeputePage, exporter::ExportPage, groupe::GroupePage, home::HomePage,
     methodologie::MethodePage, positions_groupes::PositionsGroupesPage, reseau::ReseauPage,
-    stats_globales::StatsGlobalesPage,
+    scrutin::ScrutinPage, scrutins::ScrutinsPage, stats_globales::StatsGlobalesPage,
 };
 use store::provide_store;
 
@@ -38,6 +38,9 @@ fn App() -> impl IntoView {
                     <Route path=crate::app_path!("/home") view=HomePage />
                     <Route path=crate::app_path!("/") view=HomePage />
                     <Route path=crate::app_path!("/depute/:id") view=DeputePage />
+                    <Route path=crate::app_path!("/groupe/:id") view=GroupePage />
+                    <Route path=crate::app_path!("/scrutins") view=ScrutinsPage />
+                    <Route path=crate::app_path!("/scrutin/:id") view=ScrutinPage />
                     <Route path=crate::app_path!("/comparer") view=ComparerPage />
                     <Route path=crate::app_path!("/exporter") view=ExportPage />
                     <Route path=crate::app_path!("/stats-globales") view=StatsGlobalesPage />
@@ -13,6 +13,8 @@ fn period_from_query(raw: &str) -> Option<Period> {
         "p30" | "30" | "30j" | "30d" | "30days" => Some(Period::P30),
         "p180" | "180" | "180j" | "180d" | "180days" => Some(Period::P180),
         "leg" | "legislature" | "l17" => Some(Period::LEG),
+        "custom" => Some(Period::Custom),
+        "session" => Some(Period::Session),
         _ => None,
     }
 }
@@ -21,6 +23,8 @@ fn period_from_query(raw: &str) -> Option<Period> {
 pub fn ComparerPage() -> impl IntoView {
     let store = use_store();
     let (period, set_period) = create_signal(Period::P180);
+    let custom_range = store.custom_range;
+    let session_id = store.session_id;
     let query = use_query_map();
 
     let (selected_a_id, set_selected_a_id) = create_signal::<Option<String>>(None);
@@ -56,6 +60,12 @@ pub fn ComparerPage() -> impl IntoView {
                 set_selected_b_id.set(Some(b.clone()));
             }
             if let Some(p) = q.get("period").and_then(|v| period_from_query(v)) {
+                // Dates / session : seulement si une fenêtre a déjà été choisie pendant la visite
+                if (p == Period::Custom && custom_range.get_untracked().is_none())
+                    || (p == Period::Session && session_id.get_untracked().is_none())
+                {
+                    return;
+                }
                 set_period.set(p);
             }
         });
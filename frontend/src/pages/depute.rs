@@ -1,9 +1,10 @@
 use leptos::*;
 use leptos_router::*;
 
-use crate::api::{base_url, fetch_deputy_ppl_shard};
+use crate::api::{base_url, fetch_depute_questions, fetch_depute_series, fetch_deputy_ppl_shard};
 use crate::components::{
-    kpi_card::KpiCard, period_selector::PeriodSelector, skeleton::SkeletonKpi, tooltip::InfoIcon,
+    kpi_card::KpiCard, period_selector::PeriodSelector, skeleton::SkeletonKpi, sparkline::Sparkline,
+    tooltip::InfoIcon,
 };
 use crate::models::*;
 use crate::store::use_store;
@@ -14,6 +15,8 @@ fn period_query_value(period: Period) -> &'static str {
         Period::P30 => "p30",
         Period::P180 => "p180",
         Period::LEG => "leg",
+        Period::Custom => "custom",
+        Period::Session => "session",
     }
 }
 
@@ -82,6 +85,8 @@ pub fn DeputePage() -> impl IntoView {
                             let grp_color   = groupe_color(d.groupe_abrev.as_deref());
                             let part_class  = participation_class(d.participation_rate);
                             let current_period = period.get();
+                            // Période personnalisée : dérivée des compteurs journaliers, sans les indicateurs non additifs
+                            let custom = matches!(current_period, Period::Custom);
                             let current_stats = store
                                 .stats_for(current_period)
                                 .get()
@@ -180,11 +185,19 @@ pub fn DeputePage() -> impl IntoView {
                                                         {format!("{} {}", d.prenom, d.nom)}
                                                     </h1>
                                                     <div style="display:flex;align-items:center;gap:0.75rem;flex-wrap:wrap;margin-bottom:0.45rem;">
-                                                        {d.groupe_abrev.as_ref().map(|g| view! {
-                                                            <span class="badge"
-                                                                style=format!("border-color:{grp_color};color:{grp_color};font-size:0.75rem;padding:0.15rem 0.5rem;")>
-                                                                {g.clone()}
-                                                            </span>
+                                                        {d.groupe_abrev.as_ref().map(|g| {
+                                                            let badge = view! {
+                                                                <span class="badge"
+                                                                    style=format!("border-color:{grp_color};color:{grp_color};font-size:0.75rem;padding:0.15rem 0.5rem;")>
+                                                                    {g.clone()}
+                                                                </span>
+                                                            };
+                                                            match d.groupe_id.as_deref() {
+                                                                Some(gid) => view! {
+                                                                    <A href=app_href(&format!("/groupe/{gid}")) attr:style="text-decoration:none;" attr:title="Fiche du groupe">{badge}</A>
+                                                                }.into_view(),
+                                                                None => badge.into_view(),
+                                                            }
                                                         })}
                                                         {d.groupe_nom.as_ref().map(|g| view! {
                                                             <span style="color:var(--text-secondary);font-size:0.82rem;">{g.clone()}</span>
@@ -280,6 +293,16 @@ pub fn DeputePage() -> impl IntoView {
                                             sub=format!("{} / {} scrutins éligibles", d.votes_exprimes, d.scrutins_eligibles)
                                             color=part_class
                                         />
+                                        <KpiCard
+                                            label="Participation solennels"
+                                            value=d.participation_rate_solennel.map(fmt_pct).unwrap_or_else(|| "—".to_string())
+                                            sub=format!("{} / {} scrutins solennels", d.votes_solennels_exprimes, d.scrutins_solennels)
+                                        />
+                                        <KpiCard
+                                            label="Participation ordinaires"
+                                            value=d.participation_rate_ordinaire.map(fmt_pct).unwrap_or_else(|| "—".to_string())
+                                            sub=format!("{} / {} scrutins ordinaires", d.votes_ordinaires_exprimes, d.scrutins_ordinaires)
+                                        />
                                         <KpiCard
                                             label="Votes POUR"
                                             value=d.pour_count.to_string()
@@ -308,6 +331,16 @@ pub fn DeputePage() -> impl IntoView {
                                             value=d.absent.to_string()
                                             sub="aucune position enregistrée".to_string()
                                         />
+                                        <KpiCard
+                                            label="Mises au point"
+                                            value=d.mises_au_point.to_string()
+                                            sub="position voulue déclarée après le vote".to_string()
+                                        />
+                                        <KpiCard
+                                            label="Votes par délégation"
+                                            value=d.votes_par_delegation.to_string()
+                                            sub="vote confié à un collègue".to_string()
+                                        />
                                         <KpiCard
                                             label="Amendements déposés"
                                             value=d.amd_authored.to_string()
@@ -321,7 +354,41 @@ pub fn DeputePage() -> impl IntoView {
                                                 .unwrap_or_else(|| "—".to_string())
                                             color="var(--success)"
                                         />
+                                        <KpiCard
+                                            label="Interventions en séance"
+                                            value=d.interventions_count.to_string()
+                                            sub=format!("{} caractères prononcés", d.interventions_chars)
+                                        />
+                                        <KpiCard
+                                            label="Questions écrites / orales"
+                                            value=(d.questions_ecrites + d.questions_orales).to_string()
+                                            sub=d.questions_answer_rate
+                                                .map(|r| format!("{} répondues · {}", d.questions_repondues, fmt_pct(r)))
+                                                .unwrap_or_else(|| "—".to_string())
+                                        />
+                                        <KpiCard
+                                            label="Questions au Gouvernement"
+                                            value=d.questions_gouvernement.to_string()
+                                            sub="séances de questions (QAG)".to_string()
+                                        />
+                                        <KpiCard
+                                            label="Présence en commission"
+                                            value=d.commission_presence_rate.map(fmt_pct).unwrap_or_else(|| "—".to_string())
+                                            sub=format!("{} / {} réunions · {} excusé(s)", d.commission_presences, d.commission_reunions, d.commission_excuses)
+                                        />
+                                        {(!custom).then(|| view! {
+                                            <KpiCard
+                                                label="Rapports"
+                                                value=(d.rapporteur_fond + d.rapporteur_avis + d.rapporteur_special).to_string()
+                                                sub=format!("{} au fond · {} pour avis · {} spéciaux", d.rapporteur_fond, d.rapporteur_avis, d.rapporteur_special)
+                                            />
+                                        })}
                                     </div>
+                                    {custom.then(|| view! {
+                                        <p style="margin:-1rem 0 1.75rem 0;color:var(--text-muted);font-size:.78rem;">
+                                            "Période personnalisée : rapports, top dossiers, derniers votes dissidents et co-signatures ne sont disponibles que sur les périodes prédéfinies."
+                                        </p>
+                                    })}
 
                                     // Lecture relative (benchmark dataset + groupe)
                                     <div style="display:grid;grid-template-columns:repeat(auto-fit,minmax(260px,1fr));gap:1rem;margin-bottom:1.75rem;">
@@ -382,6 +449,18 @@ pub fn DeputePage() -> impl IntoView {
                                         </div>
                                     </div>
 
+                                    // Séries hebdomadaires (semaines ISO, depuis le début de législature)
+                                    <WeeklySeriesSection deputy_id=d.deputy_id.clone() accent_color=grp_color />
+
+                                    // Questions au Gouvernement (toute la législature)
+                                    <QuestionsSection deputy_id=d.deputy_id.clone() accent_color=grp_color />
+
+                                    // Commissions (appartenances et fonctions)
+                                    <CommissionsSection commissions=d.commissions.clone() accent_color=grp_color />
+
+                                    // Parcours : groupes successifs
+                                    <ParcoursSection groupes=d.groupes.clone() accent_color=grp_color />
+
                                     // Cartes identité / contact
                                     <div style="display:grid;grid-template-columns:repeat(auto-fit,minmax(260px,1fr));gap:1rem;margin-bottom:1.75rem;">
                                         <div style=format!("padding:1rem;background:var(--bg-secondary);border:1px solid var(--bg-border);border-left:3px solid {};border-radius:10px;", grp_color)>
@@ -390,6 +469,7 @@ pub fn DeputePage() -> impl IntoView {
                                                 <span style="color:var(--text-muted);">"ID"</span><span style="font-family:monospace;">{d.deputy_id.clone()}</span>
                                                 <span style="color:var(--text-muted);">"Groupe"</span><span>{d.groupe_nom.clone().unwrap_or_else(|| "—".into())}</span>
                                                 <span style="color:var(--text-muted);">"Parti"</span><span>{d.parti_rattachement.clone().unwrap_or_else(|| "—".into())}</span>
+                                                <span style="color:var(--text-muted);">"Commission"</span><span>{commission_permanente(&d.commissions).map(|c| c.libelle.clone()).unwrap_or_else(|| "—".into())}</span>
                                                 <span style="color:var(--text-muted);">"Profession"</span><span>{d.profession.clone().unwrap_or_else(|| "—".into())}</span>
                                                 <span style="color:var(--text-muted);">"Naissance"</span><span>{naissance_line.clone().unwrap_or_else(|| "—".into())}</span>
                                                 <span style="color:var(--text-muted);">"Territoire"</span><span>{location_line.clone().unwrap_or_else(|| "—".into())}</span>
@@ -590,13 +670,16 @@ pub fn DeputePage() -> impl IntoView {
                                         }}
                                     </div>
 
+                                    // Loyauté envers le groupe
+                                    <GroupLoyaltySection d=d.clone() custom=custom />
+
                                     // Top dossiers
                                     {if !d.top_dossiers.is_empty() {
                                         view! {
                                             <div style="margin-bottom:1.75rem;">
                                                 <h2 style="font-size:0.85rem;font-weight:600;margin:0 0 0.75rem 0;text-transform:uppercase;letter-spacing:0.06em;color:var(--text-muted);display:flex;align-items:center;gap:0.4rem;">
                                                     "Top dossiers — activité"
-                                                    <InfoIcon text="Score = 1×votes + 2×amendements déposés + 1×interventions. Coefficient 2 sur les amendements : engagement actif de rédaction." />
+                                                    <InfoIcon text="Score = 1×votes + 2×amendements déposés + 1×interventions + 10 si le député est rapporteur du dossier. Coefficient 2 sur les amendements : engagement actif de rédaction." />
                                                 </h2>
                                                 <div style=format!("background:var(--bg-secondary);border:1px solid var(--bg-border);border-left:3px solid {};border-radius:8px;overflow:hidden;", grp_color)>
                                                     <table class="data-table">
@@ -617,6 +700,11 @@ pub fn DeputePage() -> impl IntoView {
                                                                             {dos.dossier_id.clone()}
                                                                         </span>
                                                                         <span style="font-size:0.82rem;">{dos.titre.clone()}</span>
+                                                                        {dos.rapporteur.as_deref().map(|r| view! {
+                                                                            <span style="display:inline-block;margin-left:.4rem;font-size:.68rem;padding:.05rem .4rem;border:1px solid var(--accent);border-radius:999px;color:var(--accent);">
+                                                                                {rapporteur_role_label(r)}
+                                                                            </span>
+                                                                        })}
                                                                     </td>
                                                                     <td>{dos.votes}</td>
                                                                     <td>{dos.amendements}</td>
@@ -634,14 +722,16 @@ pub fn DeputePage() -> impl IntoView {
                                     }}
 
                                     // Réseau de co-signatures (intra / hors groupe)
-                                    <CosignNetworkSection d=d.clone() />
+                                    {(!custom).then(|| view! { <CosignNetworkSection d=d.clone() /> })}
 
                                     // Traçabilité des chiffres
                                     <div style=format!("margin-bottom:1.75rem;padding:1rem;background:var(--bg-secondary);border:1px solid var(--bg-border);border-left:3px solid {};border-radius:10px;", grp_color)>
                                         <h2 style="font-size:0.82rem;font-weight:600;margin:0 0 .75rem 0;text-transform:uppercase;letter-spacing:.06em;color:var(--text-muted);">"Traçabilité & export"</h2>
                                         <div style="display:flex;flex-wrap:wrap;gap:.5rem;margin-bottom:.55rem;">
                                             <a href=dataset_json_url.clone() target="_blank" rel="noopener noreferrer" style="font-size:.75rem;padding:.35rem .65rem;border:1px solid var(--bg-border);border-radius:8px;background:rgba(255,255,255,0.01);color:var(--text-primary);text-decoration:none;">"JSON période ↗"</a>
-                                            <a href=dataset_csv_url.clone() target="_blank" rel="noopener noreferrer" style="font-size:.75rem;padding:.35rem .65rem;border:1px solid var(--bg-border);border-radius:8px;background:rgba(255,255,255,0.01);color:var(--text-primary);text-decoration:none;">"CSV période ↗"</a>
+                                            {(!matches!(current_period, Period::Custom | Period::Session)).then(|| view! {
+                                                <a href=dataset_csv_url.clone() target="_blank" rel="noopener noreferrer" style="font-size:.75rem;padding:.35rem .65rem;border:1px solid var(--bg-border);border-radius:8px;background:rgba(255,255,255,0.01);color:var(--text-primary);text-decoration:none;">"CSV période ↗"</a>
+                                            })}
                                             <a href=profile_url.clone() target="_blank" rel="noopener noreferrer" style="font-size:.75rem;padding:.35rem .65rem;border:1px solid var(--bg-border);border-radius:8px;background:rgba(255,255,255,0.01);color:var(--text-primary);text-decoration:none;">"Source AN ↗"</a>
                                         </div>
                                         <p style="margin:0;color:var(--text-muted);font-size:.78rem;line-height:1.5;">
@@ -665,6 +755,75 @@ pub fn DeputePage() -> impl IntoView {
     }
 }
 
+#[component]
+fn GroupLoyaltySection(d: DeputeStats, custom: bool) -> impl IntoView {
+    let accent_color = groupe_color(d.groupe_abrev.as_deref());
+    let dissents = d.recent_dissents.clone();
+
+    view! {
+        <div style="margin-bottom:1.75rem;">
+            <h2 style="font-size:0.85rem;font-weight:600;margin:0 0 0.75rem 0;text-transform:uppercase;letter-spacing:0.06em;color:var(--text-muted);display:flex;align-items:center;gap:0.4rem;">
+                "Vote avec le groupe"
+                <InfoIcon text="Part des votes exprimés (pour, contre, abstention) identiques à la position majoritaire du groupe sous lequel le député est décompté, sur les scrutins où cette position est publiée. Les non-votants et absents sont exclus." />
+            </h2>
+            <div style=format!("background:var(--bg-secondary);border:1px solid var(--bg-border);border-left:3px solid {};border-radius:10px;padding:1rem;", accent_color)>
+                {match d.loyalty_rate {
+                    Some(rate) => view! {
+                        <>
+                            <div style="display:grid;grid-template-columns:repeat(auto-fit,minmax(170px,1fr));gap:.6rem;margin-bottom:.85rem;">
+                                <MiniMetric label="Loyauté" value=fmt_pct(rate) subtle="votes alignés sur le groupe".to_string() />
+                                <MiniMetric label="Votes comparables" value=d.loyalty_votes.to_string() subtle="".to_string() />
+                                <MiniMetric label="Votes dissidents" value=d.dissent_count.to_string() subtle="".to_string() />
+                            </div>
+                            {(!custom).then(|| if dissents.is_empty() {
+                                view! {
+                                    <p style="margin:0;color:var(--text-muted);font-size:.78rem;">"Aucun vote dissident sur la période."</p>
+                                }.into_view()
+                            } else {
+                                view! {
+                                    <div style="font-size:.74rem;color:var(--text-secondary);margin-bottom:.35rem;">"Derniers votes dissidents"</div>
+                                    <table class="data-table">
+                                        <thead>
+                                            <tr>
+                                                <th>"Scrutin"</th>
+                                                <th>"Date"</th>
+                                                <th>"Vote"</th>
+                                                <th>"Groupe"</th>
+                                            </tr>
+                                        </thead>
+                                        <tbody>
+                                            {dissents.into_iter().map(|dv| view! {
+                                                <tr>
+                                                    <td style="max-width:420px;">
+                                                        <span style="font-size:0.7rem;color:var(--text-muted);font-family:monospace;display:block;">
+                                                            {format!("n° {} · {}", dv.numero, dv.scrutin_id)}
+                                                        </span>
+                                                        <A href=app_href(&format!("/scrutin/{}", dv.scrutin_id)) attr:style="font-size:0.8rem;color:var(--text-primary);text-decoration:none;">
+                                                            {dv.titre}
+                                                        </A>
+                                                    </td>
+                                                    <td style="white-space:nowrap;">{dv.date.format("%d/%m/%Y").to_string()}</td>
+                                                    <td style="font-weight:600;">{dv.position.label()}</td>
+                                                    <td style="color:var(--text-muted);">{dv.groupe_position.label()}</td>
+                                                </tr>
+                                            }).collect_view()}
+                                        </tbody>
+                                    </table>
+                                }.into_view()
+                            })}
+                        </>
+                    }.into_view(),
+                    None => view! {
+                        <p style="margin:0;color:var(--text-muted);font-size:.8rem;">
+                            "Aucun vote exprimé comparable à une position de groupe sur la période."
+                        </p>
+                    }.into_view(),
+                }}
+            </div>
+        </div>
+    }
+}
+
 #[component]
 fn CosignNetworkSection(d: DeputeStats) -> impl IntoView {
     let accent_color = groupe_color(d.groupe_abrev.as_deref());
@@ -832,6 +991,254 @@ fn CosignNetworkSection(d: DeputeStats) -> impl IntoView {
     .into_view()
 }
 
+#[component]
+fn WeeklySeriesSection(deputy_id: String, accent_color: &'static str) -> impl IntoView {
+    let series_res = create_resource(move || deputy_id.clone(), |id| async move { fetch_depute_series(&id).await });
+
+    view! {
+        <div style="margin-bottom:1.75rem;">
+            <h2 style="font-size:0.85rem;font-weight:600;margin:0 0 0.75rem 0;text-transform:uppercase;letter-spacing:0.06em;color:var(--text-muted);display:flex;align-items:center;gap:0.4rem;">
+                "Évolution hebdomadaire"
+                <InfoIcon text="Compteurs par semaine ISO (lundi → dimanche) depuis le début de la législature, bornés aux périodes de mandat. Les semaines sans scrutin éligible n'ont pas de taux de participation et interrompent la courbe." />
+            </h2>
+            <div style=format!("background:var(--bg-secondary);border:1px solid var(--bg-border);border-left:3px solid {};border-radius:10px;padding:1rem;", accent_color)>
+                {move || match series_res.get() {
+                    None => view! {
+                        <p style="margin:0;color:var(--text-muted);font-size:.8rem;">"Chargement des séries hebdomadaires..."</p>
+                    }.into_view(),
+                    Some(Err(e)) => view! {
+                        <p style="margin:0;color:var(--text-muted);font-size:.8rem;">{format!("Séries hebdomadaires indisponibles : {e}")}</p>
+                    }.into_view(),
+                    Some(Ok(file)) if file.weeks == 0 => view! {
+                        <p style="margin:0;color:var(--text-muted);font-size:.8rem;">"Aucune semaine couverte."</p>
+                    }.into_view(),
+                    Some(Ok(file)) => {
+                        let s = file.series;
+                        let range = format!(
+                            "{} → {} · {} semaines",
+                            iso_week_label(file.week_start, 0),
+                            iso_week_label(file.week_start, file.weeks - 1),
+                            file.weeks
+                        );
+                        let as_f64 = |v: &[u32]| v.iter().map(|&x| Some(x as f64)).collect::<Vec<_>>();
+                        let total = |v: &[u32]| v.iter().sum::<u32>();
+                        let (eligibles, exprimes) = (total(&s.scrutins_eligibles), total(&s.votes_exprimes));
+                        let rows = vec![
+                            ("Participation", s.participation(), Some(1.0), if eligibles > 0 { fmt_pct(exprimes as f64 / eligibles as f64) } else { "—".to_string() }),
+                            ("Votes exprimés", as_f64(&s.votes_exprimes), None, format!("{exprimes} / {eligibles} scrutins")),
+                            ("Amendements déposés", as_f64(&s.amd_authored), None, total(&s.amd_authored).to_string()),
+                            ("Amendements adoptés", as_f64(&s.amd_adopted), None, total(&s.amd_adopted).to_string()),
+                            ("Cosignatures", as_f64(&s.amd_cosigned), None, total(&s.amd_cosigned).to_string()),
+                        ];
+                        view! {
+                            <>
+                                <div style="display:flex;flex-direction:column;gap:.5rem;">
+                                    {rows.into_iter().map(|(label, values, max, summary)| view! {
+                                        <div style="display:grid;grid-template-columns:minmax(150px,1fr) auto minmax(110px,auto);gap:.75rem;align-items:center;font-size:.8rem;">
+                                            <span style="color:var(--text-secondary);">{label}</span>
+                                            <Sparkline values=values max=max width=240 height=30 title=format!("{label} par semaine") />
+                                            <span style="font-weight:600;color:var(--text-primary);text-align:right;">{summary}</span>
+                                        </div>
+                                    }).collect_view()}
+                                </div>
+                                <p style="margin:.65rem 0 0 0;color:var(--text-muted);font-size:.72rem;">{range}</p>
+                            </>
+                        }.into_view()
+                    }
+                }}
+            </div>
+        </div>
+    }
+}
+
+/// Libellé court d'un type d'organe de commission.
+fn commission_type_label(code: &str) -> &'static str {
+    match code {
+        "COMPER" => "Permanente",
+        "CNPE" => "Enquête",
+        "CMP" => "Mixte paritaire",
+        _ => "Commission",
+    }
+}
+
+#[component]
+fn CommissionsSection(commissions: Vec<CommissionMembership>, accent_color: &'static str) -> impl IntoView {
+    if commissions.is_empty() {
+        return ().into_view();
+    }
+
+    view! {
+        <div style="margin-bottom:1.75rem;">
+            <h2 style="font-size:0.85rem;font-weight:600;margin:0 0 0.75rem 0;text-transform:uppercase;letter-spacing:0.06em;color:var(--text-muted);display:flex;align-items:center;gap:0.4rem;">
+                "Commissions"
+                <InfoIcon text="Commissions permanentes, d'enquête et mixtes paritaires, avec la fonction occupée (président, rapporteur, secrétaire…). La présence est décomptée sur les réunions où le député était convoqué." />
+            </h2>
+            <div style=format!("background:var(--bg-secondary);border:1px solid var(--bg-border);border-left:3px solid {};border-radius:8px;overflow:hidden;", accent_color)>
+                <table class="data-table">
+                    <thead>
+                        <tr>
+                            <th>"Commission"</th>
+                            <th>"Type"</th>
+                            <th>"Fonction"</th>
+                            <th>"Période"</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {commissions.into_iter().map(|c| {
+                            let periode = match (c.date_debut, c.date_fin) {
+                                (Some(debut), Some(fin)) => format!("{} → {}", debut.format("%d/%m/%Y"), fin.format("%d/%m/%Y")),
+                                (Some(debut), None) => format!("depuis le {}", debut.format("%d/%m/%Y")),
+                                (None, Some(fin)) => format!("jusqu'au {}", fin.format("%d/%m/%Y")),
+                                (None, None) => "—".to_string(),
+                            };
+                            let qualite_style = if c.has_role() {
+                                "font-size:.8rem;font-weight:600;color:var(--accent);"
+                            } else {
+                                "font-size:.8rem;color:var(--text-secondary);"
+                            };
+                            view! {
+                                <tr>
+                                    <td style="max-width:380px;font-size:0.82rem;">{c.libelle.clone()}</td>
+                                    <td style="font-size:.78rem;color:var(--text-muted);">{commission_type_label(&c.type_organe)}</td>
+                                    <td style=qualite_style>{c.qualite.clone()}</td>
+                                    <td style="white-space:nowrap;font-size:.78rem;color:var(--text-muted);">{periode}</td>
+                                </tr>
+                            }
+                        }).collect_view()}
+                    </tbody>
+                </table>
+            </div>
+        </div>
+    }
+    .into_view()
+}
+
+#[component]
+fn ParcoursSection(groupes: Vec<GroupeMembership>, accent_color: &'static str) -> impl IntoView {
+    if groupes.is_empty() {
+        return ().into_view();
+    }
+
+    view! {
+        <div style="margin-bottom:1.75rem;">
+            <h2 style="font-size:0.85rem;font-weight:600;margin:0 0 0.75rem 0;text-transform:uppercase;letter-spacing:0.06em;color:var(--text-muted);display:flex;align-items:center;gap:0.4rem;">
+                "Parcours"
+                <InfoIcon text="Groupes politiques successifs, membre ou apparenté. Les périodes marquées « déduit des scrutins » ne figurent plus dans les mandats publiés : elles sont reconstituées à partir du groupe sous lequel le député vote. Votes et amendements sont attribués au groupe de la date concernée." />
+            </h2>
+            <div style=format!("padding:1rem 1.25rem;background:var(--bg-secondary);border:1px solid var(--bg-border);border-left:3px solid {};border-radius:8px;", accent_color)>
+                {groupes.into_iter().rev().map(|g| {
+                    let color = groupe_color(g.abrev.as_deref());
+                    let periode = match (g.date_debut, g.date_fin) {
+                        (Some(debut), Some(fin)) => format!("{} → {}", debut.format("%d/%m/%Y"), fin.format("%d/%m/%Y")),
+                        (Some(debut), None) => format!("depuis le {}", debut.format("%d/%m/%Y")),
+                        (None, Some(fin)) => format!("jusqu'au {}", fin.format("%d/%m/%Y")),
+                        (None, None) => "—".to_string(),
+                    };
+                    let statut = if g.apparente { "Apparenté" } else { "Membre" };
+                    view! {
+                        <div style=format!("position:relative;padding:0 0 .85rem 1.1rem;border-left:2px solid {};", color)>
+                            <span style=format!("position:absolute;left:-6px;top:.2rem;width:10px;height:10px;border-radius:50%;background:{};", color)></span>
+                            <div style="display:flex;flex-wrap:wrap;align-items:baseline;gap:.5rem;font-size:.85rem;">
+                                <span style="font-weight:600;">{g.libelle.clone()}</span>
+                                {g.abrev.clone().map(|a| view! { <span style="font-size:.75rem;color:var(--text-muted);">{format!("({a})")}</span> })}
+                                <span style="font-size:.72rem;padding:.05rem .4rem;border:1px solid var(--bg-border);border-radius:999px;color:var(--text-secondary);">{statut}</span>
+                            </div>
+                            <div style="font-size:.78rem;color:var(--text-muted);margin-top:.2rem;">
+                                {periode}
+                                {g.deduite.then(|| view! { <span style="font-style:italic;">" · déduit des scrutins"</span> })}
+                            </div>
+                        </div>
+                    }
+                }).collect_view()}
+            </div>
+        </div>
+    }
+    .into_view()
+}
+
+/// Nombre de questions affichées avant repli.
+const QUESTIONS_PREVIEW: usize = 15;
+
+#[component]
+fn QuestionsSection(deputy_id: String, accent_color: &'static str) -> impl IntoView {
+    let questions_res = create_resource(move || deputy_id.clone(), |id| async move { fetch_depute_questions(&id).await });
+    let (show_all, set_show_all) = create_signal(false);
+
+    view! {
+        <div style="margin-bottom:1.75rem;">
+            <h2 style="font-size:0.85rem;font-weight:600;margin:0 0 0.75rem 0;text-transform:uppercase;letter-spacing:0.06em;color:var(--text-muted);display:flex;align-items:center;gap:0.4rem;">
+                "Questions au Gouvernement"
+                <InfoIcon text="Questions écrites, orales sans débat et au Gouvernement déposées depuis le début de la législature, la plus récente en premier. Une question est répondue dès qu'une réponse est publiée au Journal officiel." />
+            </h2>
+            <div style=format!("background:var(--bg-secondary);border:1px solid var(--bg-border);border-left:3px solid {};border-radius:10px;overflow:hidden;", accent_color)>
+                {move || match questions_res.get() {
+                    None => view! {
+                        <p style="margin:0;padding:1rem;color:var(--text-muted);font-size:.8rem;">"Chargement des questions..."</p>
+                    }.into_view(),
+                    Some(Err(e)) => view! {
+                        <p style="margin:0;padding:1rem;color:var(--text-muted);font-size:.8rem;">{format!("Questions indisponibles : {e}")}</p>
+                    }.into_view(),
+                    Some(Ok(file)) if file.questions.is_empty() => view! {
+                        <p style="margin:0;padding:1rem;color:var(--text-muted);font-size:.8rem;">"Aucune question enregistrée."</p>
+                    }.into_view(),
+                    Some(Ok(file)) => {
+                        let total = file.questions.len();
+                        let shown = if show_all.get() { total } else { total.min(QUESTIONS_PREVIEW) };
+                        view! {
+                            <>
+                                <table class="data-table">
+                                    <thead>
+                                        <tr>
+                                            <th>"Date"</th>
+                                            <th>"Question"</th>
+                                            <th>"Ministère"</th>
+                                            <th>"Réponse"</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        {file.questions.into_iter().take(shown).map(|q| {
+                                            let date = q.date_question.map(|d| d.format("%d/%m/%Y").to_string()).unwrap_or_else(|| "—".into());
+                                            let (reponse, reponse_color) = match (q.repondue, q.date_reponse) {
+                                                (true, Some(d)) => (format!("Répondue le {}", d.format("%d/%m/%Y")), "var(--success)"),
+                                                (true, None) => ("Répondue".to_string(), "var(--success)"),
+                                                (false, _) => (q.cloture.clone().unwrap_or_else(|| "En attente".into()), "var(--text-muted)"),
+                                            };
+                                            let numero = q.numero.map(|n| format!(" n° {n}")).unwrap_or_default();
+                                            view! {
+                                                <tr>
+                                                    <td style="white-space:nowrap;font-size:.78rem;">{date}</td>
+                                                    <td style="max-width:380px;">
+                                                        <span style="font-size:0.7rem;color:var(--text-muted);display:block;">
+                                                            {format!("{}{}", question_type_label(&q.type_question), numero)}
+                                                            {q.rubrique.clone().map(|r| format!(" · {r}"))}
+                                                        </span>
+                                                        <span style="font-size:0.82rem;">{q.titre.clone().unwrap_or_else(|| q.id.clone())}</span>
+                                                    </td>
+                                                    <td style="font-size:.78rem;color:var(--text-secondary);">{q.ministere.clone().unwrap_or_else(|| "—".into())}</td>
+                                                    <td style=format!("font-size:.78rem;color:{reponse_color};")>{reponse}</td>
+                                                </tr>
+                                            }
+                                        }).collect_view()}
+                                    </tbody>
+                                </table>
+                                {(total > QUESTIONS_PREVIEW).then(|| view! {
+                                    <button
+                                        class="btn"
+                                        style="margin:.75rem 1rem;font-size:.78rem;"
+                                        on:click=move |_| set_show_all.update(|v| *v = !*v)
+                                    >
+                                        {move || if show_all.get() { "Réduire".to_string() } else { format!("Afficher les {total} questions") }}
+                                    </button>
+                                })}
+                            </>
+                        }.into_view()
+                    }
+                }}
+            </div>
+        </div>
+    }
+}
+
 #[component]
 fn MiniMetric(
     label: &'static str,
@@ -56,7 +56,7 @@ pub fn ExportPage() -> impl IntoView {
                                         class="btn"
                                         on:click=move |_| {
                                             if let Some(Ok(ref data)) = resource.get() {
-                                                let csv = stats_to_csv(data);
+                                                let csv = stats_to_csv(data, p);
                                                 trigger_download(&csv, &format!("{}.csv", p.csv_label()), "text/csv;charset=utf-8;");
                                             }
                                         }
@@ -203,6 +203,8 @@ impl CsvLabel for Period {
             Period::P30  => "deputes_activity_P30",
             Period::P180 => "deputes_activity_P180",
             Period::LEG  => "deputes_activity_LEG",
+            Period::Custom => "deputes_activity_custom",
+            Period::Session => "deputes_activity_session",
         }
     }
 }
@@ -230,9 +232,23 @@ const CSV_SCHEMA: &[(&str, &str, &str)] = &[
     ("amd_adopted",        "integer",       "Amendements déposés adoptés"),
     ("amd_adoption_rate",  "float|null",   "amd_adopted / amd_authored (null si authored=0)"),
     ("amd_cosigned",       "integer",       "Amendements co-signés (auteur secondaire)"),
-    ("interventions_count","integer",       "Interventions en séance (0 en V1)"),
-    ("interventions_chars","integer",       "Caractères interventions (0 en V1)"),
+    ("interventions_count","integer",       "Prises de parole en séance publique (hors présidence)"),
+    ("interventions_chars","integer",       "Caractères prononcés dans ces interventions"),
+    ("questions_ecrites",  "integer",       "Questions écrites (QE) déposées"),
+    ("questions_orales",   "integer",       "Questions orales sans débat (QOSD) déposées"),
+    ("questions_gouvernement","integer",    "Questions au Gouvernement (QAG) posées en séance"),
+    ("questions_repondues","integer",       "Questions écrites et orales sans débat ayant reçu une réponse"),
+    ("questions_answer_rate","float|null",  "questions_repondues / (questions_ecrites + questions_orales)"),
+    ("commission_permanente","string|null", "Commission permanente en cours"),
+    ("commission_qualite", "string|null",   "Fonction dans cette commission (Président, Secrétaire, Membre…)"),
+    ("commission_reunions","integer",       "Réunions de commission où le député était convoqué"),
+    ("commission_presences","integer",      "Réunions où il est noté présent"),
+    ("commission_excuses", "integer",       "Réunions où il est noté excusé"),
+    ("commission_presence_rate","float|null","commission_presences / commission_reunions"),
+    ("rapporteur_fond",    "integer|null",  "Dossiers dont le député est rapporteur au fond (ou rapporteur général) ; vide sur période personnalisée"),
+    ("rapporteur_avis",    "integer|null",  "Dossiers dont il est rapporteur pour avis"),
+    ("rapporteur_special", "integer|null",  "Dossiers dont il est rapporteur spécial (budget)"),
     ("top_dossier_id",     "string|null",   "Dossier avec score d'activité le plus élevé"),
     ("top_dossier_titre",  "string|null",   "Titre du dossier principal"),
-    ("top_dossier_score",  "integer|null",  "Score = 1×votes + 2×amendements"),
+    ("top_dossier_score",  "integer|null",  "Score = 1×votes + 2×amendements + 1×interventions + 10 si rapporteur"),
 ];
@@ -0,0 +1,247 @@
+use leptos::*;
+use leptos_router::*;
+
+use crate::api::{fetch_group_ppl_group_shard, fetch_groupe_detail};
+use crate::components::kpi_card::KpiCard;
+use crate::components::skeleton::SkeletonKpi;
+use crate::models::*;
+use crate::utils::{app_href, fmt_pct, groupe_color};
+
+/// Nombre de PPL listées sur la fiche (les plus récentes).
+const PPL_PREVIEW_LIMIT: usize = 15;
+
+#[component]
+pub fn GroupePage() -> impl IntoView {
+    let params = use_params_map();
+    let groupe_id = move || params.with(|p| p.get("id").cloned().unwrap_or_default());
+
+    let detail_res = create_resource(groupe_id, |id| async move { fetch_groupe_detail(&id).await });
+
+    view! {
+        <div>
+            <div style="margin-bottom:1rem;">
+                <A href=crate::app_path!("/stats-globales") attr:style="color:var(--accent);font-size:0.82rem;text-decoration:none;">
+                    "← Retour aux stats globales"
+                </A>
+            </div>
+
+            {move || match detail_res.get() {
+                None => view! {
+                    <div style="display:grid;grid-template-columns:repeat(auto-fill,minmax(180px,1fr));gap:1rem;margin-bottom:1.5rem;">
+                        <SkeletonKpi/><SkeletonKpi/><SkeletonKpi/><SkeletonKpi/>
+                    </div>
+                }.into_view(),
+                Some(Err(e)) => view! {
+                    <div style="text-align:center;padding:3rem;color:var(--text-muted);">
+                        <p style="font-size:1.2rem;margin-bottom:0.5rem;">"Groupe non trouvé"</p>
+                        <p style="font-size:0.82rem;">{format!("L'identifiant {} est introuvable ({e}).", groupe_id())}</p>
+                    </div>
+                }.into_view(),
+                Some(Ok(g)) => view! { <GroupeDetailView g=g /> }.into_view(),
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn GroupeDetailView(g: GroupeDetail) -> impl IntoView {
+    let color = groupe_color(g.abrev.as_deref());
+    let title = g.abrev.clone().unwrap_or_else(|| g.id.clone());
+
+    // Shard PPL du groupe (positions-groupes/ppl), chargé seulement si le groupe en a
+    let ppl_file = g.ppl.as_ref().map(|p| p.file.clone());
+    let ppl_res = create_resource(
+        move || ppl_file.clone(),
+        |file| async move {
+            match file {
+                Some(f) => fetch_group_ppl_group_shard(&f).await.map(Some),
+                None => Ok(None),
+            }
+        },
+    );
+
+    let membres = g.membres.clone();
+    let mouvements = g.mouvements.clone();
+
+    view! {
+        <div class="reveal">
+            // En-tête
+            <div style=format!("margin-bottom:1.5rem;padding:1.25rem;background:var(--bg-secondary);border:1px solid var(--bg-border);border-left:4px solid {color};border-radius:10px;")>
+                <div style="display:flex;align-items:baseline;gap:0.6rem;flex-wrap:wrap;">
+                    <h1 style=format!("font-size:1.5rem;font-weight:700;margin:0;color:{color};")>{title}</h1>
+                    <span style="font-size:0.95rem;color:var(--text-primary);">{g.libelle.clone()}</span>
+                </div>
+                <p style="margin:0.45rem 0 0 0;font-size:0.78rem;color:var(--text-muted);">
+                    {format!("{} membres • période {} → {}", g.membres.len(), g.period_start.format("%d/%m/%Y"), g.period_end.format("%d/%m/%Y"))}
+                    <span style="font-family:monospace;margin-left:0.5rem;">{g.id.clone()}</span>
+                </p>
+            </div>
+
+            // KPIs
+            <div style="display:grid;grid-template-columns:repeat(auto-fill,minmax(175px,1fr));gap:1rem;margin-bottom:1.75rem;">
+                <KpiCard
+                    label="Participation médiane"
+                    value=g.participation_median.map(fmt_pct).unwrap_or_else(|| "—".to_string())
+                    sub="médiane des membres".to_string()
+                />
+                <KpiCard
+                    label="Cohésion"
+                    value=g.cohesion.cohesion_moyenne.map(fmt_pct).unwrap_or_else(|| "—".to_string())
+                    sub=format!("{} scrutins • {} unanimes", g.cohesion.scrutins, g.cohesion.unanimes)
+                />
+                <KpiCard
+                    label="Amendements déposés"
+                    value=g.amd_authored.to_string()
+                    sub="par les membres à la date de dépôt".to_string()
+                />
+                <KpiCard
+                    label="Amendements adoptés"
+                    value=g.amd_adopted.to_string()
+                    sub=g.amd_adoption_rate
+                        .map(|r| format!("taux : {}", fmt_pct(r)))
+                        .unwrap_or_else(|| "—".to_string())
+                    color="var(--success)"
+                />
+                <KpiCard
+                    label="Propositions de loi"
+                    value=g.ppl.as_ref().map(|p| p.ppl_count.to_string()).unwrap_or_else(|| "0".to_string())
+                    sub=g.ppl.as_ref()
+                        .map(|p| format!("{} en auteur • {} en cosignature", p.authored_ppl_count, p.cosigned_only_ppl_count))
+                        .unwrap_or_else(|| "aucune".to_string())
+                />
+            </div>
+
+            // Membres
+            <div style="margin-bottom:1.75rem;">
+                <h2 style="font-size:0.85rem;font-weight:600;margin:0 0 0.75rem 0;text-transform:uppercase;letter-spacing:0.06em;color:var(--text-muted);">
+                    "Membres"
+                </h2>
+                <div style="background:var(--bg-secondary);border:1px solid var(--bg-border);border-radius:8px;overflow:auto;">
+                    <table class="data-table">
+                        <thead>
+                            <tr>
+                                <th>"Député"</th>
+                                <th>"Participation"</th>
+                                <th>"Scrutins éligibles"</th>
+                                <th>"Amendements"</th>
+                                <th>"Adoptés"</th>
+                                <th>"Vote avec le groupe"</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {membres.into_iter().map(|m| view! {
+                                <tr>
+                                    <td>
+                                        <A href=app_href(&format!("/depute/{}", m.deputy_id)) attr:style="color:var(--text-primary);text-decoration:none;font-size:0.8rem;">
+                                            {format!("{} {}", m.prenom, m.nom)}
+                                        </A>
+                                    </td>
+                                    <td>{fmt_pct(m.participation_rate)}</td>
+                                    <td>{m.scrutins_eligibles}</td>
+                                    <td>{m.amd_authored}</td>
+                                    <td>{m.amd_adopted}</td>
+                                    <td>{m.loyalty_rate.map(fmt_pct).unwrap_or_else(|| "—".to_string())}</td>
+                                </tr>
+                            }).collect_view()}
+                        </tbody>
+                    </table>
+                </div>
+            </div>
+
+            // Mouvements
+            <div style="margin-bottom:1.75rem;">
+                <h2 style="font-size:0.85rem;font-weight:600;margin:0 0 0.75rem 0;text-transform:uppercase;letter-spacing:0.06em;color:var(--text-muted);">
+                    "Arrivées et départs"
+                </h2>
+                {if mouvements.is_empty() {
+                    view! {
+                        <p style="margin:0;font-size:0.78rem;color:var(--text-muted);">"Aucun changement de groupe observé dans les scrutins."</p>
+                    }.into_view()
+                } else {
+                    view! {
+                        <div style="background:var(--bg-secondary);border:1px solid var(--bg-border);border-radius:8px;overflow:auto;">
+                            <table class="data-table">
+                                <thead>
+                                    <tr>
+                                        <th>"Date"</th>
+                                        <th>"Député"</th>
+                                        <th>"Mouvement"</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {mouvements.into_iter().map(|mv| {
+                                        let autre = mv.autre_groupe_abrev.clone().unwrap_or_else(|| mv.autre_groupe_ref.clone());
+                                        let (label, mv_color) = if mv.sens == "arrivee" {
+                                            (format!("Arrivée (depuis {autre})"), "var(--success)")
+                                        } else {
+                                            (format!("Départ (vers {autre})"), "var(--danger)")
+                                        };
+                                        view! {
+                                            <tr>
+                                                <td style="white-space:nowrap;">{mv.date.format("%d/%m/%Y").to_string()}</td>
+                                                <td>
+                                                    <A href=app_href(&format!("/depute/{}", mv.deputy_id)) attr:style="color:var(--text-primary);text-decoration:none;font-size:0.8rem;">
+                                                        {mv.nom.clone().unwrap_or_else(|| mv.deputy_id.clone())}
+                                                    </A>
+                                                </td>
+                                                <td style=format!("color:{mv_color};font-size:0.78rem;")>{label}</td>
+                                            </tr>
+                                        }
+                                    }).collect_view()}
+                                </tbody>
+                            </table>
+                        </div>
+                    }.into_view()
+                }}
+            </div>
+
+            // Propositions de loi
+            <div style="margin-bottom:1.75rem;">
+                <h2 style="font-size:0.85rem;font-weight:600;margin:0 0 0.75rem 0;text-transform:uppercase;letter-spacing:0.06em;color:var(--text-muted);">
+                    "Propositions de loi"
+                </h2>
+                {move || match ppl_res.get() {
+                    None => view! { <div class="loading-box"><span class="spinner"></span>" Chargement…"</div> }.into_view(),
+                    Some(Err(e)) => view! { <p style="margin:0;color:var(--danger);font-size:0.78rem;">{e}</p> }.into_view(),
+                    Some(Ok(None)) => view! {
+                        <p style="margin:0;font-size:0.78rem;color:var(--text-muted);">"Aucune proposition de loi signée par un membre du groupe."</p>
+                    }.into_view(),
+                    Some(Ok(Some(shard))) => {
+                        let mut items = shard.items.clone();
+                        items.sort_by(|a, b| b.deposit_date.cmp(&a.deposit_date));
+                        let total = items.len();
+                        view! {
+                            <ul style="list-style:none;margin:0;padding:0;display:flex;flex-direction:column;gap:0.35rem;">
+                                {items.into_iter().take(PPL_PREVIEW_LIMIT).map(|it| view! {
+                                    <li style="display:flex;justify-content:space-between;gap:0.75rem;font-size:0.78rem;padding:0.4rem 0.55rem;border:1px solid var(--bg-border);border-radius:6px;background:var(--bg-secondary);">
+                                        <span style="min-width:0;">
+                                            {match it.source_url.clone() {
+                                                Some(url) => view! { <a href=url target="_blank" rel="noopener noreferrer" style="color:var(--text-primary);text-decoration:none;">{it.title.clone()}</a> }.into_view(),
+                                                None => view! { <span>{it.title.clone()}</span> }.into_view(),
+                                            }}
+                                        </span>
+                                        <span style="white-space:nowrap;color:var(--text-muted);font-size:0.72rem;">
+                                            {format!("{} • {}", it.deposit_date.clone().unwrap_or_default(), if it.has_author { "auteur" } else { "cosignature" })}
+                                        </span>
+                                    </li>
+                                }).collect_view()}
+                            </ul>
+                            {(total > PPL_PREVIEW_LIMIT).then(|| view! {
+                                <p style="margin:0.5rem 0 0 0;font-size:0.75rem;">
+                                    <A href=crate::app_path!("/positions-groupes") attr:style="color:var(--accent);">
+                                        {format!("→ Voir les {total} propositions dans Positions groupes")}
+                                    </A>
+                                </p>
+                            })}
+                        }.into_view()
+                    }
+                }}
+            </div>
+
+            <div style="padding:0.75rem 1rem;background:var(--bg-secondary);border:1px solid var(--bg-border);border-radius:6px;font-size:0.75rem;color:var(--text-muted);line-height:1.6;">
+                "Indicateurs calculés sur la fenêtre LEG pour les membres actuels ; les amendements comptent pour le groupe de leur auteur à la date de dépôt. Les arrivées et départs sont déduits du groupe sous lequel chaque député est décompté dans les scrutins. "
+                <A href=crate::app_path!("/methodologie") attr:style="color:var(--accent);">"→ Méthodologie"</A>
+            </div>
+        </div>
+    }
+}
@@ -2,13 +2,15 @@ use leptos::*;
 use leptos_router::A;
 use std::collections::HashSet;
 
+use crate::api::fetch_series_index;
 use crate::store::use_store;
 use crate::models::*;
-use crate::utils::{fmt_pct, matches_search, groupe_color, app_href};
+use crate::utils::{fmt_pct, matches_search, groupe_color, app_href, legislature_label};
 use crate::components::{
     skeleton::SkeletonTable,
     period_selector::PeriodSelector,
     rate_bar::RateBar,
+    sparkline::Sparkline,
     tooltip::InfoIcon,
 };
 
@@ -19,6 +21,8 @@ fn period_window_suffix(period: Period) -> &'static str {
         Period::P30 => "sur les 30 derniers jours",
         Period::P180 => "sur les 180 derniers jours",
         Period::LEG => "sur la législature",
+        Period::Custom => "sur la période choisie",
+        Period::Session => "sur la session choisie",
     }
 }
 
@@ -27,6 +31,8 @@ fn period_window_short(period: Period) -> &'static str {
         Period::P30 => "fenêtre 30 jours",
         Period::P180 => "fenêtre 180 jours",
         Period::LEG => "législature",
+        Period::Custom => "dates choisies",
+        Period::Session => "session",
     }
 }
 
@@ -52,15 +58,33 @@ pub fn HomePage() -> impl IntoView {
         set_page.set(0);
     });
 
+    // Aperçu hebdomadaire (26 dernières semaines) pour la colonne Tendance
+    let series_index = create_resource(|| (), |_| async move { fetch_series_index().await });
+    let trend_by_id = create_memo(move |_| {
+        series_index
+            .get()
+            .and_then(|r| r.ok())
+            .map(|idx| idx.participation)
+            .unwrap_or_default()
+    });
+
     let store_for_stats  = store.clone();
     let store_for_status = store.clone();
     let store_for_hero   = store.clone();
+    let store_for_leg    = store.clone();
     let raw_stats = create_memo(move |_| {
         store_for_stats
             .stats_for(period.get())  // Retourne Resource
             .get()  //  Resource.get() → Option<Result<Vec>>
     });
 
+    let leg_label = create_memo(move |_| {
+        store_for_leg.status.get()
+            .and_then(|r| r.ok())
+            .map(|s| legislature_label(s.legislature))
+            .unwrap_or_else(|| "législature en cours".to_string())
+    });
+
     // Chiffres clés calculés depuis le dataset chargé (période sélectionnée)
     let hero_stats = create_memo(move |_| {
         let selected_period = period.get();
@@ -193,7 +217,7 @@ pub fn HomePage() -> impl IntoView {
     };
 
     // Nombre de colonnes selon l'état du toggle
-    let col_count = move || if show_detail_cols.get() { 12 } else { 8 };
+    let col_count = move || if show_detail_cols.get() { 13 } else { 9 };
 
     view! {
         <div class="reveal">
@@ -206,7 +230,7 @@ pub fn HomePage() -> impl IntoView {
                             "Activité parlementaire observable"
                         </h1>
                         <p style="color:var(--text-muted);font-size:0.82rem;margin:0;">
-                            "Assemblée nationale · 17e législature · Données open data officielles"
+                            {move || format!("Assemblée nationale · {} · Données open data officielles", leg_label.get())}
                         </p>
                         <p style="color:var(--text-muted);font-size:0.74rem;margin:0.25rem 0 0 0;">
                             "Période affichée : "
@@ -224,7 +248,7 @@ pub fn HomePage() -> impl IntoView {
                         <HeroStat
                             value=nb.to_string()
                             label="députés suivis"
-                            sub="mandat actif 17e législature"
+                            sub=format!("mandat actif {}", leg_label.get())
                         />
                         <HeroStat
                             value=format!("{:.1}%", median * 100.0)
@@ -391,6 +415,12 @@ pub fn HomePage() -> impl IntoView {
                                     <InfoIcon text="Taux de votes exprimés (Pour/Contre/Abstention) sur scrutins publics où le député avait un mandat actif. Ne mesure pas la présence physique." />
                                 </span>
                             </th>
+                            <th>
+                                <span style="display:inline-flex;align-items:center;gap:0.25rem;">
+                                    "Tendance"
+                                    <InfoIcon text="Participation par semaine ISO sur les 26 dernières semaines (toutes périodes confondues). Les semaines sans scrutin éligible interrompent la courbe." />
+                                </span>
+                            </th>
                             <SortTh label="Scrutins"    field=SortField::ScrutinsEligibles sf=sort_field sd=sort_dir handle_sort=handle_sort />
                             // Colonnes détail — masquées par défaut
                             {move || if show_detail_cols.get() { view! {
@@ -455,6 +485,11 @@ pub fn HomePage() -> impl IntoView {
                                     let show_det   = show_detail_cols.get();
                                     let nom_display = format!("{} {}", d.prenom, d.nom);
                                     let nom_aria    = format!("Voir la fiche de {} {}", d.prenom, d.nom);
+                                    let trend: Vec<Option<f64>> = trend_by_id.with(|m| {
+                                        m.get(&id)
+                                            .map(|v| v.iter().map(|p| p.map(f64::from)).collect())
+                                            .unwrap_or_default()
+                                    });
                                     view! {
                                         <tr>
                                             <td class="td-nom">
@@ -472,6 +507,13 @@ pub fn HomePage() -> impl IntoView {
                                                 })}
                                             </td>
                                             <td class="td-participation"><RateBar rate=d.participation_rate /></td>
+                                            <td class="td-trend">
+                                                {if trend.is_empty() {
+                                                    view! { <span style="color:var(--text-muted);">"—"</span> }.into_view()
+                                                } else {
+                                                    view! { <Sparkline values=trend max=Some(100.0) width=80 height=22 title="Participation hebdomadaire (26 semaines)".to_string() /> }.into_view()
+                                                }}
+                                            </td>
                                             <td style="font-variant-numeric:tabular-nums;color:var(--text-secondary);font-size:0.8rem;">
                                                 {d.votes_exprimes}"/"{d.scrutins_eligibles}
                                             </td>
@@ -554,7 +596,7 @@ pub fn HomePage() -> impl IntoView {
 fn HeroStat(
     value: String,
     label: &'static str,
-    sub: &'static str,
+    #[prop(into)] sub: String,
     #[prop(optional)] accent: bool,
 ) -> impl IntoView {
     view! {
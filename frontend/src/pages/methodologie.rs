@@ -1,5 +1,6 @@
 use leptos::*;
 use crate::api::{fetch_status, inferred_github_repo_urls};
+use crate::utils::legislature_label;
 
 #[component]
 pub fn MethodePage() -> impl IntoView {
@@ -7,6 +8,8 @@ pub fn MethodePage() -> impl IntoView {
     let repo_links = inferred_github_repo_urls();
     let repo_url = repo_links.as_ref().map(|(r, _)| r.clone());
     let issue_url = repo_links.as_ref().map(|(_, i)| i.clone());
+    // 17 tant que status.json n'est pas chargé (législature par défaut du pipeline)
+    let legislature = move || status.get().and_then(|r| r.ok()).map(|s| s.legislature).unwrap_or(17);
 
     view! {
         <div class="reveal" style="max-width:860px;">
@@ -44,45 +47,92 @@ pub fn MethodePage() -> impl IntoView {
 
             <Section title="Sources de données">
                 <p>"Toutes les données proviennent de "<a href="https://data.assemblee-nationale.fr" target="_blank" rel="noopener" style="color:var(--accent);">"data.assemblee-nationale.fr"</a>", Open Data officiel de l'Assemblée nationale, sous Licence Ouverte v2.0 (Etalab)."</p>
-                <p>"Nous utilisons les datasets de la 17e législature :"</p>
+                <p>
+                    "Nous utilisons les datasets de la "
+                    {move || legislature_label(legislature())}
+                    " :"
+                </p>
                 <table class="data-table" style="margin-top:0.75rem;">
                     <thead><tr><th>"Dataset"</th><th>"Contenu"</th><th>"URL"</th></tr></thead>
                     <tbody>
                         <tr>
                             <td style="font-family:monospace;font-size:0.75rem;">"AMO10_deputes_actifs..."</td>
                             <td>"Députés en exercice, mandats, organes (groupes, partis)"</td>
-                            <td><a href="http://data.assemblee-nationale.fr/static/openData/repository/17/amo/deputes_actifs_mandats_actifs_organes/AMO10_deputes_actifs_mandats_actifs_organes.json.zip" target="_blank" rel="noopener" style="color:var(--accent);font-size:0.75rem;">"ZIP"</a></td>
+                            <td><a href=move || an_dataset_url(legislature(), "amo/deputes_actifs_mandats_actifs_organes/AMO10_deputes_actifs_mandats_actifs_organes.json.zip") target="_blank" rel="noopener" style="color:var(--accent);font-size:0.75rem;">"ZIP"</a></td>
                         </tr>
                         <tr>
                             <td style="font-family:monospace;font-size:0.75rem;">"Scrutins.json.zip"</td>
                             <td>"Résultats de l'ensemble des scrutins publics, avec position par acteur"</td>
-                            <td><a href="http://data.assemblee-nationale.fr/static/openData/repository/17/loi/scrutins/Scrutins.json.zip" target="_blank" rel="noopener" style="color:var(--accent);font-size:0.75rem;">"ZIP"</a></td>
+                            <td><a href=move || an_dataset_url(legislature(), "loi/scrutins/Scrutins.json.zip") target="_blank" rel="noopener" style="color:var(--accent);font-size:0.75rem;">"ZIP"</a></td>
                         </tr>
                         <tr>
                             <td style="font-family:monospace;font-size:0.75rem;">"Amendements.json.zip"</td>
                             <td>"Tous les amendements déposés, avec auteur(s), sort et dossier"</td>
-                            <td><a href="http://data.assemblee-nationale.fr/static/openData/repository/17/loi/amendements_div_legis/Amendements.json.zip" target="_blank" rel="noopener" style="color:var(--accent);font-size:0.75rem;">"ZIP"</a></td>
+                            <td><a href=move || an_dataset_url(legislature(), "loi/amendements_div_legis/Amendements.json.zip") target="_blank" rel="noopener" style="color:var(--accent);font-size:0.75rem;">"ZIP"</a></td>
                         </tr>
                         <tr>
                             <td style="font-family:monospace;font-size:0.75rem;">"Dossiers_Legislatifs.json.zip"</td>
                             <td>"Dossiers législatifs (titres, statuts)"</td>
-                            <td><a href="http://data.assemblee-nationale.fr/static/openData/repository/17/loi/dossiers_legislatifs/Dossiers_Legislatifs.json.zip" target="_blank" rel="noopener" style="color:var(--accent);font-size:0.75rem;">"ZIP"</a></td>
+                            <td><a href=move || an_dataset_url(legislature(), "loi/dossiers_legislatifs/Dossiers_Legislatifs.json.zip") target="_blank" rel="noopener" style="color:var(--accent);font-size:0.75rem;">"ZIP"</a></td>
+                        </tr>
+                        <tr>
+                            <td style="font-family:monospace;font-size:0.75rem;">"syseron.xml.zip"</td>
+                            <td>"Comptes rendus des séances publiques (XML Syceron) : interventions en séance"</td>
+                            <td><a href=move || an_dataset_url(legislature(), "vp/syceronbrut/syseron.xml.zip") target="_blank" rel="noopener" style="color:var(--accent);font-size:0.75rem;">"ZIP"</a></td>
+                        </tr>
+                        <tr>
+                            <td style="font-family:monospace;font-size:0.75rem;">"Questions_ecrites.json.zip"</td>
+                            <td>"Questions écrites : auteur, ministère, rubrique, dates de publication et de réponse au JO"</td>
+                            <td><a href=move || an_dataset_url(legislature(), "questions/questions_ecrites/Questions_ecrites.json.zip") target="_blank" rel="noopener" style="color:var(--accent);font-size:0.75rem;">"ZIP"</a></td>
+                        </tr>
+                        <tr>
+                            <td style="font-family:monospace;font-size:0.75rem;">"Questions_orales_sans_debat.json.zip"</td>
+                            <td>"Questions orales sans débat"</td>
+                            <td><a href=move || an_dataset_url(legislature(), "questions/questions_orales_sans_debat/Questions_orales_sans_debat.json.zip") target="_blank" rel="noopener" style="color:var(--accent);font-size:0.75rem;">"ZIP"</a></td>
+                        </tr>
+                        <tr>
+                            <td style="font-family:monospace;font-size:0.75rem;">"Questions_gouvernement.json.zip"</td>
+                            <td>"Questions au Gouvernement (séances de questions)"</td>
+                            <td><a href=move || an_dataset_url(legislature(), "questions/questions_gouvernement/Questions_gouvernement.json.zip") target="_blank" rel="noopener" style="color:var(--accent);font-size:0.75rem;">"ZIP"</a></td>
+                        </tr>
+                        <tr>
+                            <td style="font-family:monospace;font-size:0.75rem;">"Agenda.json.zip"</td>
+                            <td>"Réunions des commissions et organes, avec la présence des députés convoqués"</td>
+                            <td><a href=move || an_dataset_url(legislature(), "vp/reunions/Agenda.json.zip") target="_blank" rel="noopener" style="color:var(--accent);font-size:0.75rem;">"ZIP"</a></td>
                         </tr>
                     </tbody>
                 </table>
-                <Note>"Note : Le dataset des débats (interventions en séance, syseron.xml.zip) est en cours d'intégration. Les colonnes interventions_count et interventions_chars affichent 0 en V1."</Note>
+                <Note>"Note : Les datasets des débats, des questions et de l'agenda sont optionnels. S'ils sont indisponibles lors d'une mise à jour, les colonnes correspondantes (interventions_*, questions_*, commission_reunions…) affichent 0."</Note>
             </Section>
 
             <Section title="Fenêtres temporelles">
-                <p>"Trois fenêtres sont disponibles :"</p>
+                <p>"Quatre fenêtres précalculées sont disponibles, plus une fenêtre libre :"</p>
                 <ul style="padding-left:1.5rem;line-height:2;">
                     <li><strong>"P30"</strong>" : 30 derniers jours glissants (depuis la date de mise à jour)"</li>
                     <li><strong>"P180"</strong>" : 180 derniers jours glissants"</li>
-                    <li><strong>"LEG"</strong>" : Depuis le début de la 17e législature (19 juin 2022) ou depuis le début du mandat si le député est entré après"</li>
+                    <li>
+                        <strong>"LEG"</strong>
+                        " : Depuis le début de la législature"
+                        {move || status.get().and_then(|r| r.ok()).and_then(|s| s.legislature_start).map(|d| format!(" ({})", d.format("%d/%m/%Y"))).unwrap_or_default()}
+                        " ou depuis le début du mandat si le député est entré après"
+                    </li>
+                    <li><strong>"Sessions"</strong>" : une session parlementaire de la législature. Session ordinaire du 1er octobre au 30 juin ; session extraordinaire de juillet à septembre, proposée seulement si au moins un scrutin y a eu lieu. Contrairement aux fenêtres glissantes, ces périodes ne mélangent pas séances et intersessions"</li>
+                    <li><strong>"Dates"</strong>" : n'importe quel intervalle [début, fin] depuis le début de la législature, recalculé dans le navigateur à partir des compteurs journaliers de chaque député"</li>
                 </ul>
+                <p>"Sur une fenêtre « Dates », seuls les scrutins (éligibles, exprimés, pour/contre/abstention, non-votants) et les amendements datés (déposés, adoptés, cosignés) sont recalculés. Les amendements sans date, les dossiers principaux, les co-signataires, la loyauté au groupe et la ventilation solennel/ordinaire ne sont disponibles que sur les fenêtres précalculées."</p>
                 <p>"Pour chaque député, la fenêtre effective est l'intersection de la période choisie et de la durée de son mandat. Un député entré en cours de législature n'est comptabilisé que sur les scrutins et amendements postérieurs à son entrée en fonction."</p>
             </Section>
 
+            <Section title="Séries hebdomadaires">
+                <p>"Les courbes « Tendance » (tableau d'accueil) et « Évolution hebdomadaire » (fiche député) découpent la législature en semaines ISO, du lundi au dimanche, à partir de la semaine du début de législature."</p>
+                <ul style="padding-left:1.5rem;line-height:2;">
+                    <li>"Chaque scrutin, vote et amendement est rattaché à la semaine de sa date, uniquement si le député était en mandat ce jour-là"</li>
+                    <li>"La participation hebdomadaire vaut votes exprimés / scrutins éligibles de la semaine ; une semaine sans scrutin éligible n'a pas de valeur et interrompt la courbe"</li>
+                    <li>"Les séries de groupe additionnent, jour par jour, celles des députés membres du groupe à cette date : un député qui change de groupe compte pour l'ancien jusqu'à son départ, pour le nouveau ensuite"</li>
+                    <li>"Le tableau d'accueil n'affiche que les 26 dernières semaines, quelle que soit la période sélectionnée"</li>
+                </ul>
+            </Section>
+
             <Section title="A — Participation aux scrutins publics">
                 <p>"Définitions :"</p>
                 <ul style="padding-left:1.5rem;line-height:2;">
@@ -91,10 +141,42 @@ pub fn MethodePage() -> impl IntoView {
                     <li><strong>"non_votant"</strong>" : Position NON_VOTANT enregistrée dans le dataset (délégation de vote, absence déclarée…)."</li>
                     <li><strong>"absent"</strong>" : Scrutin éligible sans aucune position enregistrée."</li>
                     <li><strong>"participation_rate"</strong>" = votes_exprimes / scrutins_eligibles."</li>
+                    <li><strong>"mises_au_point"</strong>" : Scrutins pour lesquels le député a fait une mise au point (miseAuPoint), c'est-à-dire déclaré après coup qu'il souhaitait voter autrement. La position voulue est affichée sur la page du scrutin ; le décompte officiel, et donc les indicateurs ci-dessus, restent basés sur la position enregistrée."</li>
+                    <li><strong>"votes_par_delegation"</strong>" : Votes enregistrés avec l'indicateur parDelegation (vote exprimé par un collègue au nom du député)."</li>
                 </ul>
                 <Note>"⚠ Ce n'est pas une mesure de présence physique en hémicycle. Un député peut voter depuis l'une des travées, depuis une salle de vote déportée, ou via délégation selon les règles en vigueur. La position enregistrée dans les données open data est la seule information disponible."</Note>
             </Section>
 
+            <Section title="A′ — Scrutins solennels et scrutins ordinaires">
+                <p>"Chaque scrutin porte un type de vote (typeVote) publié par l'AN. Les scrutins publics solennels (SPS) sont annoncés à l'avance par la Conférence des présidents et portent sur les votes les plus importants (ensemble d'un projet de loi, budget…) ; les scrutins publics ordinaires (SPO) peuvent être demandés en cours de séance, y compris tard le soir."</p>
+                <ul style="padding-left:1.5rem;line-height:2;">
+                    <li><strong>"scrutins_solennels"</strong>" / "<strong>"votes_solennels_exprimes"</strong>" : scrutins SPS éligibles et votes exprimés sur ces scrutins."</li>
+                    <li><strong>"participation_rate_solennel"</strong>" = votes_solennels_exprimes / scrutins_solennels (null s'il n'y a aucun scrutin solennel sur la période)."</li>
+                    <li><strong>"scrutins_ordinaires"</strong>" / "<strong>"votes_ordinaires_exprimes"</strong>" / "<strong>"participation_rate_ordinaire"</strong>" : même calcul sur les scrutins SPO."</li>
+                </ul>
+                <Note>"La participation aux scrutins solennels est la comparaison la plus équitable entre députés : ces votes sont programmés et concentrent la présence en séance. Les motions de censure (seuls les votes « pour » y sont recensés) et les scrutins de type inconnu n'entrent dans aucune des deux catégories, mais restent comptés dans participation_rate."</Note>
+            </Section>
+
+            <Section title="A bis — Vote avec le groupe">
+                <ul style="padding-left:1.5rem;line-height:2;">
+                    <li><strong>"loyalty_votes"</strong>" : Votes exprimés (Pour, Contre, Abstention) sur les scrutins où le groupe sous lequel le député est décompté publie une position majoritaire exprimée."</li>
+                    <li><strong>"dissent_count"</strong>" : Parmi ces votes, ceux qui diffèrent de la position majoritaire du groupe."</li>
+                    <li><strong>"loyalty_rate"</strong>" = 1 − dissent_count / loyalty_votes (null si loyalty_votes = 0)."</li>
+                </ul>
+                <Note>"La position majoritaire est celle publiée par l'AN pour chaque groupe (positionMajoritaire) ; elle ne reflète pas nécessairement une consigne de vote officielle."</Note>
+            </Section>
+
+            <Section title="A ter — Fiches groupe">
+                <ul style="padding-left:1.5rem;line-height:2;">
+                    <li><strong>"Membres"</strong>" : députés dont le groupe actuel (mandat GP en cours) est le groupe ; indicateurs de la fenêtre LEG."</li>
+                    <li><strong>"participation_median"</strong>" : médiane des participation_rate des membres ayant au moins un scrutin éligible."</li>
+                    <li><strong>"cohesion_moyenne"</strong>" : pour chaque scrutin de la période où le groupe a exprimé des votes, part des votes exprimés du groupe identiques à sa position la plus fréquente (pour, contre ou abstention) ; moyenne sur ces scrutins. Un scrutin est « unanime » quand cette part vaut 100 %."</li>
+                    <li><strong>"Arrivées et départs"</strong>" : déduits du groupe sous lequel chaque député est décompté dans la ventilation des scrutins, la date retenue étant celle du premier scrutin dans le nouveau groupe."</li>
+                    <li><strong>"amd_authored"</strong>" / "<strong>"amd_adopted"</strong>" : amendements de la période dont l'auteur était membre du groupe à la date de l'amendement, anciens membres compris ; un amendement sans date compte pour le groupe actuel de son auteur."</li>
+                    <li><strong>"Propositions de loi"</strong>" : agrégat PPL par groupe (signataires auteurs ou cosignataires membres du groupe à la date de dépôt du texte, à défaut de leur groupe actuel)."</li>
+                </ul>
+            </Section>
+
             <Section title="B — Amendements">
                 <ul style="padding-left:1.5rem;line-height:2;">
                     <li><strong>"amd_authored"</strong>" : Amendements où le député figure comme auteur principal (signataire 1)."</li>
@@ -104,18 +186,49 @@ pub fn MethodePage() -> impl IntoView {
                 </ul>
             </Section>
 
+            <Section title="B bis — Interventions en séance">
+                <ul style="padding-left:1.5rem;line-height:2;">
+                    <li><strong>"interventions_count"</strong>" : Prises de parole du député dans les comptes rendus des séances publiques. Les paragraphes consécutifs d'un même orateur sur un même point de l'ordre du jour comptent pour une seule intervention."</li>
+                    <li><strong>"interventions_chars"</strong>" : Nombre de caractères du texte de ces interventions (espaces normalisés)."</li>
+                    <li>"Les interventions au titre de la présidence de séance ne sont pas comptées. Les travaux en commission ne sont pas inclus."</li>
+                    <li>"Rattachement au dossier : le titre du point débattu est comparé aux titres des dossiers législatifs ; sans correspondance unique, l'intervention compte dans les totaux mais pour aucun dossier."</li>
+                </ul>
+            </Section>
+
+            <Section title="B ter — Questions au Gouvernement">
+                <ul style="padding-left:1.5rem;line-height:2;">
+                    <li><strong>"questions_ecrites"</strong>", "<strong>"questions_orales"</strong>", "<strong>"questions_gouvernement"</strong>" : Questions dont le député est l'auteur, datées par leur publication au Journal officiel (QAG : date de la séance)."</li>
+                    <li><strong>"questions_repondues"</strong>" : Questions écrites et orales sans débat dont la réponse est publiée au JO (date de réponse ou clôture « Réponse publiée »), quelle que soit la date de la réponse."</li>
+                    <li><strong>"questions_answer_rate"</strong>" = questions_repondues / (questions_ecrites + questions_orales) (null si aucune). Les questions au Gouvernement, posées et répondues en séance, en sont exclues."</li>
+                    <li>"Les questions retirées ou devenues caduques comptent comme posées mais non répondues."</li>
+                </ul>
+            </Section>
+
+            <Section title="B quater — Commissions">
+                <ul style="padding-left:1.5rem;line-height:2;">
+                    <li><strong>"Appartenances"</strong>" : mandats du député dans les commissions permanentes, d'enquête et mixtes paritaires (dataset députés), avec la qualité AN : président, vice-président, secrétaire, rapporteur général, membre…"</li>
+                    <li><strong>"commission_reunions"</strong>" : Réunions de commission ou d'organe de la période (date de début) dont la liste de présence mentionne le député, qu'il soit présent, absent ou excusé."</li>
+                    <li><strong>"commission_presence_rate"</strong>" = commission_presences / commission_reunions (null si aucune réunion). Un député excusé compte comme non présent ; commission_excuses permet de le distinguer d'une absence."</li>
+                    <li>"Les réunions annulées et celles sans liste de présence publiée sont ignorées. Le taux ne mesure que la présence déclarée dans le compte rendu, pas la durée ni la participation aux débats."</li>
+                </ul>
+            </Section>
+
             <Section title="C — Dossiers législatifs et score d'activité">
                 <p>"Pour chaque député, on calcule un score par dossier :"</p>
                 <div style="padding:0.75rem 1rem;background:var(--bg-secondary);border:1px solid var(--bg-border);border-radius:6px;font-family:monospace;font-size:0.82rem;margin:0.75rem 0;">
-                    "score = 1 × votes_in_dossier + 2 × amd_authored_in_dossier + 1 × interventions_in_dossier"
+                    "score = 1 × votes_in_dossier + 2 × amd_authored_in_dossier + 1 × interventions_in_dossier + 10 × rapporteur"
                 </div>
-                <p>"Le coefficient 2 sur les amendements reflète un engagement plus actif de rédaction (vs simplement participer au vote). Ce coefficient est arbitraire et documenté ici pour transparence."</p>
+                <p>"Le coefficient 2 sur les amendements reflète un engagement plus actif de rédaction (vs simplement participer au vote). Le bonus de 10 s'applique quand le député a été nommé rapporteur du dossier pendant la période (au fond, pour avis ou spécial) : le rapporteur porte l'essentiel du travail sur le texte, sans qu'il apparaisse dans les votes ou les amendements. Ces coefficients sont arbitraires et documentés ici pour transparence."</p>
+                <ul style="padding-left:1.5rem;line-height:2;">
+                    <li><strong>"rapporteur_fond"</strong>" / "<strong>"rapporteur_avis"</strong>" / "<strong>"rapporteur_special"</strong>" : dossiers pour lesquels le député a été nommé rapporteur au fond (y compris rapporteur général), pour avis ou rapporteur spécial (budget) sur la période, d'après les actes de nomination des dossiers législatifs. Plusieurs nominations au même rôle sur un dossier comptent une fois ; une nomination sans date prend la date de dépôt du dossier."</li>
+                </ul>
                 <p>"Seuls les 10 dossiers au score le plus élevé sont affichés par député."</p>
             </Section>
 
             <Section title="D — Groupes et partis">
                 <ul style="padding-left:1.5rem;line-height:2;">
                     <li><strong>"Groupe parlementaire"</strong>" : Regroupement officiel au sein de l'Assemblée nationale. Correspond à l'organe de type GP dans les données."</li>
+                    <li><strong>"Parcours"</strong>" : groupes successifs du député, en tant que membre ou apparenté (qualité AN « Membre apparenté »). Le dataset des députés en exercice ne publie que les mandats en cours ; les périodes antérieures sont déduites du groupe sous lequel le député est décompté dans les scrutins (du premier scrutin dans un groupe à la veille du premier scrutin dans le suivant) et signalées comme telles."</li>
                     <li><strong>"Parti de rattachement"</strong>" : Organisation politique déclarée par le député (organe de type PARPOL). Peut être absent, non fiable ou différent du groupe."</li>
                 </ul>
                 <Note>"La distinction entre groupe et parti est importante : un même parti peut avoir des membres dans plusieurs groupes, et un groupe peut rassembler des membres de plusieurs partis."</Note>
@@ -180,6 +293,10 @@ pub fn MethodePage() -> impl IntoView {
     }
 }
 
+fn an_dataset_url(legislature: u32, path: &str) -> String {
+    format!("http://data.assemblee-nationale.fr/static/openData/repository/{legislature}/{path}")
+}
+
 #[component]
 fn Section(title: &'static str, children: Children) -> impl IntoView {
     view! {
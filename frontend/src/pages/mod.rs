@@ -11,3 +11,8 @@ pub mod reseau;
 pub mod positions_groupes;
 
 pub mod amendements;
+
+pub mod scrutin;
+pub mod scrutins;
+
+pub mod groupe;
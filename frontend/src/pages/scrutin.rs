@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+
+use leptos::*;
+use leptos_router::*;
+
+use crate::api::fetch_scrutin_detail;
+use crate::components::skeleton::SkeletonKpi;
+use crate::models::*;
+use crate::store::use_store;
+use crate::utils::{app_href, groupe_color, type_vote_label};
+
+#[component]
+pub fn ScrutinPage() -> impl IntoView {
+    let store = use_store();
+    let params = use_params_map();
+    let scrutin_id = move || params.with(|p| p.get("id").cloned().unwrap_or_default());
+
+    let detail_res = create_resource(scrutin_id, |id| async move { fetch_scrutin_detail(&id).await });
+
+    view! {
+        <div>
+            <div style="margin-bottom:1rem;">
+                <A href=crate::app_path!("/scrutins") attr:style="color:var(--accent);font-size:0.82rem;text-decoration:none;">
+                    "← Retour aux scrutins"
+                </A>
+            </div>
+
+            {move || match detail_res.get() {
+                None => view! {
+                    <div style="display:grid;grid-template-columns:repeat(auto-fill,minmax(180px,1fr));gap:1rem;margin-bottom:1.5rem;">
+                        <SkeletonKpi/><SkeletonKpi/><SkeletonKpi/><SkeletonKpi/>
+                    </div>
+                }.into_view(),
+                Some(Err(e)) => view! {
+                    <div style="text-align:center;padding:3rem;color:var(--text-muted);">
+                        <p style="font-size:1.2rem;margin-bottom:0.5rem;">"Scrutin non trouvé"</p>
+                        <p style="font-size:0.82rem;">{format!("L'identifiant {} est introuvable ({e}).", scrutin_id())}</p>
+                    </div>
+                }.into_view(),
+                Some(Ok(s)) => {
+                    let legislature = store.status.get()
+                        .and_then(|r| r.ok())
+                        .map(|st| st.legislature)
+                        .unwrap_or(17);
+                    view! { <ScrutinDetailView s=s legislature=legislature /> }.into_view()
+                }
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn ScrutinDetailView(s: ScrutinDetail, legislature: u32) -> impl IntoView {
+    let an_url = format!("https://www.assemblee-nationale.fr/dyn/{}/scrutins/{}", legislature, s.numero);
+    let groupe_abrevs: HashMap<String, String> = s.groupes.iter()
+        .filter_map(|g| g.abrev.clone().map(|a| (g.organe_ref.clone(), a)))
+        .collect();
+
+    let total_exprimes = s.totals.pour + s.totals.contre + s.totals.abstentions;
+    let pct = |n: u32| if total_exprimes > 0 { n as f64 / total_exprimes as f64 * 100.0 } else { 0.0 };
+    let (pour_pct, contre_pct, abst_pct) = (pct(s.totals.pour), pct(s.totals.contre), pct(s.totals.abstentions));
+
+    let mut groupes = s.groupes.clone();
+    groupes.sort_by(|a, b| {
+        let ta = a.pour + a.contre + a.abstentions + a.non_votants;
+        let tb = b.pour + b.contre + b.abstentions + b.non_votants;
+        tb.cmp(&ta).then_with(|| a.organe_ref.cmp(&b.organe_ref))
+    });
+
+    view! {
+        <div class="reveal">
+            // En-tête
+            <div style="margin-bottom:1.5rem;padding-bottom:1.25rem;border-bottom:1px solid var(--bg-border);">
+                <div style="display:flex;align-items:center;gap:0.5rem;flex-wrap:wrap;margin-bottom:0.45rem;">
+                    <span style="font-size:0.74rem;color:var(--text-muted);padding:0.15rem 0.45rem;border:1px solid var(--bg-border);border-radius:999px;">
+                        {format!("Scrutin n° {}", s.numero)}
+                    </span>
+                    <span style="font-size:0.74rem;color:var(--text-muted);">
+                        {s.date.map(|d| d.format("%d/%m/%Y").to_string()).unwrap_or_else(|| "Date inconnue".to_string())}
+                    </span>
+                    {s.type_vote_libelle.clone().or_else(|| s.type_vote.as_deref().map(|c| type_vote_label(c).to_string())).map(|t| view! {
+                        <span style="font-size:0.74rem;color:var(--text-secondary);">{t}</span>
+                    })}
+                    {s.sort.clone().map(|sort| view! {
+                        <span class="badge" style="font-size:0.72rem;padding:0.15rem 0.5rem;">{sort}</span>
+                    })}
+                </div>
+                <h1 style="font-size:1.3rem;font-weight:700;margin:0 0 0.5rem 0;line-height:1.35;">{s.titre.clone()}</h1>
+                {s.dossier_ref.clone().map(|dref| view! {
+                    <p style="margin:0 0 0.35rem 0;font-size:0.8rem;color:var(--text-secondary);">
+                        "Dossier : "
+                        <span>{s.dossier_titre.clone().unwrap_or_else(|| dref.clone())}</span>
+                        <span style="font-family:monospace;font-size:0.7rem;color:var(--text-muted);margin-left:0.4rem;">{dref.clone()}</span>
+                    </p>
+                })}
+                <div style="display:flex;gap:.5rem;flex-wrap:wrap;margin-top:0.6rem;">
+                    <a href=an_url target="_blank" rel="noopener noreferrer" style="font-size:.75rem;padding:.35rem .65rem;border:1px solid var(--bg-border);border-radius:8px;background:rgba(255,255,255,0.01);color:var(--text-primary);text-decoration:none;">"Source AN ↗"</a>
+                    <span style="font-size:.72rem;color:var(--text-muted);font-family:monospace;align-self:center;">{s.id.clone()}</span>
+                </div>
+            </div>
+
+            // Résultat global
+            <div style="margin-bottom:1.75rem;padding:1.25rem;background:var(--bg-secondary);border:1px solid var(--bg-border);border-radius:8px;">
+                <h2 style="font-size:0.85rem;font-weight:600;margin:0 0 1rem 0;text-transform:uppercase;letter-spacing:0.06em;color:var(--text-muted);">
+                    "Résultat (votes exprimés)"
+                </h2>
+                <div style="display:flex;gap:2px;height:20px;border-radius:4px;overflow:hidden;margin-bottom:0.75rem;"
+                    role="img"
+                    aria-label=format!("Pour {:.1}%, Contre {:.1}%, Abstention {:.1}%", pour_pct, contre_pct, abst_pct)>
+                    <div style=format!("width:{pour_pct:.1}%;background:var(--success);")></div>
+                    <div style=format!("width:{contre_pct:.1}%;background:var(--danger);")></div>
+                    <div style=format!("width:{abst_pct:.1}%;background:var(--warning);")></div>
+                </div>
+                <div style="display:flex;gap:1.5rem;flex-wrap:wrap;font-size:0.78rem;">
+                    <span><span style="color:var(--success);">"■"</span>{format!(" Pour : {}", s.totals.pour)}</span>
+                    <span><span style="color:var(--danger);">"■"</span>{format!(" Contre : {}", s.totals.contre)}</span>
+                    <span><span style="color:var(--warning);">"■"</span>{format!(" Abstention : {}", s.totals.abstentions)}</span>
+                    <span style="color:var(--text-muted);">{format!("Non-votants : {}", s.totals.non_votants)}</span>
+                </div>
+            </div>
+
+            // Ventilation par groupe
+            {if groupes.is_empty() {
+                view! { <span></span> }.into_view()
+            } else {
+                view! {
+                    <div style="margin-bottom:1.75rem;">
+                        <h2 style="font-size:0.85rem;font-weight:600;margin:0 0 0.75rem 0;text-transform:uppercase;letter-spacing:0.06em;color:var(--text-muted);">
+                            "Vote des groupes"
+                        </h2>
+                        <div style="background:var(--bg-secondary);border:1px solid var(--bg-border);border-radius:8px;overflow:auto;">
+                            <table class="data-table">
+                                <thead>
+                                    <tr>
+                                        <th>"Groupe"</th>
+                                        <th>"Position majoritaire"</th>
+                                        <th>"Pour"</th>
+                                        <th>"Contre"</th>
+                                        <th>"Abst."</th>
+                                        <th>"Non-votants"</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {groupes.into_iter().map(|g| {
+                                        let color = groupe_color(g.abrev.as_deref());
+                                        view! {
+                                            <tr>
+                                                <td>
+                                                    <span style=format!("font-weight:600;color:{color};")>
+                                                        {g.abrev.clone().unwrap_or_else(|| g.organe_ref.clone())}
+                                                    </span>
+                                                    {g.libelle.clone().map(|l| view! {
+                                                        <span style="display:block;font-size:0.7rem;color:var(--text-muted);">{l}</span>
+                                                    })}
+                                                </td>
+                                                <td>{g.position_majoritaire.as_ref().map(VotePosition::label).unwrap_or("—")}</td>
+                                                <td>{g.pour}</td>
+                                                <td>{g.contre}</td>
+                                                <td>{g.abstentions}</td>
+                                                <td style="color:var(--text-muted);">{g.non_votants}</td>
+                                            </tr>
+                                        }
+                                    }).collect_view()}
+                                </tbody>
+                            </table>
+                        </div>
+                    </div>
+                }.into_view()
+            }}
+
+            // Listes nominatives
+            <div style="margin-bottom:1.75rem;">
+                <h2 style="font-size:0.85rem;font-weight:600;margin:0 0 0.75rem 0;text-transform:uppercase;letter-spacing:0.06em;color:var(--text-muted);">
+                    "Votes nominatifs"
+                </h2>
+                <div style="display:grid;grid-template-columns:repeat(auto-fit,minmax(240px,1fr));gap:0.75rem;">
+                    <VotantsColumn title="Pour" color="var(--success)" votants=s.pour.clone() groupes=groupe_abrevs.clone() />
+                    <VotantsColumn title="Contre" color="var(--danger)" votants=s.contre.clone() groupes=groupe_abrevs.clone() />
+                    <VotantsColumn title="Abstention" color="var(--warning)" votants=s.abstention.clone() groupes=groupe_abrevs.clone() />
+                    <VotantsColumn title="Non-votants" color="var(--text-muted)" votants=s.non_votant.clone() groupes=groupe_abrevs />
+                </div>
+            </div>
+
+            // Mises au point (position voulue déclarée après le vote)
+            {if s.mises_au_point.is_empty() {
+                view! { <span></span> }.into_view()
+            } else {
+                let mises_au_point = s.mises_au_point.clone();
+                view! {
+                    <div style="margin-bottom:1.75rem;">
+                        <h2 style="font-size:0.85rem;font-weight:600;margin:0 0 0.75rem 0;text-transform:uppercase;letter-spacing:0.06em;color:var(--text-muted);">
+                            "Mises au point"
+                        </h2>
+                        <div style="background:var(--bg-secondary);border:1px solid var(--bg-border);border-radius:8px;overflow:auto;">
+                            <table class="data-table">
+                                <thead>
+                                    <tr>
+                                        <th>"Député"</th>
+                                        <th>"Position enregistrée"</th>
+                                        <th>"Position voulue"</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {mises_au_point.into_iter().map(|m| {
+                                        let label = m.nom.clone().unwrap_or_else(|| m.deputy_id.clone());
+                                        view! {
+                                            <tr>
+                                                <td>
+                                                    <A href=app_href(&format!("/depute/{}", m.deputy_id)) attr:style="color:var(--text-primary);text-decoration:none;font-size:0.8rem;">
+                                                        {label}
+                                                    </A>
+                                                </td>
+                                                <td style="color:var(--text-muted);">{m.position_enregistree.as_ref().map(VotePosition::label).unwrap_or("Absent")}</td>
+                                                <td style="font-weight:600;">{m.position_voulue.label()}</td>
+                                            </tr>
+                                        }
+                                    }).collect_view()}
+                                </tbody>
+                            </table>
+                        </div>
+                        <p style="margin:0.4rem 0 0 0;font-size:0.72rem;color:var(--text-muted);">
+                            "Une mise au point ne modifie pas le résultat du scrutin : seule la position enregistrée est décomptée."
+                        </p>
+                    </div>
+                }.into_view()
+            }}
+
+            <div style="padding:0.75rem 1rem;background:var(--bg-secondary);border:1px solid var(--bg-border);border-radius:6px;font-size:0.75rem;color:var(--text-muted);line-height:1.6;">
+                "Positions telles que publiées dans le décompte nominatif de l'open data de l'Assemblée nationale (« délég. » : vote exprimé par délégation). "
+                <A href=crate::app_path!("/methodologie") attr:style="color:var(--accent);">"→ Méthodologie"</A>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn VotantsColumn(
+    title: &'static str,
+    color: &'static str,
+    votants: Vec<ScrutinVotant>,
+    groupes: HashMap<String, String>,
+) -> impl IntoView {
+    view! {
+        <div style=format!("background:var(--bg-secondary);border:1px solid var(--bg-border);border-top:3px solid {color};border-radius:8px;padding:0.75rem;")>
+            <div style="display:flex;justify-content:space-between;align-items:center;margin-bottom:0.5rem;">
+                <span style="font-size:0.8rem;font-weight:600;color:var(--text-primary);">{title}</span>
+                <span style="font-size:0.75rem;color:var(--text-muted);">{votants.len()}</span>
+            </div>
+            {if votants.is_empty() {
+                view! { <p style="margin:0;font-size:0.75rem;color:var(--text-muted);">"Aucun"</p> }.into_view()
+            } else {
+                view! {
+                    <ul style="list-style:none;margin:0;padding:0;max-height:420px;overflow:auto;display:flex;flex-direction:column;gap:0.2rem;">
+                        {votants.into_iter().map(|v| {
+                            let abrev = v.groupe_ref.as_ref().and_then(|g| groupes.get(g)).cloned();
+                            let label = v.nom.clone().unwrap_or_else(|| v.deputy_id.clone());
+                            view! {
+                                <li style="display:flex;justify-content:space-between;gap:0.5rem;font-size:0.76rem;">
+                                    <A href=app_href(&format!("/depute/{}", v.deputy_id)) attr:style="color:var(--text-primary);text-decoration:none;">
+                                        {label}
+                                    </A>
+                                    <span style="display:flex;gap:0.3rem;align-items:center;">
+                                        {v.par_delegation.then(|| view! {
+                                            <span title="Vote par délégation" style="font-size:0.64rem;color:var(--text-muted);">"délég."</span>
+                                        })}
+                                        {abrev.map(|a| view! {
+                                            <span style=format!("font-size:0.68rem;color:{};", groupe_color(Some(&a)))>{a.clone()}</span>
+                                        })}
+                                    </span>
+                                </li>
+                            }
+                        }).collect_view()}
+                    </ul>
+                }.into_view()
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,350 @@
+use leptos::*;
+use leptos_router::A;
+use std::collections::HashMap;
+
+use crate::api::{fetch_dossiers_min, fetch_scrutins_index};
+use crate::models::ScrutinIndexEntry;
+use crate::utils::{app_href, matches_search_normalized, normalize_search, type_vote_label};
+
+/// Nombre max de dossiers proposés dans le filtre (les plus votés).
+const DOSSIER_OPTIONS_LIMIT: usize = 200;
+
+fn is_adopte(sort: Option<&str>) -> Option<bool> {
+    let s = normalize_search(sort?);
+    if s.contains("adopt") {
+        Some(true)
+    } else if s.contains("rejet") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Page "Scrutins" : exploration de l'index compact (data/scrutins/index.json).
+///
+/// Tous les filtres sont appliqués côté navigateur sur l'index déjà chargé :
+/// recherche texte normalisée (titre, numéro, dossier, sort), intervalle de dates,
+/// résultat (adopté / rejeté), type de vote et dossier. Le détail nominatif d'un scrutin
+/// n'est chargé qu'à l'ouverture de sa page (`/scrutin/:id`).
+#[component]
+pub fn ScrutinsPage() -> impl IntoView {
+    let (filter, set_filter) = create_signal(String::new());
+    let (date_from, set_date_from) = create_signal(String::new());
+    let (date_to, set_date_to) = create_signal(String::new());
+    let (outcome, set_outcome) = create_signal(String::new()); // "", "adopte", "rejete"
+    let (type_filter, set_type_filter) = create_signal(String::new());
+    let (dossier_filter, set_dossier_filter) = create_signal(String::new());
+    let (page_size, set_page_size) = create_signal(20usize);
+    let (current_page, set_current_page) = create_signal(1usize);
+
+    let index_res = create_resource(|| (), |_| async move { fetch_scrutins_index().await });
+    // dossiers_min.json : mapping ID dossier -> titre
+    let dossiers_res = create_resource(|| (), |_| async move { fetch_dossiers_min().await });
+
+    let dossiers_map = create_memo(move |_| {
+        dossiers_res.get().and_then(|r| r.ok()).unwrap_or_default()
+    });
+
+    // Dossiers présents dans l'index, triés par nombre de scrutins
+    let dossier_options = create_memo(move |_| {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        if let Some(Ok(idx)) = index_res.get() {
+            for s in &idx.scrutins {
+                if let Some(d) = s.dossier_ref.as_ref() {
+                    *counts.entry(d.clone()).or_insert(0) += 1;
+                }
+            }
+        }
+        let mut v: Vec<(String, usize)> = counts.into_iter().collect();
+        v.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        v.truncate(DOSSIER_OPTIONS_LIMIT);
+        v
+    });
+
+    let filtered = create_memo(move |_| {
+        let idx = match index_res.get() {
+            Some(Ok(idx)) => idx,
+            _ => return Vec::<ScrutinIndexEntry>::new(),
+        };
+        let dos_map = dossiers_map.get();
+        let needle_norm = normalize_search(filter.get().trim());
+        let from = date_from.get();
+        let to = date_to.get();
+        let outcome_f = outcome.get();
+        let type_f = type_filter.get();
+        let dossier_f = dossier_filter.get();
+
+        idx.scrutins
+            .into_iter()
+            .filter(|s| {
+                // Les dates ISO se comparent lexicographiquement
+                let date = s.date.map(|d| d.to_string()).unwrap_or_default();
+                if !from.is_empty() && (date.is_empty() || date < from) {
+                    return false;
+                }
+                if !to.is_empty() && (date.is_empty() || date > to) {
+                    return false;
+                }
+                match outcome_f.as_str() {
+                    "adopte" if is_adopte(s.sort.as_deref()) != Some(true) => return false,
+                    "rejete" if is_adopte(s.sort.as_deref()) != Some(false) => return false,
+                    _ => {}
+                }
+                if !type_f.is_empty() && s.type_vote.as_deref() != Some(type_f.as_str()) {
+                    return false;
+                }
+                if !dossier_f.is_empty() && s.dossier_ref.as_deref() != Some(dossier_f.as_str()) {
+                    return false;
+                }
+                if needle_norm.is_empty() {
+                    return true;
+                }
+                let dossier_title = s.dossier_ref.as_deref()
+                    .and_then(|id| dos_map.get(id))
+                    .map(|t| t.as_str())
+                    .unwrap_or("");
+                let hay = format!(
+                    "{} {} {} {} {} {}",
+                    s.numero,
+                    s.id,
+                    s.titre,
+                    s.sort.as_deref().unwrap_or(""),
+                    s.dossier_ref.as_deref().unwrap_or(""),
+                    dossier_title,
+                );
+                matches_search_normalized(&hay, &needle_norm)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let reset_page = move || set_current_page.set(1);
+
+    view! {
+        <div style="max-width:1400px;margin:0 auto;padding:1.5rem;">
+            <div style="margin-bottom:1rem;">
+                <h1 style="margin:0;font-size:1.65rem;letter-spacing:-0.02em;">"Scrutins publics"</h1>
+                <p style="margin:0.35rem 0 0 0;color:var(--text-secondary);max-width:78ch;line-height:1.35;">
+                    "Tous les scrutins publics de la législature, du plus récent au plus ancien. Chaque ligne ouvre le détail du vote : ventilation par groupe et liste nominative."
+                </p>
+            </div>
+
+            <div class="kpi-card" style="margin-bottom:0.75rem;">
+                <div style="display:flex;gap:0.6rem;flex-wrap:wrap;align-items:flex-end;">
+                    <div style="flex:1;min-width:220px;">
+                        <label class="amd-label" for="scr-filter-input">"Recherche"</label>
+                        <input
+                            id="scr-filter-input"
+                            type="text"
+                            placeholder="ex: ensemble, budget, 1234…"
+                            aria-label="Rechercher un scrutin"
+                            prop:value=move || filter.get()
+                            on:input=move |ev| { set_filter.set(event_target_value(&ev)); reset_page(); }
+                            style="width:100%;"
+                        />
+                    </div>
+                    <div>
+                        <label class="amd-label" for="scr-date-from">"Du"</label>
+                        <input
+                            id="scr-date-from"
+                            type="date"
+                            prop:value=move || date_from.get()
+                            on:change=move |ev| { set_date_from.set(event_target_value(&ev)); reset_page(); }
+                        />
+                    </div>
+                    <div>
+                        <label class="amd-label" for="scr-date-to">"Au"</label>
+                        <input
+                            id="scr-date-to"
+                            type="date"
+                            prop:value=move || date_to.get()
+                            on:change=move |ev| { set_date_to.set(event_target_value(&ev)); reset_page(); }
+                        />
+                    </div>
+                    <div>
+                        <label class="amd-label" for="scr-outcome">"Résultat"</label>
+                        <select
+                            id="scr-outcome"
+                            class="amd-select"
+                            on:change=move |ev| { set_outcome.set(event_target_value(&ev)); reset_page(); }
+                        >
+                            <option value="" selected=move || outcome.get().is_empty()>"Tous"</option>
+                            <option value="adopte" selected=move || outcome.get() == "adopte">"Adopté"</option>
+                            <option value="rejete" selected=move || outcome.get() == "rejete">"Rejeté"</option>
+                        </select>
+                    </div>
+                    <div>
+                        <label class="amd-label" for="scr-type">"Type"</label>
+                        <select
+                            id="scr-type"
+                            class="amd-select"
+                            on:change=move |ev| { set_type_filter.set(event_target_value(&ev)); reset_page(); }
+                        >
+                            <option value="" selected=move || type_filter.get().is_empty()>"Tous types"</option>
+                            <option value="SPO" selected=move || type_filter.get() == "SPO">"Ordinaire"</option>
+                            <option value="SPS" selected=move || type_filter.get() == "SPS">"Solennel"</option>
+                            <option value="MOC" selected=move || type_filter.get() == "MOC">"Motion de censure"</option>
+                        </select>
+                    </div>
+                    <div style="min-width:220px;max-width:360px;">
+                        <label class="amd-label" for="scr-dossier">"Dossier"</label>
+                        <select
+                            id="scr-dossier"
+                            class="amd-select"
+                            style="width:100%;"
+                            on:change=move |ev| { set_dossier_filter.set(event_target_value(&ev)); reset_page(); }
+                        >
+                            <option value="" selected=move || dossier_filter.get().is_empty()>"Tous dossiers"</option>
+                            {move || {
+                                let dos_map = dossiers_map.get();
+                                dossier_options.get().into_iter().map(|(id, n)| {
+                                    let title = dos_map.get(&id).cloned().unwrap_or_else(|| id.clone());
+                                    let selected = dossier_filter.get() == id;
+                                    view! { <option value=id.clone() selected=selected>{format!("{} ({})", title, n)}</option> }
+                                }).collect_view()
+                            }}
+                        </select>
+                    </div>
+                    <button class="btn" on:click=move |_| {
+                        set_filter.set(String::new());
+                        set_date_from.set(String::new());
+                        set_date_to.set(String::new());
+                        set_outcome.set(String::new());
+                        set_type_filter.set(String::new());
+                        set_dossier_filter.set(String::new());
+                        reset_page();
+                    }>
+                        "Réinitialiser"
+                    </button>
+                </div>
+            </div>
+
+            <div class="kpi-card" style="min-width:0;">
+                <div style="display:flex;align-items:center;justify-content:space-between;gap:0.75rem;flex-wrap:wrap;margin-bottom:0.6rem;">
+                    <h2 style="margin:0;font-size:1rem;">"Liste des scrutins"</h2>
+                    <div class="amd-page-size-row">
+                        <span style="color:var(--text-muted);font-size:0.78rem;">"Afficher"</span>
+                        <select
+                            aria-label="Nombre de scrutins par page"
+                            on:change=move |ev| {
+                                if let Ok(n) = event_target_value(&ev).parse::<usize>() {
+                                    set_page_size.set(n);
+                                    reset_page();
+                                }
+                            }
+                            class="amd-select amd-select-sm"
+                        >
+                            <option value="20" selected=move || page_size.get() == 20>"20"</option>
+                            <option value="50" selected=move || page_size.get() == 50>"50"</option>
+                            <option value="100" selected=move || page_size.get() == 100>"100"</option>
+                        </select>
+                    </div>
+                </div>
+
+                {move || {
+                    let total_all = match index_res.get() {
+                        Some(Ok(idx)) => idx.total,
+                        Some(Err(e)) => return view! { <p style="margin:0;color:var(--danger);">{e}</p> }.into_view(),
+                        None => return view! { <div class="loading-box"><span class="spinner spinner-lg"></span>" Chargement…"</div> }.into_view(),
+                    };
+                    let rows = filtered.get();
+                    let dos_map = dossiers_map.get();
+
+                    // Pagination
+                    let psize = page_size.get();
+                    let total = rows.len();
+                    // Ceiling division: total pages = ⌈total / psize⌉
+                    let total_pages = if total == 0 { 1 } else { total.div_ceil(psize) };
+                    let page = current_page.get().min(total_pages).max(1);
+                    let skip = (page - 1) * psize;
+                    let display: Vec<ScrutinIndexEntry> = rows.into_iter().skip(skip).take(psize).collect();
+
+                    view! {
+                        <div style="color:var(--text-muted);font-size:0.78rem;margin-bottom:0.5rem;">
+                            {format!("{} scrutins • {} filtrés • page {}/{}", total_all, total, page, total_pages)}
+                        </div>
+
+                        <div style="overflow:auto;">
+                            <table class="data-table">
+                                <thead>
+                                    <tr>
+                                        <th>"N°"</th>
+                                        <th>"Date"</th>
+                                        <th>"Objet"</th>
+                                        <th>"Type"</th>
+                                        <th>"Résultat"</th>
+                                        <th>"Pour"</th>
+                                        <th>"Contre"</th>
+                                        <th>"Abst."</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {display.into_iter().map(|s| {
+                                        let href = app_href(&format!("/scrutin/{}", s.id));
+                                        let dossier_title = s.dossier_ref.as_deref()
+                                            .map(|id| dos_map.get(id).cloned().unwrap_or_else(|| id.to_string()));
+                                        let sort_view = match (s.sort.clone(), is_adopte(s.sort.as_deref())) {
+                                            (Some(label), Some(true)) => view!{ <span class="badge" style="border-color:rgba(52,211,153,.35);background:rgba(52,211,153,.12);color:var(--success);">{label}</span> }.into_view(),
+                                            (Some(label), _) => view!{ <span class="badge">{label}</span> }.into_view(),
+                                            (None, _) => view!{ <span style="color:var(--text-muted);">"—"</span> }.into_view(),
+                                        };
+                                        view! {
+                                            <tr>
+                                                <td style="font-family:monospace;">{s.numero}</td>
+                                                <td style="white-space:nowrap;">{s.date.map(|d| d.format("%d/%m/%Y").to_string()).unwrap_or_else(|| "—".to_string())}</td>
+                                                <td style="max-width:560px;">
+                                                    <A href=href attr:style="color:var(--text-primary);text-decoration:none;font-size:0.82rem;">{s.titre.clone()}</A>
+                                                    {dossier_title.map(|t| view! {
+                                                        <span style="display:block;font-size:0.7rem;color:var(--text-muted);">{t}</span>
+                                                    })}
+                                                </td>
+                                                <td style="font-size:0.75rem;color:var(--text-secondary);">{s.type_vote.as_deref().map(type_vote_label).unwrap_or("—")}</td>
+                                                <td>{sort_view}</td>
+                                                <td>{s.pour}</td>
+                                                <td>{s.contre}</td>
+                                                <td>{s.abstentions}</td>
+                                            </tr>
+                                        }
+                                    }).collect_view()}
+                                </tbody>
+                            </table>
+                        </div>
+
+                        {if total_pages > 1 {
+                            view! {
+                                <div style="display:flex;align-items:center;justify-content:center;gap:0.35rem;margin-top:0.75rem;flex-wrap:wrap;">
+                                    <button
+                                        type="button"
+                                        class="btn"
+                                        disabled={move || current_page.get() <= 1}
+                                        on:click={move |_| set_current_page.update(|p| { if *p > 1 { *p -= 1; } })}
+                                        style="min-width:2.2rem;padding:0.35rem 0.6rem;font-size:0.82rem;"
+                                    >"◀"</button>
+                                    <span style="color:var(--text-muted);font-size:0.82rem;">{format!("{} / {}", page, total_pages)}</span>
+                                    <button
+                                        type="button"
+                                        class="btn"
+                                        disabled={move || current_page.get() >= total_pages}
+                                        on:click={move |_| set_current_page.update(|p| { if *p < total_pages { *p += 1; } })}
+                                        style="min-width:2.2rem;padding:0.35rem 0.6rem;font-size:0.82rem;"
+                                    >"▶"</button>
+                                </div>
+                            }.into_view()
+                        } else {
+                            view! {}.into_view()
+                        }}
+                    }
+                    .into_view()
+                }}
+            </div>
+
+            <div style="margin-top:1rem;color:var(--text-muted);font-size:0.78rem;line-height:1.35;">
+                {move || {
+                    match index_res.get() {
+                        Some(Ok(idx)) => format!("Données générées le {} • schema v{}", idx.generated_at, idx.schema_version),
+                        _ => "".to_string(),
+                    }
+                }}
+            </div>
+        </div>
+    }
+}
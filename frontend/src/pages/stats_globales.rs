@@ -4,7 +4,7 @@ use chrono::{Datelike, NaiveDate};
 use leptos::*;
 use leptos_router::A;
 
-use crate::utils::app_href;
+use crate::utils::{app_href, legislature_label};
 use crate::api::fetch_deputes;
 use crate::models::{DeputeInfo, DeputeStats, Period};
 use crate::store::use_store;
@@ -26,6 +26,8 @@ struct HistogramBin {
 #[derive(Debug, Clone, Default)]
 struct GroupActivityRow {
     group: String,
+    /// Organe du groupe (lien vers la fiche `/groupe/:id`), si connu.
+    groupe_id: Option<String>,
     seats: usize,
     median_participation_pct: Option<f64>,
     avg_participation_pct: Option<f64>,
@@ -64,6 +66,7 @@ struct GlobalStatsPageSummary {
 
 #[derive(Debug, Default)]
 struct GroupStatsAccumulator {
+    groupe_id: Option<String>,
     seats: usize,
     participations: Vec<f64>,
     scrutins_ref: u32,
@@ -78,6 +81,7 @@ pub fn StatsGlobalesPage() -> impl IntoView {
     let (period, set_period) = create_signal(Period::P180);
 
     let store_for_header = store.clone();
+    let store_for_leg = store.clone();
     let store_for_page = store.clone();
 
     view! {
@@ -89,9 +93,13 @@ pub fn StatsGlobalesPage() -> impl IntoView {
                             <span style="display:inline-flex;align-items:center;gap:.35rem;padding:.18rem .5rem;border-radius:999px;background:rgba(99,102,241,.14);color:var(--accent);font-size:.72rem;border:1px solid var(--accent-border);font-weight:600;">
                                 "Analyse descriptive"
                             </span>
-                            <span style="display:inline-flex;align-items:center;padding:.18rem .5rem;border-radius:999px;background:rgba(255,255,255,.03);color:var(--text-muted);font-size:.72rem;border:1px solid var(--bg-border);">
-                                "17e législature"
-                            </span>
+                            {move || {
+                                store_for_leg.status.get().and_then(|r| r.ok()).map(|s| view! {
+                                    <span style="display:inline-flex;align-items:center;padding:.18rem .5rem;border-radius:999px;background:rgba(255,255,255,.03);color:var(--text-muted);font-size:.72rem;border:1px solid var(--bg-border);">
+                                        {legislature_label(s.legislature)}
+                                    </span>
+                                })
+                            }}
                         </div>
                         <h1 style="margin:0 0 .35rem 0;font-size:1.35rem;font-weight:700;color:var(--text-primary);">
                             "Stats globales — Assemblée nationale"
@@ -468,9 +476,16 @@ fn StatsGlobalesContent(summary: GlobalStatsPageSummary) -> impl IntoView {
                             {groups_by_median.iter().take(12).enumerate().map(|(i, g)| {
                                 let rate = g.median_participation_pct.unwrap_or(0.0).clamp(0.0, 100.0);
                                 let color = group_palette_color(&g.group);
+                                let label = g.group.clone();
+                                let label_view = match g.groupe_id.clone().map(|id| app_href(&format!("/groupe/{id}"))) {
+                                    Some(href) => view! {
+                                        <A href=href attr:style=format!("color:{color};text-decoration:none;")>{label}</A>
+                                    }.into_view(),
+                                    None => label.into_view(),
+                                };
                                 view! {
                                     <div class="sg-row3">
-                                        <div class="sg-code" style=format!("color:{color};")>{g.group.clone()}</div>
+                                        <div class="sg-code" style=format!("color:{color};")>{label_view}</div>
                                         <div class="sg-track"><div class="sg-fill" style=format!("--w:{rate:.2}%;background:{color};opacity:.75;animation-delay:{}ms;", 100 + i * 45)></div></div>
                                         <div class="sg-right-num" style=format!("color:{color};")>{fmt_pct1(rate)}</div>
                                     </div>
@@ -819,6 +834,9 @@ fn compute_global_stats_page_summary(
 
         let group_label = deputy_group_label_stats(s);
         let entry = group_acc.entry(group_label).or_default();
+        if entry.groupe_id.is_none() {
+            entry.groupe_id = s.groupe_id.clone();
+        }
         entry.seats += 1;
         entry.participations.push(part_pct);
         entry.scrutins_ref = entry.scrutins_ref.max(s.scrutins_eligibles);
@@ -855,6 +873,7 @@ fn compute_global_stats_page_summary(
             };
             GroupActivityRow {
                 group,
+                groupe_id: acc.groupe_id,
                 seats: acc.seats,
                 median_participation_pct: median,
                 avg_participation_pct: avg,
@@ -1,18 +1,36 @@
+use chrono::NaiveDate;
 use leptos::*;
-use crate::api::{fetch_stats, fetch_status};
-use crate::models::{DeputeStats, Period, Status};
+use crate::api::{
+    check_data_compat, fetch_daily_activity, fetch_session_stats, fetch_sessions_index, fetch_stats,
+    fetch_status,
+};
+use crate::models::{DailyActivityFile, DataCompat, DeputeStats, Period, SessionsIndex, Status};
 
 /// Store global partagé via context Leptos.
 /// Les données sont chargées une fois et réutilisées sur toutes les pages.
 #[derive(Clone)]
 pub struct AppStore {
     pub status: Resource<(), Result<Status, String>>,
+    /// Compatibilité données / build, vérifiée une fois au démarrage via manifest.json
+    pub data_compat: Resource<(), DataCompat>,
     pub stats_p30:  Resource<(), Result<Vec<DeputeStats>, String>>,
     pub stats_p180: Resource<(), Result<Vec<DeputeStats>, String>>,
     /// LEG: Option Ressource chargée à la demande
     pub stats_leg:  RwSignal<Option<Resource<(), Result<Vec<DeputeStats>, String>>>>,
     /// Placeholder unique réutilisable tant que LEG n'est pas chargé
     pub stats_leg_placeholder: Resource<(), Result<Vec<DeputeStats>, String>>,
+    /// Fenêtre [début, fin] de `Period::Custom` (partagée entre les pages)
+    pub custom_range: RwSignal<Option<(NaiveDate, NaiveDate)>>,
+    /// Compteurs journaliers : chargés au premier choix d'une fenêtre personnalisée
+    pub daily: RwSignal<Option<Resource<(), Result<DailyActivityFile, String>>>>,
+    /// Stats recalculées sur `custom_range` (identité et mandat repris du dataset LEG)
+    pub stats_custom: Memo<Option<Result<Vec<DeputeStats>, String>>>,
+    /// Index des sessions parlementaires : chargé au premier choix de "Sessions"
+    pub sessions_index: RwSignal<Option<Resource<(), Result<SessionsIndex, String>>>>,
+    /// Session de `Period::Session` (id "SO-2024-2025"…)
+    pub session_id: RwSignal<Option<String>>,
+    /// Stats de la session choisie (rechargées à chaque changement de `session_id`)
+    pub stats_session: Resource<Option<String>, Result<Vec<DeputeStats>, String>>,
 }
 
 impl AppStore {
@@ -29,12 +47,52 @@ impl AppStore {
         let stats_leg_placeholder: Resource<(), Result<Vec<DeputeStats>, String>> =
             create_resource(|| (), |_| async { Ok(Vec::new()) });
 
+        let stats_leg = RwSignal::new(Some(stats_leg_resource));
+        let custom_range = RwSignal::new(None::<(NaiveDate, NaiveDate)>);
+        let daily = RwSignal::new(None::<Resource<(), Result<DailyActivityFile, String>>>);
+        let stats_custom = create_memo(move |_| {
+            let (start, end) = custom_range.get()?;
+            let daily_res = daily.get()?;
+            let leg_res = stats_leg.get()?;
+            leg_res.with(|leg| {
+                daily_res.with(|file| {
+                    // Ressources encore en chargement
+                    let (Some(leg), Some(file)) = (leg, file) else { return None };
+                    Some(match (leg.as_ref(), file.as_ref()) {
+                        (Err(e), _) | (_, Err(e)) => Err(e.clone()),
+                        (Ok(leg), Ok(file)) => Ok(leg
+                            .iter()
+                            .map(|d| file.stats_for_range(d, start, end))
+                            .collect()),
+                    })
+                })
+            })
+        });
+
+        let session_id = RwSignal::new(None::<String>);
+        let stats_session = create_resource(
+            move || session_id.get(),
+            |id| async move {
+                match id {
+                    Some(id) => fetch_session_stats(id).await,
+                    None => Ok(Vec::new()),
+                }
+            },
+        );
+
         Self {
             status:     create_resource(|| (), |_| fetch_status()),
+            data_compat: create_resource(|| (), |_| check_data_compat()),
             stats_p30,
             stats_p180,
-            stats_leg:  RwSignal::new(Some(stats_leg_resource)),
+            stats_leg,
             stats_leg_placeholder,
+            custom_range,
+            daily,
+            stats_custom,
+            sessions_index: RwSignal::new(None),
+            session_id,
+            stats_session,
         }
     }
 
@@ -48,7 +106,33 @@ impl AppStore {
         self.stats_leg.set(Some(resource));
     }
 
+    /// Choisit la fenêtre de `Period::Custom` (bornes remises dans l'ordre) et charge
+    /// les compteurs journaliers au premier appel.
+    pub fn set_custom_range(&self, start: NaiveDate, end: NaiveDate) {
+        self.custom_range.set(Some((start.min(end), start.max(end))));
+        self.load_leg();
+        if self.daily.get_untracked().is_none() {
+            self.daily.set(Some(create_resource(|| (), |_| fetch_daily_activity())));
+        }
+    }
+
+    /// Charger l'index des sessions à la demande (appelé quand utilisateur clique sur "Sessions")
+    pub fn load_sessions(&self) {
+        if self.sessions_index.get_untracked().is_some() {
+            return;
+        }
+        self.sessions_index.set(Some(create_resource(|| (), |_| fetch_sessions_index())));
+    }
+
+    /// Choisit la session de `Period::Session` (son fichier de stats est chargé par `stats_session`).
+    pub fn set_session(&self, id: String) {
+        if self.session_id.get_untracked().as_deref() != Some(id.as_str()) {
+            self.session_id.set(Some(id));
+        }
+    }
+
     /// Retourne TOUJOURS une Resource: pour LEG, renvoie le placeholder si pas encore chargé
+    /// (idem pour `Period::Custom` et `Period::Session`, servis par `stats_for`)
     pub fn get_resource_for(&self, period: Period) -> Resource<(), Result<Vec<DeputeStats>, String>> {
         match period {
             Period::P30  => self.stats_p30,
@@ -61,12 +145,25 @@ impl AppStore {
                     self.stats_leg_placeholder
                 }
             }
+            Period::Custom | Period::Session => self.stats_leg_placeholder,
         }
     }
 
-    /// Compatibilité ancienne API
-    pub fn stats_for(&self, period: Period) -> Resource<(), Result<Vec<DeputeStats>, String>> {
-        self.get_resource_for(period)
+    /// Données d'une période (`None` tant que le chargement est en cours)
+    pub fn stats_for(&self, period: Period) -> Signal<Option<Result<Vec<DeputeStats>, String>>> {
+        match period {
+            Period::Custom => self.stats_custom.into(),
+            Period::Session => {
+                let (session_id, stats_session) = (self.session_id, self.stats_session);
+                Signal::derive(move || {
+                    if session_id.with(Option::is_some) { stats_session.get() } else { None }
+                })
+            }
+            p => {
+                let resource = self.get_resource_for(p);
+                Signal::derive(move || resource.get())
+            }
+        }
     }
 
     /// Cherche un député par ID
@@ -27,6 +27,25 @@ pub fn groupe_color(abrev: Option<&str>) -> &'static str {
     }
 }
 
+/// Libellé ordinal d'une législature : « 1re législature », « 17e législature ».
+pub fn legislature_label(legislature: u32) -> String {
+    if legislature == 1 {
+        "1re législature".to_string()
+    } else {
+        format!("{legislature}e législature")
+    }
+}
+
+/// Libellé court d'un type de vote AN (`codeTypeVote`).
+pub fn type_vote_label(code: &str) -> &'static str {
+    match code {
+        "SPO" => "Ordinaire",
+        "SPS" => "Solennel",
+        "MOC" => "Motion de censure",
+        _ => "Autre",
+    }
+}
+
 pub fn normalize_search(s: &str) -> String {
     s.to_lowercase()
         .chars()
@@ -1,27 +1,146 @@
 use anyhow::Result;
-use chrono::{DateTime, Duration, NaiveDate, Utc};
-use std::collections::HashMap;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::time::Instant;
 use tracing::info;
 
 use crate::models::*;
+use shared::{iso_week_monday, DayCounts, DeputeDaily, Session, SessionKind};
 
+#[derive(Debug, Serialize, Deserialize)]
 pub struct AllAggregates {
+    pub legislature: u32,
+    /// Début de la fenêtre LEG.
+    pub leg_start: NaiveDate,
+    /// Instant de référence des fenêtres, repris tel quel par l'export (`generated_at`).
+    pub now: DateTime<Utc>,
     pub p30: Vec<DeputeStats>,
     pub p180: Vec<DeputeStats>,
     pub leg: Vec<DeputeStats>,
     pub deputes: Vec<Depute>,
+    pub organes: HashMap<String, Organe>,
+    pub scrutins: Vec<Scrutin>,
     pub dossiers: HashMap<String, Dossier>,
     /// Amendements normalisés (utilisés pour exporter des vues "jour par jour").
     pub amendements: Vec<Amendement>,
+    /// Questions au Gouvernement (listes par député).
+    #[serde(default)]
+    pub questions: Vec<Question>,
+    /// Une entrée par session parlementaire de la législature (la plus récente en premier).
+    pub sessions: Vec<SessionAggregate>,
+    /// Compteurs journaliers sur toute la fenêtre LEG (fenêtres personnalisées côté frontend).
+    pub daily: DailyActivity,
+    /// Séries hebdomadaires (semaines ISO) sur toute la fenêtre LEG.
+    pub weekly: WeeklySeries,
+    /// Fichiers écartés au parsing, repris dans le rapport qualité.
+    #[serde(default)]
+    pub parse_failures: Vec<ParseFailure>,
 }
 
-// Début de la 17e législature
-const LEG17_START: &str = "2022-06-19";
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SessionAggregate {
+    pub session: Session,
+    /// Mêmes règles que P30/P180 (amendements sans date exclus).
+    pub stats: Vec<DeputeStats>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DailyActivity {
+    /// Premier jour de l'axe (début de législature).
+    pub day_start: NaiveDate,
+    pub days: usize,
+    /// Scrutins datés par jour (`scrutins[i]` = jour `day_start + i`).
+    pub scrutins: Vec<u32>,
+    /// Dont scrutins solennels / ordinaires, même indexation que `scrutins`.
+    pub scrutins_solennels: Vec<u32>,
+    pub scrutins_ordinaires: Vec<u32>,
+    pub deputes: HashMap<String, DeputeDaily>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WeeklySeries {
+    /// Lundi de la semaine ISO du début de législature.
+    pub week_start: NaiveDate,
+    pub weeks: usize,
+    pub deputes: HashMap<String, WeeklyBuckets>,
+    /// Somme des séries des députés, par groupe (organe PO…) : chaque jour compte pour le groupe
+    /// dont le député était membre ce jour-là.
+    pub groupes: BTreeMap<String, WeeklyBuckets>,
+}
+
+// Début des législatures connues (second tour des élections législatives). La 17e démarre le
+// 7 juillet 2024 : la fenêtre LEG ne reprend pas la fin de la 16e (ouverte le 19 juin 2022).
+const LEGISLATURE_STARTS: &[(u32, &str)] = &[
+    (15, "2017-06-18"),
+    (16, "2022-06-19"),
+    (17, "2024-07-07"),
+];
+
+/// Date de début d'une législature connue (fenêtre LEG), `None` sinon.
+pub fn legislature_start(legislature: u32) -> Option<NaiveDate> {
+    LEGISLATURE_STARTS
+        .iter()
+        .find(|(n, _)| *n == legislature)
+        .and_then(|(_, d)| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+}
+
+/// Sessions de la législature sur [leg_start, today], de la plus récente à la plus ancienne.
+/// Session ordinaire : 1er octobre → 30 juin. Session extraordinaire : juillet → septembre,
+/// retenue seulement si au moins un scrutin y est daté (pas de session convoquée sinon).
+pub fn session_calendar(leg_start: NaiveDate, today: NaiveDate, scrutin_dates: &[NaiveDate]) -> Vec<Session> {
+    let ymd = |y: i32, m: u32, d: u32| NaiveDate::from_ymd_opt(y, m, d).expect("date de session valide");
+    let mut sessions = Vec::new();
+    if today < leg_start {
+        return sessions;
+    }
+
+    for year in (leg_start.year() - 1)..=today.year() {
+        let candidates = [
+            (SessionKind::Extraordinaire, ymd(year, 7, 1), ymd(year, 9, 30)),
+            (SessionKind::Ordinaire, ymd(year, 10, 1), ymd(year + 1, 6, 30)),
+        ];
+        for (kind, natural_start, natural_end) in candidates {
+            let start = natural_start.max(leg_start);
+            let end = natural_end.min(today);
+            if start > end {
+                continue;
+            }
+            let scrutins = scrutin_dates.iter().filter(|d| **d >= start && **d <= end).count() as u32;
+            let (id, label) = match kind {
+                SessionKind::Ordinaire => (
+                    format!("SO-{}-{}", year, year + 1),
+                    format!("Session ordinaire {}-{}", year, year + 1),
+                ),
+                SessionKind::Extraordinaire => {
+                    if scrutins == 0 {
+                        continue;
+                    }
+                    (format!("SE-{year}"), format!("Session extraordinaire {year}"))
+                }
+            };
+            sessions.push(Session {
+                id,
+                label,
+                kind,
+                start,
+                end,
+                en_cours: natural_end > today,
+                scrutins,
+            });
+        }
+    }
+
+    sessions.reverse();
+    sessions
+}
 
 const COSIGN_TOP_LIMIT: usize = 10;
+const RECENT_DISSENTS_LIMIT: usize = 5;
 const COSIGN_IN_GROUP_UI_LIMIT: usize = 12;
 const COSIGN_OUT_GROUP_MEMBERS_UI_LIMIT: usize = 8;
+/// Poids d'un rôle de rapporteur dans le score des dossiers (voir la méthodologie).
+const RAPPORTEUR_WEIGHT: u32 = 10;
 
 #[derive(Default)]
 struct PeriodCosignAnalytics {
@@ -79,16 +198,323 @@ fn effective_mandate_windows(dep: &Depute, period_start: NaiveDate, period_end:
     merge_windows(windows)
 }
 
-fn date_in_windows(date: NaiveDate, windows: &[DateWindow]) -> bool {
-    windows.iter().any(|w| date >= w.start && date <= w.end)
+fn is_expressed(position: &VotePosition) -> bool {
+    matches!(position, VotePosition::Pour | VotePosition::Contre | VotePosition::Abstention)
+}
+
+/// Catégorie de scrutin pour la participation ventilée.
+enum VoteKind {
+    Solennel,
+    Ordinaire,
+    /// Motion de censure (seuls les "pour" sont recensés) ou type inconnu.
+    Autre,
+}
+
+fn vote_kind(scrutin: &Scrutin) -> VoteKind {
+    match scrutin.type_vote.as_deref() {
+        Some("SPS") => VoteKind::Solennel,
+        Some("SPO") => VoteKind::Ordinaire,
+        _ => VoteKind::Autre,
+    }
+}
+
+fn ratio(num: u32, den: u32) -> Option<f64> {
+    if den > 0 {
+        Some(num as f64 / den as f64)
+    } else {
+        None
+    }
+}
+
+/// Position majoritaire exprimée du groupe sous lequel le député est décompté sur ce scrutin.
+fn groupe_position_for<'a>(scrutin: &'a Scrutin, dep_id: &str) -> Option<&'a VotePosition> {
+    let groupe_ref = scrutin.groupe_votants.get(dep_id)?;
+    scrutin
+        .groupes
+        .iter()
+        .find(|g| &g.organe_ref == groupe_ref)
+        .and_then(|g| g.position_majoritaire.as_ref())
+        .filter(|p| is_expressed(p))
+}
+
+/// Index construits une seule fois pour toutes les fenêtres (P30, P180, LEG, sessions,
+/// compteurs journaliers) : scrutins, amendements, interventions, questions et réunions de
+/// commission triés par date, et pour chaque député les positions, dans ces listes, des
+/// scrutins où il figure, des amendements qu'il a déposés ou cosignés, de ses interventions, de
+/// ses questions et des réunions où il était convoqué. Une fenêtre se résout par recherche
+/// dichotomique au lieu d'un parcours de tous les scrutins et amendements pour chaque député.
+struct AggregationIndex<'a> {
+    dep_idx: HashMap<&'a str, usize>,
+    /// Scrutins datés, par date croissante.
+    scrutins: Vec<&'a Scrutin>,
+    scrutin_dates: Vec<NaiveDate>,
+    /// `solennels_before[i]` : scrutins solennels parmi `scrutins[..i]` (idem ordinaires).
+    solennels_before: Vec<u32>,
+    ordinaires_before: Vec<u32>,
+    /// Par député : scrutins où il apparaît (vote, mise au point ou délégation).
+    votes_by_dep: Vec<Vec<DepVote>>,
+    /// Amendements datés par date croissante, suivis des amendements sans date.
+    amendements: Vec<&'a Amendement>,
+    amd_dates: Vec<NaiveDate>,
+    /// Par député : amendements déposés, et cosignés hors auteur (sans doublon).
+    amd_by_author: Vec<Vec<u32>>,
+    amd_by_cosigner: Vec<Vec<u32>>,
+    /// Interventions en séance datées, par date croissante, et leurs positions par député.
+    interventions: Vec<&'a Intervention>,
+    itv_dates: Vec<NaiveDate>,
+    itv_by_dep: Vec<Vec<u32>>,
+    /// Questions datées (publication de la question), par date croissante, et par auteur.
+    questions: Vec<&'a Question>,
+    qst_dates: Vec<NaiveDate>,
+    qst_by_dep: Vec<Vec<u32>>,
+    /// Réunions de commission datées, par date croissante, et par député convoqué (avec sa présence).
+    reu_dates: Vec<NaiveDate>,
+    reu_by_dep: Vec<Vec<(u32, Presence)>>,
+    /// Nominations de rapporteur datées (avec leur dossier), par date croissante, et par député.
+    rapporteurs: Vec<(&'a str, &'a Rapporteur)>,
+    rap_dates: Vec<NaiveDate>,
+    rap_by_dep: Vec<Vec<u32>>,
+}
+
+/// Présence d'un député dans un scrutin, résolue une fois pour toutes les fenêtres.
+#[derive(Debug, Clone)]
+struct DepVote {
+    /// Position du scrutin dans `AggregationIndex::scrutins`.
+    pos: u32,
+    position: Option<VotePosition>,
+    /// Position majoritaire exprimée du groupe sous lequel il est décompté.
+    groupe_position: Option<VotePosition>,
+    mise_au_point: bool,
+    par_delegation: bool,
+}
+
+impl<'a> AggregationIndex<'a> {
+    fn build(raw: &'a RawDataset) -> Self {
+        let dep_idx: HashMap<&str, usize> = raw
+            .deputes
+            .iter()
+            .enumerate()
+            .map(|(i, d)| (d.id.as_str(), i))
+            .collect();
+
+        let mut scrutins: Vec<&Scrutin> = raw.scrutins.iter().filter(|s| s.date.is_some()).collect();
+        scrutins.sort_by_key(|s| s.date);
+        let scrutin_dates: Vec<NaiveDate> = scrutins.iter().filter_map(|s| s.date).collect();
+
+        let mut solennels_before = Vec::with_capacity(scrutins.len() + 1);
+        let mut ordinaires_before = Vec::with_capacity(scrutins.len() + 1);
+        let (mut sol, mut ord) = (0u32, 0u32);
+        solennels_before.push(0);
+        ordinaires_before.push(0);
+
+        let mut votes_by_dep: Vec<Vec<DepVote>> = vec![Vec::new(); raw.deputes.len()];
+        for (pos, scrutin) in scrutins.iter().enumerate() {
+            match vote_kind(scrutin) {
+                VoteKind::Solennel => sol += 1,
+                VoteKind::Ordinaire => ord += 1,
+                VoteKind::Autre => {}
+            }
+            solennels_before.push(sol);
+            ordinaires_before.push(ord);
+
+            // Votants, puis députés cités seulement dans les mises au point ou les délégations
+            let extra = scrutin
+                .mises_au_point
+                .keys()
+                .chain(scrutin.par_delegation.iter())
+                .filter(|id| !scrutin.votes.contains_key(*id));
+            for id in scrutin.votes.keys().chain(extra) {
+                let Some(&i) = dep_idx.get(id.as_str()) else { continue };
+                // Un même député peut figurer à la fois en mise au point et en délégation
+                if votes_by_dep[i].last().is_some_and(|v| v.pos == pos as u32) {
+                    continue;
+                }
+                votes_by_dep[i].push(DepVote {
+                    pos: pos as u32,
+                    position: scrutin.votes.get(id).cloned(),
+                    groupe_position: groupe_position_for(scrutin, id).cloned(),
+                    mise_au_point: scrutin.mises_au_point.contains_key(id),
+                    par_delegation: scrutin.par_delegation.binary_search(id).is_ok(),
+                });
+            }
+        }
+
+        let mut amendements: Vec<&Amendement> = raw.amendements.iter().collect();
+        // Tri stable : les amendements sans date (None < Some) sont ramenés en fin de liste
+        amendements.sort_by_key(|a| (a.date.is_none(), a.date));
+        let amd_dates: Vec<NaiveDate> = amendements.iter().map_while(|a| a.date).collect();
+
+        let mut amd_by_author: Vec<Vec<u32>> = vec![Vec::new(); raw.deputes.len()];
+        let mut amd_by_cosigner: Vec<Vec<u32>> = vec![Vec::new(); raw.deputes.len()];
+        for (pos, amd) in amendements.iter().enumerate() {
+            let auteur = amd.auteur_id.as_deref();
+            if let Some(&i) = auteur.and_then(|a| dep_idx.get(a)) {
+                amd_by_author[i].push(pos as u32);
+            }
+            for (k, cos) in amd.cosignataires_ids.iter().enumerate() {
+                if Some(cos.as_str()) == auteur || amd.cosignataires_ids[..k].contains(cos) {
+                    continue;
+                }
+                if let Some(&i) = dep_idx.get(cos.as_str()) {
+                    amd_by_cosigner[i].push(pos as u32);
+                }
+            }
+        }
+
+        let mut interventions: Vec<&Intervention> =
+            raw.interventions.iter().filter(|i| i.date.is_some()).collect();
+        interventions.sort_by_key(|i| i.date);
+        let itv_dates: Vec<NaiveDate> = interventions.iter().filter_map(|i| i.date).collect();
+        let mut itv_by_dep: Vec<Vec<u32>> = vec![Vec::new(); raw.deputes.len()];
+        for (pos, itv) in interventions.iter().enumerate() {
+            if let Some(&i) = dep_idx.get(itv.acteur_id.as_str()) {
+                itv_by_dep[i].push(pos as u32);
+            }
+        }
+
+        let mut questions: Vec<&Question> = raw.questions.iter().filter(|q| q.date_question.is_some()).collect();
+        questions.sort_by_key(|q| q.date_question);
+        let qst_dates: Vec<NaiveDate> = questions.iter().filter_map(|q| q.date_question).collect();
+        let mut qst_by_dep: Vec<Vec<u32>> = vec![Vec::new(); raw.deputes.len()];
+        for (pos, q) in questions.iter().enumerate() {
+            if let Some(&i) = dep_idx.get(q.auteur_id.as_str()) {
+                qst_by_dep[i].push(pos as u32);
+            }
+        }
+
+        let mut reunions: Vec<&Reunion> = raw.reunions.iter().filter(|r| r.date.is_some()).collect();
+        reunions.sort_by_key(|r| r.date);
+        let reu_dates: Vec<NaiveDate> = reunions.iter().filter_map(|r| r.date).collect();
+        let mut reu_by_dep: Vec<Vec<(u32, Presence)>> = vec![Vec::new(); raw.deputes.len()];
+        for (pos, r) in reunions.iter().enumerate() {
+            for (acteur, presence) in &r.participants {
+                if let Some(&i) = dep_idx.get(acteur.as_str()) {
+                    reu_by_dep[i].push((pos as u32, *presence));
+                }
+            }
+        }
+
+        let mut rapporteurs: Vec<(&str, &Rapporteur)> = raw
+            .dossiers
+            .values()
+            .flat_map(|d| d.rapporteurs.iter().filter(|r| r.date.is_some()).map(move |r| (d.id.as_str(), r)))
+            .collect();
+        rapporteurs.sort_by_key(|(dossier_id, r)| (r.date, *dossier_id));
+        let rap_dates: Vec<NaiveDate> = rapporteurs.iter().filter_map(|(_, r)| r.date).collect();
+        let mut rap_by_dep: Vec<Vec<u32>> = vec![Vec::new(); raw.deputes.len()];
+        for (pos, (_, r)) in rapporteurs.iter().enumerate() {
+            if let Some(&i) = dep_idx.get(r.acteur_id.as_str()) {
+                rap_by_dep[i].push(pos as u32);
+            }
+        }
+
+        Self {
+            dep_idx,
+            scrutins,
+            scrutin_dates,
+            solennels_before,
+            ordinaires_before,
+            votes_by_dep,
+            amendements,
+            amd_dates,
+            amd_by_author,
+            amd_by_cosigner,
+            interventions,
+            itv_dates,
+            itv_by_dep,
+            questions,
+            qst_dates,
+            qst_by_dep,
+            reu_dates,
+            reu_by_dep,
+            rapporteurs,
+            rap_dates,
+            rap_by_dep,
+        }
+    }
+
+    /// Positions `[lo, hi)` des scrutins datés dans la fenêtre.
+    fn scrutin_range(&self, w: DateWindow) -> (usize, usize) {
+        date_range(&self.scrutin_dates, w)
+    }
+
+    /// Positions `[lo, hi)` des amendements datés dans la fenêtre.
+    fn amd_range(&self, w: DateWindow) -> (usize, usize) {
+        date_range(&self.amd_dates, w)
+    }
+
+    /// Positions `[lo, hi)` des interventions dans la fenêtre.
+    fn itv_range(&self, w: DateWindow) -> (usize, usize) {
+        date_range(&self.itv_dates, w)
+    }
+
+    /// Positions `[lo, hi)` des questions publiées dans la fenêtre.
+    fn qst_range(&self, w: DateWindow) -> (usize, usize) {
+        date_range(&self.qst_dates, w)
+    }
+
+    /// Positions `[lo, hi)` des réunions de commission dans la fenêtre.
+    fn reu_range(&self, w: DateWindow) -> (usize, usize) {
+        date_range(&self.reu_dates, w)
+    }
+
+    /// Positions `[lo, hi)` des nominations de rapporteur dans la fenêtre.
+    fn rap_range(&self, w: DateWindow) -> (usize, usize) {
+        date_range(&self.rap_dates, w)
+    }
+
+    /// Amendements sans date (fin de `amendements`).
+    fn amd_undated(&self) -> (usize, usize) {
+        (self.amd_dates.len(), self.amendements.len())
+    }
+}
+
+fn date_range(dates: &[NaiveDate], w: DateWindow) -> (usize, usize) {
+    let lo = dates.partition_point(|d| *d < w.start);
+    let hi = dates.partition_point(|d| *d <= w.end);
+    (lo, hi.max(lo))
+}
+
+/// Sous-liste des éléments (triés par position croissante) dont la position est dans `[lo, hi)`.
+fn in_range<T>(items: &[T], (lo, hi): (usize, usize), pos: impl Fn(&T) -> u32) -> &[T] {
+    let from = items.partition_point(|it| (pos(it) as usize) < lo);
+    let to = items.partition_point(|it| (pos(it) as usize) < hi);
+    &items[from..to.max(from)]
 }
 
-pub fn compute_all(raw: &RawDataset, now: DateTime<Utc>) -> Result<AllAggregates> {
+/// Références vers `AggregationIndex::amendements` (ou `interventions`, `questions`) comprises
+/// dans `[lo, hi)`.
+fn refs_in(refs: &[u32], range: (usize, usize)) -> &[u32] {
+    in_range(refs, range, |&p| p)
+}
+
+/// Applique `f` à chaque député, en parallèle avec la feature `parallel` (rayon).
+fn map_deputes<T, F>(deputes: &[Depute], f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(usize, &Depute) -> T + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        deputes.par_iter().enumerate().map(|(i, dep)| f(i, dep)).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        deputes.iter().enumerate().map(|(i, dep)| f(i, dep)).collect()
+    }
+}
+
+pub fn compute_all(
+    raw: &RawDataset,
+    legislature: u32,
+    leg_start: NaiveDate,
+    now: DateTime<Utc>,
+) -> Result<AllAggregates> {
     let today = now.date_naive();
 
     let p30_start  = today - Duration::days(30);
     let p180_start = today - Duration::days(180);
-    let leg_start  = NaiveDate::parse_from_str(LEG17_START, "%Y-%m-%d").unwrap();
 
     let t_all = Instant::now();
     info!(
@@ -99,79 +525,271 @@ pub fn compute_all(raw: &RawDataset, now: DateTime<Utc>) -> Result<AllAggregates
     );
 
     let t = Instant::now();
-    let p30  = compute_period(raw, p30_start,  today, false);
+    let index = AggregationIndex::build(raw);
+    info!(
+        "Index d'agrégation OK en {:?} (scrutins datés={}, amendements datés={}, interventions datées={}, questions datées={}, réunions datées={})",
+        t.elapsed(),
+        index.scrutins.len(),
+        index.amd_dates.len(),
+        index.itv_dates.len(),
+        index.qst_dates.len(),
+        index.reu_dates.len()
+    );
+
+    let t = Instant::now();
+    let p30  = compute_period(raw, &index, p30_start,  today, false);
     info!("Agrégation P30 OK en {:?} (lignes={})", t.elapsed(), p30.len());
 
     let t = Instant::now();
-    let p180 = compute_period(raw, p180_start, today, false);
+    let p180 = compute_period(raw, &index, p180_start, today, false);
     info!("Agrégation P180 OK en {:?} (lignes={})", t.elapsed(), p180.len());
 
     // Pour la période LEG, on accepte les amendements sans date (déposés sur toute la législature)
     let t = Instant::now();
-    let leg  = compute_period(raw, leg_start,  today, true);
+    let leg  = compute_period(raw, &index, leg_start,  today, true);
     info!("Agrégation LEG OK en {:?} (lignes={})", t.elapsed(), leg.len());
 
+    let sessions: Vec<SessionAggregate> = session_calendar(leg_start, today, &index.scrutin_dates)
+        .into_iter()
+        .map(|session| {
+            let t = Instant::now();
+            let stats = compute_period(raw, &index, session.start, session.end, false);
+            info!("Agrégation {} OK en {:?} (lignes={})", session.id, t.elapsed(), stats.len());
+            SessionAggregate { session, stats }
+        })
+        .collect();
+
+    let t = Instant::now();
+    let daily = compute_daily_activity(raw, &index, leg_start, today);
+    let weekly = weekly_from_daily(&daily, raw);
+    info!(
+        "Compteurs journaliers et séries hebdomadaires OK en {:?} ({} jours, {} semaines)",
+        t.elapsed(),
+        daily.days,
+        weekly.weeks
+    );
+
     info!("Agrégation détaillée: terminée en {:?}", t_all.elapsed());
 
     Ok(AllAggregates {
+        legislature,
+        leg_start,
+        now,
         p30,
         p180,
         leg,
         deputes: raw.deputes.clone(),
+        organes: raw.organes.clone(),
+        scrutins: raw.scrutins.clone(),
         dossiers: raw.dossiers.clone(),
         amendements: raw.amendements.clone(),
+        questions: raw.questions.clone(),
+        sessions,
+        daily,
+        weekly,
+        parse_failures: raw.parse_failures.clone(),
     })
 }
 
+/// Compteurs journaliers sur [leg_start, today], avec les mêmes règles que la fenêtre LEG
+/// (épisodes de mandat, vote exprimé, cosignature hors auteur) ; amendements sans date exclus.
+fn compute_daily_activity(
+    raw: &RawDataset,
+    index: &AggregationIndex,
+    leg_start: NaiveDate,
+    today: NaiveDate,
+) -> DailyActivity {
+    let days = if today >= leg_start {
+        ((today - leg_start).num_days() + 1) as usize
+    } else {
+        0
+    };
+    let day_of = |date: NaiveDate| (date - leg_start).num_days() as u32;
+
+    let mut scrutins = vec![0u32; days];
+    let mut scrutins_solennels = vec![0u32; days];
+    let mut scrutins_ordinaires = vec![0u32; days];
+    if days > 0 {
+        let (lo, hi) = index.scrutin_range(DateWindow { start: leg_start, end: today });
+        for pos in lo..hi {
+            let day = day_of(index.scrutin_dates[pos]) as usize;
+            scrutins[day] += 1;
+            match vote_kind(index.scrutins[pos]) {
+                VoteKind::Solennel => scrutins_solennels[day] += 1,
+                VoteKind::Ordinaire => scrutins_ordinaires[day] += 1,
+                VoteKind::Autre => {}
+            }
+        }
+    }
+
+    let deputes = map_deputes(&raw.deputes, |i, dep| {
+        let windows = effective_mandate_windows(dep, leg_start, today);
+        let mut jours: BTreeMap<u32, DayCounts> = BTreeMap::new();
+
+        for &w in &windows {
+            for vote in in_range(&index.votes_by_dep[i], index.scrutin_range(w), |v| v.pos) {
+                let day = day_of(index.scrutin_dates[vote.pos as usize]);
+                let c = day_entry(&mut jours, day);
+                c.mises_au_point += vote.mise_au_point as u32;
+                c.votes_par_delegation += vote.par_delegation as u32;
+                match vote.position {
+                    Some(VotePosition::Pour) => c.pour += 1,
+                    Some(VotePosition::Contre) => c.contre += 1,
+                    Some(VotePosition::Abstention) => c.abst += 1,
+                    Some(VotePosition::NonVotant) => c.non_votant += 1,
+                    Some(VotePosition::Absent) | None => {}
+                }
+                if !vote.position.as_ref().is_some_and(is_expressed) {
+                    continue;
+                }
+                c.votes_exprimes += 1;
+                match vote_kind(index.scrutins[vote.pos as usize]) {
+                    VoteKind::Solennel => c.votes_solennels_exprimes += 1,
+                    VoteKind::Ordinaire => c.votes_ordinaires_exprimes += 1,
+                    VoteKind::Autre => {}
+                }
+                if let (Some(position), Some(groupe_position)) = (&vote.position, &vote.groupe_position) {
+                    c.loyalty_votes += 1;
+                    c.dissent_count += (position != groupe_position) as u32;
+                }
+            }
+
+            let range = index.amd_range(w);
+            for &pos in refs_in(&index.amd_by_author[i], range) {
+                let amd = index.amendements[pos as usize];
+                let Some(date) = amd.date else { continue };
+                let c = day_entry(&mut jours, day_of(date));
+                c.amd_authored += 1;
+                if amd.adopte {
+                    c.amd_adopted += 1;
+                }
+            }
+            for &pos in refs_in(&index.amd_by_cosigner[i], range) {
+                let Some(date) = index.amendements[pos as usize].date else { continue };
+                day_entry(&mut jours, day_of(date)).amd_cosigned += 1;
+            }
+
+            for &pos in refs_in(&index.itv_by_dep[i], index.itv_range(w)) {
+                let c = day_entry(&mut jours, day_of(index.itv_dates[pos as usize]));
+                c.interventions += 1;
+                c.interventions_chars = c.interventions_chars.saturating_add(index.interventions[pos as usize].chars);
+            }
+            for &pos in refs_in(&index.qst_by_dep[i], index.qst_range(w)) {
+                let q = index.questions[pos as usize];
+                let c = day_entry(&mut jours, day_of(index.qst_dates[pos as usize]));
+                match q.type_question.as_str() {
+                    "QAG" => {
+                        c.questions_gouvernement += 1;
+                        continue;
+                    }
+                    "QOSD" => c.questions_orales += 1,
+                    _ => c.questions_ecrites += 1,
+                }
+                c.questions_repondues += q.repondue as u32;
+            }
+            for &(pos, presence) in in_range(&index.reu_by_dep[i], index.reu_range(w), |r| r.0) {
+                let c = day_entry(&mut jours, day_of(index.reu_dates[pos as usize]));
+                c.commission_reunions += 1;
+                match presence {
+                    Presence::Present => c.commission_presences += 1,
+                    Presence::Excuse => c.commission_excuses += 1,
+                    Presence::Absent => {}
+                }
+            }
+        }
+
+        let daily = DeputeDaily {
+            mandats: windows.iter().map(|w| (w.start, w.end)).collect(),
+            jours: jours.into_values().collect(),
+        };
+        (dep.id.clone(), daily)
+    })
+    .into_iter()
+    .collect();
+
+    DailyActivity { day_start: leg_start, days, scrutins, scrutins_solennels, scrutins_ordinaires, deputes }
+}
+
+fn day_entry(jours: &mut BTreeMap<u32, DayCounts>, day: u32) -> &mut DayCounts {
+    jours.entry(day).or_insert(DayCounts { day, ..DayCounts::default() })
+}
+
+/// Regroupe les compteurs journaliers par semaine ISO ; les séries de groupe additionnent,
+/// jour par jour, celles des députés membres du groupe à cette date.
+fn weekly_from_daily(daily: &DailyActivity, raw: &RawDataset) -> WeeklySeries {
+    let week_start = iso_week_monday(daily.day_start);
+    let offset = (daily.day_start - week_start).num_days() as usize;
+    let weeks = if daily.days > 0 { (offset + daily.days - 1) / 7 + 1 } else { 0 };
+    let week_of = |day: usize| (offset + day) / 7;
+    let by_id: HashMap<&str, &Depute> = raw.deputes.iter().map(|d| (d.id.as_str(), d)).collect();
+
+    let mut deputes: HashMap<String, WeeklyBuckets> = HashMap::with_capacity(daily.deputes.len());
+    let mut groupes: BTreeMap<String, WeeklyBuckets> = BTreeMap::new();
+    for (id, dep) in &daily.deputes {
+        let depute = by_id.get(id.as_str());
+        let mut b = WeeklyBuckets::with_weeks(weeks);
+        let groupe_of_day =
+            |day: usize| depute.and_then(|d| d.groupe_id_at(daily.day_start + Duration::days(day as i64)));
+        for &(start, end) in &dep.mandats {
+            let first = (start - daily.day_start).num_days() as usize;
+            let last = (end - daily.day_start).num_days() as usize;
+            for day in first..=last.min(daily.days.saturating_sub(1)) {
+                let w = week_of(day);
+                b.scrutins_eligibles[w] += daily.scrutins[day];
+                if let Some(g) = groupe_of_day(day) {
+                    buckets_for(&mut groupes, g, weeks).scrutins_eligibles[w] += daily.scrutins[day];
+                }
+            }
+        }
+        for c in &dep.jours {
+            let (day, w) = (c.day as usize, week_of(c.day as usize));
+            let g = groupe_of_day(day).map(|g| buckets_for(&mut groupes, g, weeks));
+            for acc in std::iter::once(&mut b).chain(g) {
+                acc.votes_exprimes[w] += c.votes_exprimes;
+                acc.amd_authored[w] += c.amd_authored;
+                acc.amd_adopted[w] += c.amd_adopted;
+                acc.amd_cosigned[w] += c.amd_cosigned;
+            }
+        }
+        deputes.insert(id.clone(), b);
+    }
+
+    WeeklySeries { week_start, weeks, deputes, groupes }
+}
+
+fn buckets_for<'a>(groupes: &'a mut BTreeMap<String, WeeklyBuckets>, g: &str, weeks: usize) -> &'a mut WeeklyBuckets {
+    groupes.entry(g.to_string()).or_insert_with(|| WeeklyBuckets::with_weeks(weeks))
+}
+
 fn compute_period(
     raw: &RawDataset,
+    index: &AggregationIndex,
     period_start: NaiveDate,
     period_end: NaiveDate,
     include_undated_amd: bool,
 ) -> Vec<DeputeStats> {
     let t_period = Instant::now();
-    info!(
-        "compute_period: début [{} -> {}] (deputes={}, scrutins={}, amendements={}, include_undated_amd={})",
-        period_start,
-        period_end,
-        raw.deputes.len(),
-        raw.scrutins.len(),
-        raw.amendements.len(),
-        include_undated_amd
-    );
 
     // PERF: calcule le réseau de co-signatures une seule fois par période
     // (au lieu de rescanner tous les amendements pour chaque député).
-    let t_cosign = Instant::now();
     let cosign_analytics =
-        compute_cosign_analytics_for_period(raw, period_start, period_end, include_undated_amd);
-    info!("compute_period: co-signatures prêtes en {:?}", t_cosign.elapsed());
-
-    let total = raw.deputes.len();
-    let out: Vec<DeputeStats> = raw.deputes
-        .iter()
-        .enumerate()
-        .map(|(idx, dep)| {
-            let done = idx + 1;
-            if done == 1 || done % 100 == 0 || done == total {
-                info!(
-                    "compute_period: députés {}/{} [{} -> {}]",
-                    done, total, period_start, period_end
-                );
-            }
-            compute_depute_stats(
-                dep,
-                raw,
-                period_start,
-                period_end,
-                include_undated_amd,
-                &cosign_analytics,
-            )
-        })
-        .collect();
+        compute_cosign_analytics_for_period(raw, index, period_start, period_end, include_undated_amd);
+
+    let out = map_deputes(&raw.deputes, |idx, dep| {
+        compute_depute_stats(
+            idx,
+            dep,
+            raw,
+            index,
+            period_start,
+            period_end,
+            include_undated_amd,
+            &cosign_analytics,
+        )
+    });
 
     info!(
-        "compute_period: terminé [{} -> {}] en {:?}",
+        "compute_period: [{} -> {}] en {:?} (co-signatures incluses)",
         period_start,
         period_end,
         t_period.elapsed()
@@ -180,9 +798,12 @@ fn compute_period(
     out
 }
 
+#[allow(clippy::too_many_arguments)]
 fn compute_depute_stats(
+    dep_idx: usize,
     dep: &Depute,
     raw: &RawDataset,
+    index: &AggregationIndex,
     period_start: NaiveDate,
     period_end: NaiveDate,
     include_undated_amd: bool,
@@ -210,6 +831,7 @@ fn compute_depute_stats(
             prenom: dep.prenom.clone(),
             groupe_abrev: dep.groupe_abrev.clone(),
             groupe_nom: dep.groupe_nom.clone(),
+            groupe_id: dep.groupe_id.clone(),
             parti_rattachement: dep.parti_nom.clone(),
             dept: dep.dept_nom.clone(),
             circo: dep.circo.clone(),
@@ -246,6 +868,32 @@ fn compute_depute_stats(
             top_dossiers: vec![],
             top_cosignataires: vec![],
             cosign_network: None,
+            loyalty_votes: 0,
+            dissent_count: 0,
+            loyalty_rate: None,
+            recent_dissents: vec![],
+            scrutins_solennels: 0,
+            votes_solennels_exprimes: 0,
+            participation_rate_solennel: None,
+            scrutins_ordinaires: 0,
+            votes_ordinaires_exprimes: 0,
+            participation_rate_ordinaire: None,
+            mises_au_point: 0,
+            votes_par_delegation: 0,
+            questions_ecrites: 0,
+            questions_orales: 0,
+            questions_gouvernement: 0,
+            questions_repondues: 0,
+            questions_answer_rate: None,
+            commissions: dep.commissions.clone(),
+            groupes: dep.groupes.clone(),
+            commission_reunions: 0,
+            commission_presences: 0,
+            commission_excuses: 0,
+            commission_presence_rate: None,
+            rapporteur_fond: 0,
+            rapporteur_avis: 0,
+            rapporteur_special: 0,
         };
     }
 
@@ -253,52 +901,72 @@ fn compute_depute_stats(
     let mut scrutins_eligibles = 0u32;
     let mut votes_exprimes = 0u32;
     let mut non_votant = 0u32;
-    let mut absent = 0u32;
     let mut pour_count = 0u32;
     let mut contre_count = 0u32;
     let mut abst_count = 0u32;
-    let mut votes_par_dossier: HashMap<String, u32> = HashMap::new();
-
-    for scrutin in &raw.scrutins {
-        let date = match scrutin.date {
-            Some(d) => d,
-            None => continue, // scrutin sans date = non comptabilisable
-        };
-        if !date_in_windows(date, &effective_windows) {
-            continue;
-        }
-        scrutins_eligibles += 1;
-
-        match scrutin.votes.get(&dep.id) {
-            Some(VotePosition::Pour) => {
-                votes_exprimes += 1;
-                pour_count += 1;
-                if let Some(dref) = &scrutin.dossier_ref {
-                    *votes_par_dossier.entry(dref.clone()).or_insert(0) += 1;
+    let mut votes_par_dossier: HashMap<&str, u32> = HashMap::new();
+    let mut loyalty_votes = 0u32;
+    let mut dissents: Vec<DissentVote> = Vec::new();
+    let mut scrutins_solennels = 0u32;
+    let mut votes_solennels_exprimes = 0u32;
+    let mut scrutins_ordinaires = 0u32;
+    let mut votes_ordinaires_exprimes = 0u32;
+    let mut mises_au_point = 0u32;
+    let mut votes_par_delegation = 0u32;
+
+    for &w in &effective_windows {
+        let (lo, hi) = index.scrutin_range(w);
+        scrutins_eligibles += (hi - lo) as u32;
+        scrutins_solennels += index.solennels_before[hi] - index.solennels_before[lo];
+        scrutins_ordinaires += index.ordinaires_before[hi] - index.ordinaires_before[lo];
+
+        // Seuls les scrutins où le député figure peuvent modifier les autres compteurs
+        for vote in in_range(&index.votes_by_dep[dep_idx], (lo, hi), |v| v.pos) {
+            let scrutin = index.scrutins[vote.pos as usize];
+            let exprime = vote.position.as_ref().is_some_and(is_expressed);
+
+            if exprime {
+                match vote_kind(scrutin) {
+                    VoteKind::Solennel => votes_solennels_exprimes += 1,
+                    VoteKind::Ordinaire => votes_ordinaires_exprimes += 1,
+                    VoteKind::Autre => {}
                 }
             }
-            Some(VotePosition::Contre) => {
-                votes_exprimes += 1;
-                contre_count += 1;
-                if let Some(dref) = &scrutin.dossier_ref {
-                    *votes_par_dossier.entry(dref.clone()).or_insert(0) += 1;
+            mises_au_point += vote.mise_au_point as u32;
+            votes_par_delegation += vote.par_delegation as u32;
+
+            // Loyauté : vote exprimé comparé à la position majoritaire du groupe sous lequel il est décompté
+            if let (true, Some(position), Some(groupe_position)) = (exprime, &vote.position, &vote.groupe_position) {
+                loyalty_votes += 1;
+                if position != groupe_position {
+                    dissents.push(DissentVote {
+                        scrutin_id: scrutin.id.clone(),
+                        numero: scrutin.numero,
+                        date: index.scrutin_dates[vote.pos as usize],
+                        titre: scrutin.titre.clone(),
+                        position: position.clone(),
+                        groupe_position: groupe_position.clone(),
+                    });
                 }
             }
-            Some(VotePosition::Abstention) => {
+
+            match vote.position {
+                Some(VotePosition::Pour) => pour_count += 1,
+                Some(VotePosition::Contre) => contre_count += 1,
+                Some(VotePosition::Abstention) => abst_count += 1,
+                Some(VotePosition::NonVotant) => non_votant += 1,
+                Some(VotePosition::Absent) | None => {}
+            }
+            if exprime {
                 votes_exprimes += 1;
-                abst_count += 1;
                 if let Some(dref) = &scrutin.dossier_ref {
-                    *votes_par_dossier.entry(dref.clone()).or_insert(0) += 1;
+                    *votes_par_dossier.entry(dref.as_str()).or_insert(0) += 1;
                 }
             }
-            Some(VotePosition::NonVotant) => {
-                non_votant += 1;
-            }
-            Some(VotePosition::Absent) | None => {
-                absent += 1;
-            }
         }
     }
+    // Scrutins éligibles sans position exprimée ni non-votant
+    let absent = scrutins_eligibles - votes_exprimes - non_votant;
 
     let participation_rate = if scrutins_eligibles > 0 {
         votes_exprimes as f64 / scrutins_eligibles as f64
@@ -306,33 +974,39 @@ fn compute_depute_stats(
         0.0
     };
 
+    let participation_rate_solennel = ratio(votes_solennels_exprimes, scrutins_solennels);
+    let participation_rate_ordinaire = ratio(votes_ordinaires_exprimes, scrutins_ordinaires);
+
+    let dissent_count = dissents.len() as u32;
+    let loyalty_rate = if loyalty_votes > 0 {
+        Some(1.0 - dissent_count as f64 / loyalty_votes as f64)
+    } else {
+        None
+    };
+    dissents.sort_by(|a, b| b.date.cmp(&a.date).then(b.numero.cmp(&a.numero)));
+    dissents.truncate(RECENT_DISSENTS_LIMIT);
+
     // ── Amendements ──────────────────────────────────────────────────────────
     let mut amd_authored = 0u32;
     let mut amd_adopted  = 0u32;
     let mut amd_cosigned = 0u32;
-    let mut amd_par_dossier: HashMap<String, u32> = HashMap::new();
-
-    for amd in &raw.amendements {
-        let in_window = match amd.date {
-            Some(d) => date_in_windows(d, &effective_windows),
-            // Sans date: inclure seulement pour LEG (on suppose toute la législature)
-            None => include_undated_amd && !effective_windows.is_empty(),
-        };
-        if !in_window {
-            continue;
-        }
-
-        if amd.auteur_id.as_deref() == Some(dep.id.as_str()) {
+    let mut amd_par_dossier: HashMap<&str, u32> = HashMap::new();
+
+    // Sans date: inclure seulement pour LEG (on suppose toute la législature)
+    let undated = include_undated_amd.then(|| index.amd_undated());
+    let ranges = effective_windows.iter().map(|&w| index.amd_range(w)).chain(undated);
+    for range in ranges {
+        for &pos in refs_in(&index.amd_by_author[dep_idx], range) {
+            let amd = index.amendements[pos as usize];
             amd_authored += 1;
             if amd.adopte {
                 amd_adopted += 1;
             }
             if let Some(dref) = &amd.dossier_ref {
-                *amd_par_dossier.entry(dref.clone()).or_insert(0) += 1;
+                *amd_par_dossier.entry(dref.as_str()).or_insert(0) += 1;
             }
-        } else if amd.cosignataires_ids.iter().any(|id| id == &dep.id) {
-            amd_cosigned += 1;
         }
+        amd_cosigned += refs_in(&index.amd_by_cosigner[dep_idx], range).len() as u32;
     }
 
     let amd_adoption_rate = if amd_authored > 0 {
@@ -341,6 +1015,82 @@ fn compute_depute_stats(
         None
     };
 
+    // ── Interventions en séance ──────────────────────────────────────────────
+    let mut interventions_count = 0u32;
+    let mut interventions_chars = 0u32;
+    let mut itv_par_dossier: HashMap<&str, u32> = HashMap::new();
+    for &w in &effective_windows {
+        for &pos in refs_in(&index.itv_by_dep[dep_idx], index.itv_range(w)) {
+            let itv = index.interventions[pos as usize];
+            interventions_count += 1;
+            interventions_chars = interventions_chars.saturating_add(itv.chars);
+            if let Some(dref) = &itv.dossier_ref {
+                *itv_par_dossier.entry(dref.as_str()).or_insert(0) += 1;
+            }
+        }
+    }
+
+    // ── Questions ────────────────────────────────────────────────────────────
+    let mut questions_ecrites = 0u32;
+    let mut questions_orales = 0u32;
+    let mut questions_gouvernement = 0u32;
+    let mut questions_repondues = 0u32;
+    for &w in &effective_windows {
+        for &pos in refs_in(&index.qst_by_dep[dep_idx], index.qst_range(w)) {
+            let q = index.questions[pos as usize];
+            match q.type_question.as_str() {
+                // Posée et répondue en séance : hors taux de réponse
+                "QAG" => {
+                    questions_gouvernement += 1;
+                    continue;
+                }
+                "QOSD" => questions_orales += 1,
+                _ => questions_ecrites += 1,
+            }
+            if q.repondue {
+                questions_repondues += 1;
+            }
+        }
+    }
+    let questions_answer_rate = ratio(questions_repondues, questions_ecrites + questions_orales);
+
+    // ── Réunions de commission ───────────────────────────────────────────────
+    let mut commission_reunions = 0u32;
+    let mut commission_presences = 0u32;
+    let mut commission_excuses = 0u32;
+    for &w in &effective_windows {
+        for &(_, presence) in in_range(&index.reu_by_dep[dep_idx], index.reu_range(w), |r| r.0) {
+            commission_reunions += 1;
+            match presence {
+                Presence::Present => commission_presences += 1,
+                Presence::Excuse => commission_excuses += 1,
+                Presence::Absent => {}
+            }
+        }
+    }
+    let commission_presence_rate = ratio(commission_presences, commission_reunions);
+
+    // ── Rapports ─────────────────────────────────────────────────────────────
+    // (dossier, rôle) distincts : plusieurs nominations au même rôle comptent une fois
+    let mut rapports: HashSet<(&str, &str)> = HashSet::new();
+    for &w in &effective_windows {
+        for &pos in refs_in(&index.rap_by_dep[dep_idx], index.rap_range(w)) {
+            let (dossier_id, r) = index.rapporteurs[pos as usize];
+            rapports.insert((dossier_id, r.role.as_str()));
+        }
+    }
+    let rapports_role = |role: &str| rapports.iter().filter(|(_, r)| *r == role).count() as u32;
+    let (rapporteur_fond, rapporteur_avis, rapporteur_special) =
+        (rapports_role("fond"), rapports_role("avis"), rapports_role("special"));
+    // Rôle retenu par dossier : au fond, puis spécial, puis pour avis
+    let mut rap_par_dossier: HashMap<&str, &str> = HashMap::new();
+    for &(dossier_id, role) in &rapports {
+        let retenu = rap_par_dossier.entry(dossier_id).or_insert(role);
+        if rapporteur_rank(role) < rapporteur_rank(retenu) {
+            *retenu = role;
+        }
+    }
+
     let top_cosignataires = cosign_analytics
         .top_by_dep
         .get(dep.id.as_str())
@@ -352,15 +1102,19 @@ fn compute_depute_stats(
         .cloned();
 
     // ── Top dossiers ──────────────────────────────────────────────────────────
-    let all_dossier_ids: std::collections::HashSet<&String> = votes_par_dossier.keys()
-        .chain(amd_par_dossier.keys())
+    let all_dossier_ids: std::collections::HashSet<&str> = votes_par_dossier.keys().copied()
+        .chain(amd_par_dossier.keys().copied())
+        .chain(itv_par_dossier.keys().copied())
+        .chain(rap_par_dossier.keys().copied())
         .collect();
 
     let mut dossier_scores: Vec<DossierScore> = all_dossier_ids.iter()
         .filter_map(|did| {
             let v = votes_par_dossier.get(*did).copied().unwrap_or(0);
             let a = amd_par_dossier.get(*did).copied().unwrap_or(0);
-            let score = v + 2 * a;
+            let i = itv_par_dossier.get(*did).copied().unwrap_or(0);
+            let rapporteur = rap_par_dossier.get(*did).map(|r| r.to_string());
+            let score = v + 2 * a + i + if rapporteur.is_some() { RAPPORTEUR_WEIGHT } else { 0 };
             if score == 0 { return None; }
             let titre = raw.dossiers.get(*did)
                 .map(|d| d.titre.clone())
@@ -371,7 +1125,8 @@ fn compute_depute_stats(
                 titre,
                 votes: v,
                 amendements: a,
-                interventions: 0,
+                interventions: i,
+                rapporteur,
                 score,
             })
         })
@@ -386,6 +1141,7 @@ fn compute_depute_stats(
         prenom: dep.prenom.clone(),
         groupe_abrev: dep.groupe_abrev.clone(),
         groupe_nom: dep.groupe_nom.clone(),
+        groupe_id: dep.groupe_id.clone(),
         parti_rattachement: dep.parti_nom.clone(),
         dept: dep.dept_nom.clone(),
         circo: dep.circo.clone(),
@@ -417,11 +1173,45 @@ fn compute_depute_stats(
         amd_adopted,
         amd_adoption_rate,
         amd_cosigned,
-        interventions_count: 0,
-        interventions_chars: 0,
+        interventions_count,
+        interventions_chars,
         top_dossiers: dossier_scores,
         top_cosignataires,
         cosign_network,
+        loyalty_votes,
+        dissent_count,
+        loyalty_rate,
+        recent_dissents: dissents,
+        scrutins_solennels,
+        votes_solennels_exprimes,
+        participation_rate_solennel,
+        scrutins_ordinaires,
+        votes_ordinaires_exprimes,
+        participation_rate_ordinaire,
+        mises_au_point,
+        votes_par_delegation,
+        questions_ecrites,
+        questions_orales,
+        questions_gouvernement,
+        questions_repondues,
+        questions_answer_rate,
+        commissions: dep.commissions.clone(),
+        groupes: dep.groupes.clone(),
+        commission_reunions,
+        commission_presences,
+        commission_excuses,
+        commission_presence_rate,
+        rapporteur_fond,
+        rapporteur_avis,
+        rapporteur_special,
+    }
+}
+
+fn rapporteur_rank(role: &str) -> u8 {
+    match role {
+        "fond" => 0,
+        "special" => 1,
+        _ => 2,
     }
 }
 
@@ -429,6 +1219,7 @@ fn compute_depute_stats(
 
 fn compute_cosign_analytics_for_period(
     raw: &RawDataset,
+    index: &AggregationIndex,
     period_start: NaiveDate,
     period_end: NaiveDate,
     include_undated_amd: bool,
@@ -437,33 +1228,26 @@ fn compute_cosign_analytics_for_period(
         return PeriodCosignAnalytics::default();
     }
 
-    let dep_idx_by_id: HashMap<&str, usize> = raw
-        .deputes
-        .iter()
-        .enumerate()
-        .map(|(idx, d)| (d.id.as_str(), idx))
-        .collect();
-
     let mut pair_counts: Vec<HashMap<usize, u32>> = vec![HashMap::new(); raw.deputes.len()];
 
-    for amd in &raw.amendements {
-        let in_window = match amd.date {
-            Some(d) => d >= period_start && d <= period_end,
-            None => include_undated_amd,
-        };
-        if !in_window {
-            continue;
-        }
+    let (lo, hi) = index.amd_range(DateWindow { start: period_start, end: period_end });
+    let dated = &index.amendements[lo..hi];
+    let undated: &[&Amendement] = if include_undated_amd {
+        &index.amendements[index.amd_dates.len()..]
+    } else {
+        &[]
+    };
 
+    for amd in dated.iter().chain(undated) {
         let mut signer_indices: Vec<usize> = Vec::with_capacity(1 + amd.cosignataires_ids.len());
 
         if let Some(author_id) = amd.auteur_id.as_deref() {
-            if let Some(&idx) = dep_idx_by_id.get(author_id) {
+            if let Some(&idx) = index.dep_idx.get(author_id) {
                 signer_indices.push(idx);
             }
         }
         for cid in &amd.cosignataires_ids {
-            if let Some(&idx) = dep_idx_by_id.get(cid.as_str()) {
+            if let Some(&idx) = index.dep_idx.get(cid.as_str()) {
                 signer_indices.push(idx);
             }
         }
@@ -605,3 +1389,528 @@ fn compute_cosign_analytics_for_period(
 
     PeriodCosignAnalytics { top_by_dep, network_by_dep }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::DailyActivityFile;
+
+    fn d(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn session_calendar_clips_to_legislature_and_skips_empty_summers() {
+        let scrutins = [d("2024-07-18"), d("2024-10-02"), d("2025-03-11"), d("2025-09-22")];
+        let sessions = session_calendar(d("2024-07-07"), d("2025-11-15"), &scrutins);
+        let ids: Vec<&str> = sessions.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, ["SO-2025-2026", "SE-2025", "SO-2024-2025", "SE-2024"]);
+
+        let se_2024 = &sessions[3];
+        assert_eq!((se_2024.start, se_2024.end), (d("2024-07-07"), d("2024-09-30")));
+        assert_eq!(se_2024.kind, SessionKind::Extraordinaire);
+        assert!(!se_2024.en_cours);
+
+        let current = &sessions[0];
+        assert_eq!((current.start, current.end), (d("2025-10-01"), d("2025-11-15")));
+        assert!(current.en_cours);
+        assert_eq!(current.scrutins, 0);
+        assert_eq!(sessions[2].scrutins, 2);
+
+        // Été sans scrutin : pas de session extraordinaire
+        let quiet = session_calendar(d("2024-07-07"), d("2025-11-15"), &scrutins[1..3]);
+        assert!(quiet.iter().all(|s| s.kind == SessionKind::Ordinaire));
+    }
+
+    #[test]
+    fn leg_window_starts_at_second_round_of_the_legislature() {
+        assert_eq!(legislature_start(16), Some(d("2022-06-19")));
+        assert_eq!(legislature_start(17), Some(d("2024-07-07")));
+        assert_eq!(legislature_start(18), None);
+
+        // Député réélu en 2024, mandat continu depuis la 16e législature
+        let dep: Depute = serde_json::from_value(serde_json::json!({
+            "id": "PA1", "nom": "Dupont", "prenom": "Alice",
+            "mandat_debut": "2022-06-22",
+            "mandat_assemblee_episodes": [{ "date_debut": "2022-06-22", "date_fin": null }]
+        }))
+        .unwrap();
+        let leg17 = legislature_start(17).unwrap();
+        let windows = effective_mandate_windows(&dep, leg17, d("2025-06-30"));
+        assert_eq!(windows.len(), 1);
+        assert_eq!((windows[0].start, windows[0].end), (d("2024-07-07"), d("2025-06-30")));
+    }
+
+    /// Activité hors scrutins et amendements de deux députés : `PA1`, en mandat du 18/07/2024
+    /// au 30/11/2024 puis depuis le 01/02/2025, et `PA2`, en mandat continu. Dans chaque
+    /// dimension, `PA1` a une activité par mandat, une entre les deux et une sans date ; `PA2`
+    /// une seule, à la même date que la première de `PA1`.
+    fn activity_dataset() -> RawDataset {
+        let depute = |id: &str, episodes: serde_json::Value| -> Depute {
+            serde_json::from_value(serde_json::json!({
+                "id": id, "nom": format!("Nom {id}"), "prenom": "Alice",
+                "mandat_debut": "2024-07-18",
+                "mandat_assemblee_episodes": episodes
+            }))
+            .unwrap()
+        };
+        let deputes = vec![
+            depute("PA1", serde_json::json!([
+                { "date_debut": "2024-07-18", "date_fin": "2024-11-30" },
+                { "date_debut": "2025-02-01", "date_fin": null }
+            ])),
+            depute("PA2", serde_json::json!([{ "date_debut": "2024-07-18", "date_fin": null }])),
+        ];
+        let (mandat1, mandat2, entre_deux) = (Some("2024-10-01"), Some("2025-03-03"), Some("2024-12-16"));
+
+        let intervention = |acteur: &str, date: Option<&str>, dossier: Option<&str>, chars: u32| Intervention {
+            acteur_id: acteur.to_string(),
+            date: date.map(d),
+            compte_rendu: "CR1".to_string(),
+            point: None,
+            dossier_ref: dossier.map(str::to_string),
+            chars,
+        };
+        let interventions = vec![
+            intervention("PA1", mandat1, Some("DLR1"), 100),
+            intervention("PA1", mandat2, None, 30),
+            intervention("PA1", entre_deux, Some("DLR1"), 50),
+            intervention("PA1", None, Some("DLR1"), 70),
+            intervention("PA2", mandat1, Some("DLR1"), 999),
+        ];
+
+        let question = |k: u32, auteur: &str, type_question: &str, date: Option<&str>, repondue: bool| Question {
+            id: format!("QANR5L17Q{k}"),
+            type_question: type_question.to_string(),
+            numero: Some(k),
+            auteur_id: auteur.to_string(),
+            ministere: None,
+            rubrique: None,
+            titre: None,
+            date_question: date.map(d),
+            date_reponse: None,
+            repondue,
+            cloture: None,
+        };
+        let questions = vec![
+            question(1, "PA1", "QE", mandat1, true),
+            question(2, "PA1", "QOSD", mandat2, false),
+            question(3, "PA1", "QAG", mandat2, true),
+            question(4, "PA1", "QE", entre_deux, true),
+            question(5, "PA1", "QE", None, true),
+            question(6, "PA2", "QE", mandat1, true),
+        ];
+
+        let reunion = |k: u32, date: Option<&str>, participants: &[(&str, Presence)]| Reunion {
+            id: format!("RUANR5L17S{k}"),
+            date: date.map(d),
+            organe_id: Some("PO10".to_string()),
+            participants: participants.iter().map(|(id, p)| (id.to_string(), *p)).collect(),
+        };
+        let reunions = vec![
+            reunion(1, mandat1, &[("PA1", Presence::Present), ("PA2", Presence::Absent)]),
+            reunion(2, mandat2, &[("PA1", Presence::Excuse)]),
+            reunion(3, entre_deux, &[("PA1", Presence::Present)]),
+            reunion(4, None, &[("PA1", Presence::Present)]),
+        ];
+
+        let nomination = |acteur: &str, role: &str, date: Option<&str>| Rapporteur {
+            acteur_id: acteur.to_string(),
+            role: role.to_string(),
+            organe_ref: Some("PO10".to_string()),
+            date: date.map(d),
+        };
+        let dossier = |id: &str, rapporteurs: Vec<Rapporteur>| Dossier {
+            id: id.to_string(),
+            titre: format!("Dossier {id}"),
+            date_depot: None,
+            statut: None,
+            legislature: None,
+            nature: None,
+            numero: None,
+            auteur_id: None,
+            cosignataires_ids: vec![],
+            source_url: None,
+            origin_chamber: None,
+            initiateur_organe_ref: None,
+            rapporteurs,
+        };
+        let dossiers = [
+            // Deux nominations au fond : un seul rapport ; le rôle au fond l'emporte sur l'avis
+            dossier("DLR1", vec![
+                nomination("PA1", "fond", mandat1),
+                nomination("PA1", "fond", Some("2024-10-08")),
+                nomination("PA1", "avis", Some("2024-10-15")),
+            ]),
+            dossier("DLR2", vec![nomination("PA1", "avis", mandat2)]),
+            dossier("DLR3", vec![nomination("PA1", "special", entre_deux)]),
+            dossier("DLR4", vec![nomination("PA1", "fond", None)]),
+            dossier("DLR5", vec![nomination("PA2", "special", mandat1)]),
+        ]
+        .into_iter()
+        .map(|dos| (dos.id.clone(), dos))
+        .collect();
+
+        RawDataset {
+            deputes,
+            organes: HashMap::new(),
+            scrutins: Vec::new(),
+            amendements: Vec::new(),
+            dossiers,
+            interventions,
+            questions,
+            reunions,
+            parse_failures: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn activity_counted_per_deputy_within_mandate_windows() {
+        let raw = activity_dataset();
+        let index = AggregationIndex::build(&raw);
+        let leg = compute_period(&raw, &index, d("2024-07-18"), d("2025-06-30"), false);
+        let y2025 = compute_period(&raw, &index, d("2025-01-01"), d("2025-06-30"), false);
+
+        // Attendu : PA1 sur LEG, PA1 en 2025 (second mandat seul), PA2 sur LEG
+        type Count = fn(&DeputeStats) -> u32;
+        let dimensions: [(&str, Count, [u32; 3]); 4] = [
+            ("interventions", |s| s.interventions_count, [2, 1, 1]),
+            ("questions", |s| s.questions_ecrites + s.questions_orales + s.questions_gouvernement, [3, 2, 1]),
+            ("réunions", |s| s.commission_reunions, [2, 1, 1]),
+            ("rapports", |s| s.rapporteur_fond + s.rapporteur_avis + s.rapporteur_special, [3, 1, 1]),
+        ];
+        for (name, count, expected) in dimensions {
+            assert_eq!([count(&leg[0]), count(&y2025[0]), count(&leg[1])], expected, "{name}");
+        }
+
+        let pa1 = &leg[0];
+        assert_eq!(pa1.interventions_chars, 130);
+        assert_eq!((pa1.questions_ecrites, pa1.questions_orales, pa1.questions_gouvernement), (1, 1, 1));
+        // QAG répondue en séance : hors taux de réponse
+        assert_eq!((pa1.questions_repondues, pa1.questions_answer_rate), (1, Some(0.5)));
+        assert_eq!((pa1.commission_presences, pa1.commission_excuses, pa1.commission_presence_rate), (1, 1, Some(0.5)));
+        assert_eq!((leg[1].commission_presences, leg[1].commission_presence_rate), (0, Some(0.0)));
+        assert_eq!((pa1.rapporteur_fond, pa1.rapporteur_avis, pa1.rapporteur_special), (1, 2, 0));
+        let top: Vec<_> = pa1
+            .top_dossiers
+            .iter()
+            .map(|ds| (ds.dossier_id.as_str(), ds.rapporteur.as_deref(), ds.interventions, ds.score))
+            .collect();
+        assert_eq!(top, [
+            ("DLR1", Some("fond"), 1, RAPPORTEUR_WEIGHT + 1),
+            ("DLR2", Some("avis"), 0, RAPPORTEUR_WEIGHT),
+        ]);
+    }
+
+    /// Générateur pseudo-aléatoire déterministe (LCG), suffisant pour les jeux de test.
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next(&mut self, n: usize) -> usize {
+            self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            ((self.0 >> 33) % n as u64) as usize
+        }
+    }
+
+    /// Jeu synthétique : groupes de taille égale, mandats interrompus pour un député sur 20,
+    /// votes de tous les députés à chaque scrutin, 5 % d'amendements sans date.
+    fn synthetic_dataset(n_deputes: usize, n_scrutins: usize, n_amendements: usize) -> RawDataset {
+        let mut rng = Lcg(42);
+        let start = d("2024-07-18");
+        let span = 340;
+        let groupe_of = |i: usize| format!("PO{}", i % 8);
+
+        let deputes: Vec<Depute> = (0..n_deputes)
+            .map(|i| {
+                let episodes = if i % 20 == 7 {
+                    vec![
+                        MandatAssembleeEpisode { date_debut: start, date_fin: Some(d("2024-11-30")) },
+                        MandatAssembleeEpisode { date_debut: d("2025-02-01"), date_fin: None },
+                    ]
+                } else {
+                    vec![MandatAssembleeEpisode { date_debut: start, date_fin: None }]
+                };
+                Depute {
+                    id: format!("PA{i}"),
+                    nom: format!("Nom{i}"),
+                    prenom: format!("Prénom{i}"),
+                    date_naissance: None,
+                    sexe: None,
+                    pays_naissance: None,
+                    profession: None,
+                    dept_code: None,
+                    dept_nom: None,
+                    circo: None,
+                    mandat_debut: Some(start),
+                    mandat_fin: None,
+                    mandat_debut_legislature: Some(start),
+                    mandat_assemblee_episodes: episodes,
+                    groupe_id: Some(groupe_of(i)),
+                    groupe_abrev: Some(format!("G{}", i % 8)),
+                    groupe_nom: None,
+                    parti_id: None,
+                    parti_nom: None,
+                    email_assemblee: None,
+                    site_web: None,
+                    sites_web: vec![],
+                    sites_web_sources: vec![],
+                    telephones: vec![],
+                    uri_hatvp: None,
+                    commissions: vec![],
+                    groupes: vec![],
+                }
+            })
+            .collect();
+
+        let positions = [
+            VotePosition::Pour,
+            VotePosition::Contre,
+            VotePosition::Abstention,
+            VotePosition::NonVotant,
+            VotePosition::Absent,
+        ];
+        let scrutins: Vec<Scrutin> = (0..n_scrutins)
+            .map(|k| {
+                let mut votes = HashMap::new();
+                let mut groupe_votants = HashMap::new();
+                let mut mises_au_point = HashMap::new();
+                let mut par_delegation = Vec::new();
+                for i in 0..n_deputes {
+                    if rng.next(3) == 0 {
+                        continue;
+                    }
+                    let id = format!("PA{i}");
+                    votes.insert(id.clone(), positions[rng.next(positions.len())].clone());
+                    groupe_votants.insert(id.clone(), groupe_of(i));
+                    if rng.next(200) == 0 {
+                        mises_au_point.insert(id.clone(), VotePosition::Pour);
+                    }
+                    if rng.next(50) == 0 {
+                        par_delegation.push(id);
+                    }
+                }
+                par_delegation.sort();
+                Scrutin {
+                    id: format!("VTANR5L17V{k}"),
+                    numero: k as u32 + 1,
+                    titre: format!("Scrutin {k}"),
+                    date: (k % 97 != 0).then(|| start + Duration::days(rng.next(span) as i64)),
+                    sort: None,
+                    dossier_ref: (k % 3 != 0).then(|| format!("DLR{}", rng.next(150))),
+                    type_vote: Some(["SPO", "SPO", "SPS", "MOC"][rng.next(4)].to_string()),
+                    type_vote_libelle: None,
+                    votes,
+                    groupes: (0..8)
+                        .map(|g| ScrutinGroupe {
+                            organe_ref: format!("PO{g}"),
+                            nombre_membres: None,
+                            position_majoritaire: Some(positions[rng.next(3)].clone()),
+                            pour: 0,
+                            contre: 0,
+                            abstentions: 0,
+                            non_votants: 0,
+                        })
+                        .collect(),
+                    groupe_votants,
+                    mises_au_point,
+                    par_delegation,
+                }
+            })
+            .collect();
+
+        let amendements: Vec<Amendement> = (0..n_amendements)
+            .map(|k| {
+                let auteur = rng.next(n_deputes + 10);
+                let cosignataires_ids = (0..rng.next(9)).map(|_| format!("PA{}", rng.next(n_deputes + 10))).collect();
+                Amendement {
+                    id: format!("AMD{k}"),
+                    numero: None,
+                    auteur_id: Some(format!("PA{auteur}")),
+                    auteur_type: None,
+                    cosignataires_ids,
+                    sort: None,
+                    date: (rng.next(20) != 0).then(|| start + Duration::days(rng.next(span) as i64)),
+                    date_depot: None,
+                    date_circulation: None,
+                    date_examen: None,
+                    date_sort: None,
+                    dossier_ref: (rng.next(4) != 0).then(|| format!("DLR{}", rng.next(150))),
+                    article: None,
+                    texte_ref: None,
+                    adopte: rng.next(7) == 0,
+                    mission_visee: None,
+                    mission_ref: None,
+                    expose_sommaire: None,
+                }
+            })
+            .collect();
+
+        RawDataset {
+            deputes,
+            organes: HashMap::new(),
+            scrutins,
+            amendements,
+            dossiers: HashMap::new(),
+            interventions: Vec::new(),
+            questions: Vec::new(),
+            reunions: Vec::new(),
+            parse_failures: Vec::new(),
+        }
+    }
+
+    /// Compteurs par parcours complet des scrutins et amendements (algorithme d'avant les index).
+    fn naive_counts(raw: &RawDataset, dep: &Depute, start: NaiveDate, end: NaiveDate, undated: bool) -> Vec<u32> {
+        let windows = effective_mandate_windows(dep, start, end);
+        let in_windows = |date: NaiveDate| windows.iter().any(|w| date >= w.start && date <= w.end);
+        let mut c = vec![0u32; 12];
+        for s in raw.scrutins.iter().filter(|s| s.date.is_some_and(in_windows)) {
+            let position = s.votes.get(&dep.id);
+            c[0] += 1;
+            match position {
+                Some(p) if is_expressed(p) => c[1] += 1,
+                Some(VotePosition::NonVotant) => c[2] += 1,
+                _ => c[3] += 1,
+            }
+            if s.type_vote.as_deref() == Some("SPS") {
+                c[4] += 1;
+            }
+            c[5] += s.mises_au_point.contains_key(&dep.id) as u32;
+            c[6] += s.par_delegation.contains(&dep.id) as u32;
+            if let (Some(p), Some(g)) = (position, groupe_position_for(s, &dep.id)) {
+                c[7] += is_expressed(p) as u32;
+                c[8] += (is_expressed(p) && p != g) as u32;
+            }
+        }
+        for a in &raw.amendements {
+            let in_window = match a.date {
+                Some(date) => in_windows(date),
+                None => undated && !windows.is_empty(),
+            };
+            if !in_window {
+                continue;
+            }
+            if a.auteur_id.as_deref() == Some(dep.id.as_str()) {
+                c[9] += 1;
+                c[10] += a.adopte as u32;
+            } else if a.cosignataires_ids.contains(&dep.id) {
+                c[11] += 1;
+            }
+        }
+        c
+    }
+
+    #[test]
+    fn indexed_period_matches_full_scan() {
+        let raw = synthetic_dataset(40, 300, 3000);
+        let index = AggregationIndex::build(&raw);
+        let windows = [
+            (d("2025-05-01"), d("2025-05-31"), false),
+            (d("2024-10-01"), d("2025-06-30"), false),
+            (d("2024-07-18"), d("2025-06-30"), true),
+        ];
+        for (start, end, undated) in windows {
+            let stats = compute_period(&raw, &index, start, end, undated);
+            for (dep, st) in raw.deputes.iter().zip(&stats) {
+                let got = vec![
+                    st.scrutins_eligibles,
+                    st.votes_exprimes,
+                    st.non_votant,
+                    st.absent,
+                    st.scrutins_solennels,
+                    st.mises_au_point,
+                    st.votes_par_delegation,
+                    st.loyalty_votes,
+                    st.dissent_count,
+                    st.amd_authored,
+                    st.amd_adopted,
+                    st.amd_cosigned,
+                ];
+                assert_eq!(got, naive_counts(&raw, dep, start, end, undated), "{} [{start} -> {end}]", dep.id);
+            }
+        }
+    }
+
+    #[test]
+    fn daily_range_stats_match_period_stats() {
+        let (leg_start, today) = (d("2024-07-18"), d("2025-06-30"));
+        let counters = |s: &DeputeStats| {
+            let rates = [
+                Some(s.participation_rate),
+                s.participation_rate_solennel,
+                s.participation_rate_ordinaire,
+                s.amd_adoption_rate,
+                s.loyalty_rate,
+                s.questions_answer_rate,
+                s.commission_presence_rate,
+            ];
+            let counts = vec![
+                s.scrutins_eligibles, s.votes_exprimes, s.non_votant, s.absent,
+                s.pour_count, s.contre_count, s.abst_count,
+                s.scrutins_solennels, s.votes_solennels_exprimes,
+                s.scrutins_ordinaires, s.votes_ordinaires_exprimes,
+                s.mises_au_point, s.votes_par_delegation, s.loyalty_votes, s.dissent_count,
+                s.amd_authored, s.amd_adopted, s.amd_cosigned,
+                s.interventions_count, s.interventions_chars,
+                s.questions_ecrites, s.questions_orales, s.questions_gouvernement, s.questions_repondues,
+                s.commission_reunions, s.commission_presences, s.commission_excuses,
+            ];
+            (counts, rates)
+        };
+
+        for raw in [synthetic_dataset(40, 300, 3000), activity_dataset()] {
+            let index = AggregationIndex::build(&raw);
+            let daily = compute_daily_activity(&raw, &index, leg_start, today);
+            let file = DailyActivityFile {
+                schema_version: 2,
+                generated_at: String::new(),
+                day_start: daily.day_start,
+                days: daily.days,
+                scrutins: daily.scrutins,
+                scrutins_solennels: daily.scrutins_solennels,
+                scrutins_ordinaires: daily.scrutins_ordinaires,
+                deputes: daily.deputes.into_iter().collect(),
+            };
+            for (start, end) in [(leg_start, today), (d("2024-10-01"), d("2025-03-15")), (d("2025-05-01"), d("2025-05-31"))] {
+                for st in compute_period(&raw, &index, start, end, false) {
+                    let custom = file.stats_for_range(&st, start, end);
+                    assert_eq!(counters(&custom), counters(&st), "{} [{start} -> {end}]", st.deputy_id);
+                }
+            }
+        }
+    }
+
+    /// Banc d'essai à l'échelle d'une législature :
+    /// `cargo test --release -p pipeline bench_compute_all -- --ignored --nocapture`.
+    /// Compare sur le même jeu la fenêtre LEG par parcours complet ([`naive_counts`]) et par
+    /// les index (construction comprise), puis chronomètre `compute_all`.
+    /// `BENCH_DUMP=<fichier>` écrit les agrégats produits, pour comparer deux versions du code.
+    #[test]
+    #[ignore]
+    fn bench_compute_all() {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+        let raw = synthetic_dataset(577, 4000, 80_000);
+        let (start, end) = (d("2024-07-18"), d("2025-06-30"));
+
+        let t = Instant::now();
+        let scanned: Vec<Vec<u32>> = raw.deputes.iter().map(|dep| naive_counts(&raw, dep, start, end, true)).collect();
+        let scan = t.elapsed();
+        let t = Instant::now();
+        let index = AggregationIndex::build(&raw);
+        let stats = compute_period(&raw, &index, start, end, true);
+        let indexed = t.elapsed();
+        assert_eq!(stats.len(), scanned.len());
+        eprintln!("fenêtre LEG: parcours complet {scan:?}, index {indexed:?} (compteurs, dossiers et co-signatures)");
+
+        let now = d("2025-06-30").and_hms_opt(12, 0, 0).unwrap().and_utc();
+        let t = Instant::now();
+        let aggregates = compute_all(&raw, 17, d("2024-07-18"), now).unwrap();
+        eprintln!(
+            "compute_all: {:?} (577 députés, 4000 scrutins, 80 000 amendements, {} sessions)",
+            t.elapsed(),
+            aggregates.sessions.len()
+        );
+        if let Ok(path) = std::env::var("BENCH_DUMP") {
+            std::fs::write(path, serde_json::to_vec(&aggregates).unwrap()).unwrap();
+        }
+    }
+}
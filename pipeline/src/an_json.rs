@@ -0,0 +1,783 @@
+//! Schéma typé des documents AN (scrutins, amendements, questions, réunions), désérialisé en flux : chaque
+//! élément est converti dès sa lecture, sans construire d'arbre `serde_json::Value` pour
+//! tout le document.
+//!
+//! Le dataset AN est irrégulier (objet seul ou tableau, nombres publiés en chaîne, champs
+//! tantôt chaîne tantôt objet) : `Scalar`, `Node` et `Many` acceptent toutes ces variantes et
+//! retombent sur une valeur vide au lieu de faire échouer le document, comme les accès
+//! `v["..."]` sur `serde_json::Value`.
+
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{DeserializeSeed, Deserializer, Error, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::Read;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+/// Méthodes de `Visitor` qui consomment une valeur non attendue sans erreur.
+macro_rules! ignore_other_values {
+    ($($skip:ident),*) => {
+        fn visit_bool<E: Error>(self, _: bool) -> Result<Self::Value, E> { Ok(Default::default()) }
+        fn visit_i64<E: Error>(self, _: i64) -> Result<Self::Value, E> { Ok(Default::default()) }
+        fn visit_u64<E: Error>(self, _: u64) -> Result<Self::Value, E> { Ok(Default::default()) }
+        fn visit_f64<E: Error>(self, _: f64) -> Result<Self::Value, E> { Ok(Default::default()) }
+        fn visit_unit<E: Error>(self) -> Result<Self::Value, E> { Ok(Default::default()) }
+        $(ignore_other_values!(@$skip);)*
+    };
+    (@str) => {
+        fn visit_str<E: Error>(self, _: &str) -> Result<Self::Value, E> { Ok(Default::default()) }
+    };
+    (@seq) => {
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            while seq.next_element::<IgnoredAny>()?.is_some() {}
+            Ok(Default::default())
+        }
+    };
+    (@map) => {
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+            Ok(Default::default())
+        }
+    };
+}
+
+// ─── Types tolérants ───────────────────────────────────────────────────────
+
+/// Feuille AN : chaîne, entier positif ou booléen ; objets, tableaux et autres nombres sont ignorés.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Scalar {
+    Str(String),
+    Num(u64),
+    Bool(bool),
+    #[default]
+    Other,
+}
+
+impl Scalar {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Scalar::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Entier publié tantôt en nombre, tantôt en chaîne ("12").
+    pub fn as_u32(&self) -> Option<u32> {
+        match self {
+            Scalar::Num(n) => Some(*n as u32),
+            Scalar::Str(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Booléen publié en chaîne ("true"/"false") ou en JSON natif.
+    pub fn as_bool(&self) -> bool {
+        match self {
+            Scalar::Bool(b) => *b,
+            Scalar::Str(s) => s.trim().eq_ignore_ascii_case("true"),
+            _ => false,
+        }
+    }
+
+    pub fn to_string_opt(&self) -> Option<String> {
+        self.as_str().map(String::from)
+    }
+}
+
+impl<'de> Deserialize<'de> for Scalar {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct ScalarVisitor;
+
+        impl<'de> Visitor<'de> for ScalarVisitor {
+            type Value = Scalar;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("une valeur JSON")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Scalar, E> {
+                Ok(Scalar::Str(v.to_string()))
+            }
+
+            fn visit_string<E: Error>(self, v: String) -> Result<Scalar, E> {
+                Ok(Scalar::Str(v))
+            }
+
+            fn visit_u64<E: Error>(self, v: u64) -> Result<Scalar, E> {
+                Ok(Scalar::Num(v))
+            }
+
+            fn visit_bool<E: Error>(self, v: bool) -> Result<Scalar, E> {
+                Ok(Scalar::Bool(v))
+            }
+
+            fn visit_i64<E: Error>(self, _: i64) -> Result<Scalar, E> {
+                Ok(Scalar::Other)
+            }
+
+            fn visit_f64<E: Error>(self, _: f64) -> Result<Scalar, E> {
+                Ok(Scalar::Other)
+            }
+
+            fn visit_unit<E: Error>(self) -> Result<Scalar, E> {
+                Ok(Scalar::Other)
+            }
+
+            ignore_other_values!(@seq);
+            ignore_other_values!(@map);
+        }
+
+        d.deserialize_any(ScalarVisitor)
+    }
+}
+
+/// Objet AN lu dans `T` ; une chaîne est conservée dans `text`, toute autre valeur ignorée.
+#[derive(Debug, Clone, Default)]
+pub struct Node<T> {
+    pub is_object: bool,
+    pub text: Option<String>,
+    pub inner: T,
+}
+
+impl<T> Deref for Node<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Default> Deserialize<'de> for Node<T> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct NodeVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de> + Default> Visitor<'de> for NodeVisitor<T> {
+            type Value = Node<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("un objet JSON")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Node<T>, A::Error> {
+                let inner = T::deserialize(MapAccessDeserializer::new(map))?;
+                Ok(Node { is_object: true, text: None, inner })
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Node<T>, E> {
+                Ok(Node { is_object: false, text: Some(v.to_string()), inner: T::default() })
+            }
+
+            ignore_other_values!(seq);
+        }
+
+        d.deserialize_any(NodeVisitor(PhantomData))
+    }
+}
+
+/// `[a, b, …]` ou `a` seul (objet) ; toute autre valeur donne une liste vide.
+#[derive(Debug, Clone)]
+pub struct Many<T>(pub Vec<T>);
+
+impl<T> Default for Many<T> {
+    fn default() -> Self {
+        Many(Vec::new())
+    }
+}
+
+impl<T> Deref for Many<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Many<T> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct ManyVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for ManyVisitor<T> {
+            type Value = Many<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("un objet ou un tableau JSON")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Many<T>, A::Error> {
+                let mut out = Vec::new();
+                while let Some(item) = seq.next_element()? {
+                    out.push(item);
+                }
+                Ok(Many(out))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Many<T>, A::Error> {
+                Ok(Many(vec![T::deserialize(MapAccessDeserializer::new(map))?]))
+            }
+
+            ignore_other_values!(str);
+        }
+
+        d.deserialize_any(ManyVisitor(PhantomData))
+    }
+}
+
+/// Chaîne seule ou tableau de chaînes (ex. `cosignataires.acteurRef`).
+#[derive(Debug, Clone, Default)]
+pub struct StrList(pub Vec<String>);
+
+impl<'de> Deserialize<'de> for StrList {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct StrListVisitor;
+
+        impl<'de> Visitor<'de> for StrListVisitor {
+            type Value = StrList;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("une chaîne ou un tableau de chaînes")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<StrList, E> {
+                Ok(StrList(vec![v.to_string()]))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<StrList, A::Error> {
+                let mut out = Vec::new();
+                while let Some(item) = seq.next_element::<Scalar>()? {
+                    if let Scalar::Str(s) = item {
+                        out.push(s);
+                    }
+                }
+                Ok(StrList(out))
+            }
+
+            ignore_other_values!(map);
+        }
+
+        d.deserialize_any(StrListVisitor)
+    }
+}
+
+// ─── Lecture en flux d'un document ─────────────────────────────────────────
+
+/// Lit un document AN `{ "<plural>": { "<singular>": … } }` et/ou `{ "<singular>": … }`
+/// (élément seul ou tableau) et passe chaque élément objet à `sink` au fil de la lecture.
+pub fn stream_elements<T, R, F>(reader: R, plural: &str, singular: &str, mut sink: F) -> serde_json::Result<()>
+where
+    T: for<'de> Deserialize<'de> + Default,
+    R: Read,
+    F: FnMut(T),
+{
+    let mut de = serde_json::Deserializer::from_reader(reader);
+    Document { plural, singular, sink: &mut sink, marker: PhantomData }.deserialize(&mut de)?;
+    de.end()
+}
+
+struct Document<'a, T, F> {
+    plural: &'a str,
+    singular: &'a str,
+    sink: &'a mut F,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T, F> DeserializeSeed<'de> for Document<'_, T, F>
+where
+    T: for<'x> Deserialize<'x> + Default,
+    F: FnMut(T),
+{
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, d: D) -> Result<(), D::Error> {
+        d.deserialize_any(self)
+    }
+}
+
+impl<'de, T, F> Visitor<'de> for Document<'_, T, F>
+where
+    T: for<'x> Deserialize<'x> + Default,
+    F: FnMut(T),
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("un document AN")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            if key == self.plural {
+                // Conteneur : seule la clé `<singular>` est lue
+                map.next_value_seed(Document {
+                    plural: "",
+                    singular: self.singular,
+                    sink: &mut *self.sink,
+                    marker: PhantomData,
+                })?;
+            } else if key == self.singular {
+                map.next_value_seed(Elements { sink: &mut *self.sink, marker: PhantomData })?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(())
+    }
+
+    ignore_other_values!(str, seq);
+}
+
+struct Elements<'a, T, F> {
+    sink: &'a mut F,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T, F> DeserializeSeed<'de> for Elements<'_, T, F>
+where
+    T: for<'x> Deserialize<'x> + Default,
+    F: FnMut(T),
+{
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, d: D) -> Result<(), D::Error> {
+        d.deserialize_any(self)
+    }
+}
+
+impl<'de, T, F> Visitor<'de> for Elements<'_, T, F>
+where
+    T: for<'x> Deserialize<'x> + Default,
+    F: FnMut(T),
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("un objet ou un tableau d'objets")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while let Some(node) = seq.next_element::<Node<T>>()? {
+            if node.is_object {
+                (self.sink)(node.inner);
+            }
+        }
+        Ok(())
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<(), A::Error> {
+        (self.sink)(T::deserialize(MapAccessDeserializer::new(map))?);
+        Ok(())
+    }
+
+    ignore_other_values!(str);
+}
+
+// ─── Scrutins ──────────────────────────────────────────────────────────────
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ScrutinAn {
+    pub uid: Scalar,
+    pub numero: Scalar,
+    pub titre: Scalar,
+    pub objet: Node<ObjetScrutin>,
+    pub date_scrutin: Scalar,
+    pub sort: Node<Libelles>,
+    pub dossier_ref: Scalar,
+    pub type_vote: Node<TypeVote>,
+    pub ventilation_votes: Node<VentilationVotes>,
+    pub mise_au_point: Node<MiseAuPoint>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ObjetScrutin {
+    pub libelle: Scalar,
+    pub dossier_legislatif: Scalar,
+}
+
+/// `{ value, libelle, code }` (sort d'un scrutin, état d'un amendement).
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Libelles {
+    pub value: Scalar,
+    pub libelle: Scalar,
+    pub code: Scalar,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TypeVote {
+    pub code_type_vote: Scalar,
+    pub libelle_type_vote: Scalar,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct VentilationVotes {
+    pub organe: Many<Node<OrganeVentilation>>,
+}
+
+/// Les groupes apparaissent sous `groupes.groupe`, `groupes.organe`, `groupe` ou `organe`
+/// selon les variantes historiques du dataset.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct OrganeVentilation {
+    pub groupes: Node<GroupesVentilation>,
+    pub groupe: Many<Node<GroupeVote>>,
+    pub organe: Many<Node<GroupeVote>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct GroupesVentilation {
+    pub groupe: Many<Node<GroupeVote>>,
+    pub organe: Many<Node<GroupeVote>>,
+}
+
+impl OrganeVentilation {
+    pub fn all_groupes(&self) -> impl Iterator<Item = &Node<GroupeVote>> {
+        self.groupes
+            .groupe
+            .iter()
+            .chain(self.groupes.organe.iter())
+            .chain(self.groupe.iter())
+            .chain(self.organe.iter())
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GroupeVote {
+    pub organe_ref: Scalar,
+    pub nombre_membres_groupe: Scalar,
+    pub vote: Node<VoteGroupe>,
+    pub votes: Node<Decompte>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct VoteGroupe {
+    pub position_majoritaire: Scalar,
+    pub decompte_voix: Node<DecompteVoix>,
+    pub decompte_nominatif: Node<Decompte>,
+    /// Variante où les catégories figurent directement sous `vote`.
+    #[serde(flatten)]
+    pub direct: Decompte,
+}
+
+/// Listes nominatives par position (noms au pluriel et au singulier selon les fichiers).
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Decompte {
+    pub pours: Node<Votants>,
+    pub pour: Node<Votants>,
+    pub contres: Node<Votants>,
+    pub contre: Node<Votants>,
+    pub abstentions: Node<Votants>,
+    pub abstention: Node<Votants>,
+    pub non_votants: Node<Votants>,
+    pub non_votant: Node<Votants>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Votants {
+    pub votant: Many<Node<Votant>>,
+    pub votants: Node<VotantsListe>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct VotantsListe {
+    pub votant: Many<Node<Votant>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Votant {
+    pub acteur_ref: Scalar,
+    pub acteur: Node<ActeurRef>,
+    pub uid: Scalar,
+    pub par_delegation: Scalar,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ActeurRef {
+    pub acteur_ref: Scalar,
+    pub uid: Scalar,
+}
+
+impl Votant {
+    pub fn acteur_ref(&self) -> Option<&str> {
+        self.acteur_ref
+            .as_str()
+            .or_else(|| self.acteur.acteur_ref.as_str())
+            .or_else(|| self.acteur.uid.as_str())
+            .or_else(|| self.uid.as_str())
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct DecompteVoix {
+    pub pour: Scalar,
+    pub pours: Scalar,
+    pub contre: Scalar,
+    pub contres: Scalar,
+    pub abstentions: Scalar,
+    pub abstention: Scalar,
+    #[serde(rename = "nonVotants")]
+    pub non_votants: Scalar,
+    #[serde(rename = "nonVotant")]
+    pub non_votant: Scalar,
+}
+
+/// `miseAuPoint` ; les corrections pour dysfonctionnement ont la même structure.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MiseAuPoint {
+    pub pours: Node<Votants>,
+    pub contres: Node<Votants>,
+    pub abstentions: Node<Votants>,
+    pub non_votants: Node<Votants>,
+    pub non_votants_volontaires: Node<Votants>,
+    /// Optionnel (et non `Node`) : un `Default` récursif ne terminerait pas.
+    pub dysfonctionnement: Option<Box<Node<MiseAuPoint>>>,
+}
+
+// ─── Amendements ───────────────────────────────────────────────────────────
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AmendementAn {
+    pub uid: Scalar,
+    pub identificatif: Node<Identificatif>,
+    pub cycle_de_vie: Node<CycleDeVie>,
+    pub signataires: Node<Signataires>,
+    pub dossier_ref: Scalar,
+    pub amendement: Node<AvecPointeur>,
+    pub corps: Node<Corps>,
+    pub pointeur_fragment_texte: Node<PointeurFragmentTexte>,
+    pub expose_sommaire: Scalar,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Identificatif {
+    pub numero: Scalar,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CycleDeVie {
+    /// Chaîne directe ("Adopté") dans les exports multi-fichiers, `{ value }` ailleurs.
+    pub sort: Node<Libelles>,
+    pub etat_des_traitements: Node<EtatDesTraitements>,
+    pub date_depot: Scalar,
+    pub date_circulation: Scalar,
+    pub date_sort: Scalar,
+    pub date_examen: Scalar,
+    /// Autres champs, par ordre de clé (repli pour la date).
+    #[serde(flatten)]
+    pub autres: BTreeMap<String, Scalar>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct EtatDesTraitements {
+    pub etat: Node<Libelles>,
+    pub sous_etat: Node<Libelles>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Signataires {
+    pub auteur: Node<Auteur>,
+    pub signataire: Node<Auteur>,
+    pub cosignataires: Node<Cosignataires>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Auteur {
+    pub acteur_ref: Scalar,
+    pub type_auteur: Scalar,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Cosignataires {
+    pub acteur_ref: StrList,
+    pub cosignataire: Many<Node<Auteur>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AvecPointeur {
+    pub pointeur_fragment_texte: Node<PointeurFragmentTexte>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Corps {
+    pub pointeur_fragment_texte: Node<PointeurFragmentTexte>,
+    pub contenu_auteur: Node<ContenuAuteur>,
+    pub cartouche_informatif: Scalar,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ContenuAuteur {
+    pub expose_sommaire: Scalar,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PointeurFragmentTexte {
+    pub division: Node<Division>,
+    pub texte_legislatif_ref: Scalar,
+    pub mission_visee: Node<MissionVisee>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Division {
+    pub titre: Scalar,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MissionVisee {
+    pub libelle_mission: Scalar,
+    pub mission_ref: Scalar,
+}
+
+// ─── Questions ─────────────────────────────────────────────────────────────
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct QuestionAn {
+    pub uid: Scalar,
+    pub identifiant: Node<Identifiant>,
+    #[serde(rename = "type")]
+    pub type_question: Scalar,
+    #[serde(rename = "indexationAN")]
+    pub indexation_an: Node<IndexationAn>,
+    pub auteur: Node<AuteurQuestion>,
+    pub min_int: Node<Denomination>,
+    pub textes_question: Node<TextesQuestion>,
+    pub textes_reponse: Node<TextesReponse>,
+    pub cloture: Node<Cloture>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Identifiant {
+    pub numero: Scalar,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct IndexationAn {
+    pub rubrique: Scalar,
+    pub tete_analyse: Scalar,
+    pub analyses: Node<Analyses>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Analyses {
+    pub analyse: StrList,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct AuteurQuestion {
+    pub identite: Node<ActeurRef>,
+}
+
+/// `{ abrege, developpe }` (ministère interrogé ou attributaire).
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Denomination {
+    pub abrege: Scalar,
+    pub developpe: Scalar,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TextesQuestion {
+    pub texte_question: Many<Node<TexteJo>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TextesReponse {
+    pub texte_reponse: Many<Node<TexteJo>>,
+}
+
+/// Texte publié au Journal officiel (question, réponse ou erratum).
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct TexteJo {
+    #[serde(rename = "infoJO")]
+    pub info_jo: Node<InfoJo>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct InfoJo {
+    #[serde(rename = "typeJO")]
+    pub type_jo: Scalar,
+    #[serde(rename = "dateJO")]
+    pub date_jo: Scalar,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Cloture {
+    pub code_cloture: Scalar,
+    pub libelle_cloture: Scalar,
+    pub date_cloture: Scalar,
+}
+
+// ─── Réunions (agenda) ─────────────────────────────────────────────────────
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ReunionAn {
+    pub uid: Scalar,
+    pub time_stamp_debut: Scalar,
+    pub organe_reuni_ref: Scalar,
+    pub cycle_de_vie: Node<CycleDeVieReunion>,
+    pub participants: Node<Participants>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct CycleDeVieReunion {
+    /// « Confirmé », « Annulé », « Supprimé »…
+    pub etat: Scalar,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Participants {
+    pub participants_internes: Node<ParticipantsInternes>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ParticipantsInternes {
+    pub participant_interne: Many<Node<ParticipantInterne>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ParticipantInterne {
+    pub acteur_ref: Scalar,
+    /// « présent », « absent » ou « excusé ».
+    pub presence: Scalar,
+}
@@ -0,0 +1,106 @@
+use anyhow::{bail, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use clap::{Args, Parser, Subcommand};
+use std::path::{Path, PathBuf};
+
+use crate::aggregator;
+
+/// ETL activite-deputes : téléchargement, parsing, agrégation et export des données AN.
+///
+/// Sans sous-commande, le pipeline complet est exécuté (équivalent à `all`).
+#[derive(Debug, Parser)]
+#[command(name = "pipeline", version)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    #[command(flatten)]
+    pub opts: GlobalOpts,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Subcommand)]
+pub enum Command {
+    /// Télécharge les datasets AN (ETags) et les décompresse dans le répertoire de travail
+    Download,
+    /// Parse les datasets décompressés et sauvegarde le jeu normalisé
+    Parse,
+    /// Calcule les agrégats P30/P180/LEG à partir du jeu normalisé
+    Aggregate,
+    /// Écrit les JSON/CSV à partir des agrégats puis les publie dans les répertoires de sortie
+    Export,
+    /// Enchaîne download → parse → aggregate → export
+    All,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct GlobalOpts {
+    /// Répertoire de travail (ZIP, extractions, cache ETag, états intermédiaires)
+    #[arg(long, global = true, default_value = "pipeline/.work")]
+    pub work_dir: PathBuf,
+
+    /// Répertoire temporaire d'écriture avant le swap vers les sorties
+    #[arg(long, global = true, default_value = "pipeline/.temp_out")]
+    pub temp_dir: PathBuf,
+
+    /// Répertoire de sortie des JSON publiés
+    #[arg(long, global = true, default_value = "docs/data")]
+    pub data_dir: PathBuf,
+
+    /// Répertoire de sortie des CSV publiés
+    #[arg(long, global = true, default_value = "docs/exports")]
+    pub exports_dir: PathBuf,
+
+    /// Numéro de législature (URLs des datasets, fenêtre LEG, libellés du site)
+    #[arg(long, global = true, default_value_t = 17)]
+    pub legislature: u32,
+
+    /// Début de la fenêtre LEG (AAAA-MM-JJ), obligatoire pour une législature non référencée
+    #[arg(long, global = true, value_name = "AAAA-MM-JJ")]
+    pub leg_start: Option<NaiveDate>,
+
+    /// Aucun appel HTTP : réutilise les datasets de --from-dir (ou de --work-dir)
+    #[arg(long, global = true)]
+    pub offline: bool,
+
+    /// Répertoire local de ZIP AN ou de datasets déjà extraits (implique --offline)
+    #[arg(long, global = true, value_name = "DIR")]
+    pub from_dir: Option<PathBuf>,
+
+    /// Ignore le cache de parsing (<work-dir>/cache/) et reparse toutes les sources
+    #[arg(long, global = true)]
+    pub no_parse_cache: bool,
+
+    /// Date de référence des fenêtres P30/P180/LEG (AAAA-MM-JJ, défaut : aujourd'hui)
+    #[arg(long, global = true, value_name = "AAAA-MM-JJ")]
+    pub date: Option<NaiveDate>,
+}
+
+impl GlobalOpts {
+    pub fn is_offline(&self) -> bool {
+        self.offline || self.from_dir.is_some()
+    }
+
+    /// Répertoire source en mode hors ligne.
+    pub fn offline_dir(&self) -> &Path {
+        self.from_dir.as_deref().unwrap_or(&self.work_dir)
+    }
+
+    /// Début de la législature : `--leg-start` si fourni, sinon la date connue du pipeline.
+    pub fn legislature_start(&self) -> Result<NaiveDate> {
+        match self.leg_start.or_else(|| aggregator::legislature_start(self.legislature)) {
+            Some(d) => Ok(d),
+            None => bail!(
+                "Début de la {}e législature inconnu : le préciser avec --leg-start AAAA-MM-JJ",
+                self.legislature
+            ),
+        }
+    }
+
+    /// Instant de référence du run : minuit UTC de `--date` si fourni, sinon maintenant.
+    pub fn reference_now(&self) -> DateTime<Utc> {
+        match self.date {
+            Some(d) => d.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc(),
+            None => Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::aggregator::DailyActivity;
+use shared::DailyActivityFile;
+
+/// Écrit `daily/deputes.json` : compteurs journaliers de tous les députés sur la fenêtre LEG.
+pub fn write_daily_json(daily: &DailyActivity, data_root: &Path, generated_at_iso: &str) -> Result<()> {
+    let out_dir = data_root.join("daily");
+    std::fs::create_dir_all(&out_dir)?;
+
+    let file = DailyActivityFile {
+        schema_version: 2,
+        generated_at: generated_at_iso.to_string(),
+        day_start: daily.day_start,
+        days: daily.days,
+        scrutins: daily.scrutins.clone(),
+        scrutins_solennels: daily.scrutins_solennels.clone(),
+        scrutins_ordinaires: daily.scrutins_ordinaires.clone(),
+        deputes: daily
+            .deputes
+            .iter()
+            .map(|(id, dep)| (id.clone(), dep.clone()))
+            .collect(),
+    };
+    let bytes = serde_json::to_vec(&file)?;
+    std::fs::write(out_dir.join("deputes.json"), &bytes)?;
+    eprintln!(
+        "[exporter] daily/deputes.json : {} député(s), {} jour(s), {:.1} MB",
+        file.deputes.len(),
+        file.days,
+        bytes.len() as f64 / (1024.0 * 1024.0)
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use shared::{DayCounts, DeputeDaily};
+
+    use super::*;
+
+    fn d(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn sample() -> DailyActivityFile {
+        let jours = vec![
+            DayCounts { day: 0, votes_exprimes: 2, pour: 2, votes_solennels_exprimes: 1, votes_ordinaires_exprimes: 1, loyalty_votes: 2, dissent_count: 1, ..Default::default() },
+            DayCounts { day: 3, votes_exprimes: 1, contre: 1, non_votant: 1, amd_authored: 2, amd_adopted: 1, ..Default::default() },
+            DayCounts { day: 5, interventions: 3, questions_ecrites: 1, ..Default::default() },
+            DayCounts { day: 8, amd_cosigned: 4, questions_gouvernement: 1, questions_orales: 1, questions_repondues: 1, commission_reunions: 2, commission_presences: 1, ..Default::default() },
+        ];
+        let dep = DeputeDaily { mandats: vec![(d("2024-07-07"), d("2024-07-10")), (d("2024-07-14"), d("2024-07-16"))], jours };
+        DailyActivityFile {
+            schema_version: 2,
+            generated_at: String::new(),
+            day_start: d("2024-07-07"),
+            days: 10,
+            scrutins: vec![3, 0, 0, 2, 0, 5, 0, 1, 4, 0],
+            scrutins_solennels: vec![1, 0, 0, 0, 0, 0, 0, 0, 2, 0],
+            scrutins_ordinaires: vec![2, 0, 0, 2, 0, 5, 0, 1, 2, 0],
+            deputes: [("PA1".to_string(), dep)].into_iter().collect(),
+        }
+    }
+
+    #[test]
+    fn range_totals_follow_mandate_windows() {
+        let file = sample();
+        let all = file.range_totals("PA1", d("2024-01-01"), d("2025-01-01"));
+        // Jour 5 (12/07) hors mandat : ses 5 scrutins ne sont pas éligibles
+        assert_eq!(all.scrutins_eligibles, 3 + 2 + 1 + 4);
+        assert_eq!(all.votes_exprimes, 3);
+        assert_eq!(all.absent(), 10 - 3 - 1);
+        assert_eq!(all.amd_adoption_rate(), Some(0.5));
+        assert_eq!(all.amd_cosigned, 4);
+        assert_eq!(all.effective, Some((d("2024-07-07"), d("2024-07-16"))));
+        assert_eq!((all.scrutins_solennels, all.scrutins_ordinaires), (3, 7));
+        assert_eq!(all.loyalty_rate(), Some(0.5));
+        // Jour 5 hors mandat ; la QAG n'entre pas dans le taux de réponse
+        assert_eq!((all.interventions, all.questions_ecrites), (0, 0));
+        assert_eq!(all.questions_answer_rate(), Some(1.0));
+        assert_eq!(all.commission_presence_rate(), Some(0.5));
+
+        let window = file.range_totals("PA1", d("2024-07-09"), d("2024-07-13"));
+        assert_eq!(window.scrutins_eligibles, 2);
+        assert_eq!(window.contre, 1);
+        assert_eq!(window.amd_cosigned, 0);
+        assert_eq!(window.effective, Some((d("2024-07-09"), d("2024-07-10"))));
+
+        let hors_mandat = file.range_totals("PA1", d("2024-07-11"), d("2024-07-13"));
+        assert_eq!(hors_mandat.effective, None);
+        assert_eq!(hors_mandat.participation_rate(), 0.0);
+        assert_eq!(file.range_totals("PA2", d("2024-07-07"), d("2024-07-16")).scrutins_eligibles, 0);
+    }
+
+    #[test]
+    fn day_counts_serialize_named_non_zero_fields() {
+        let c = DayCounts { day: 12, votes_exprimes: 3, pour: 2, abst: 1, amd_cosigned: 7, ..Default::default() };
+        let json = serde_json::to_string(&c).unwrap();
+        assert_eq!(json, r#"{"day":12,"votes_exprimes":3,"pour":2,"abst":1,"amd_cosigned":7}"#);
+        assert_eq!(serde_json::from_str::<DayCounts>(&json).unwrap(), c);
+        assert_eq!(serde_json::from_str::<DayCounts>(r#"{"day":4}"#).unwrap(), DayCounts { day: 4, ..Default::default() });
+    }
+}
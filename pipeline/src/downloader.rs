@@ -1,50 +1,227 @@
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::Path;
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::Semaphore;
 use tracing::{info, warn};
 
 pub struct Source {
     pub key: &'static str,
-    pub url: &'static str,
+    pub url: String,
     pub filename: &'static str,
+    /// Source complémentaire : absente ou en échec, le pipeline continue sans elle.
+    pub optional: bool,
 }
 
 pub struct Sources {
     pub list: Vec<Source>,
 }
 
-impl Default for Sources {
-    fn default() -> Self {
+const AN_REPOSITORY: &str = "http://data.assemblee-nationale.fr/static/openData/repository";
+
+impl Sources {
+    /// Datasets open data AN d'une législature donnée (`/repository/<n>/...`).
+    pub fn for_legislature(legislature: u32) -> Self {
+        let source = |key, path: &str, filename| Source {
+            key,
+            url: format!("{AN_REPOSITORY}/{legislature}/{path}"),
+            filename,
+            optional: false,
+        };
+        let optional = |key, path: &str, filename| Source { optional: true, ..source(key, path, filename) };
         Self {
             list: vec![
-                Source {
-                    key: "deputes",
-                    url: "http://data.assemblee-nationale.fr/static/openData/repository/17/amo/deputes_actifs_mandats_actifs_organes/AMO10_deputes_actifs_mandats_actifs_organes.json.zip",
-                    filename: "deputes.zip",
-                },
-                Source {
-                    key: "scrutins",
-                    url: "http://data.assemblee-nationale.fr/static/openData/repository/17/loi/scrutins/Scrutins.json.zip",
-                    filename: "scrutins.zip",
-                },
-                Source {
-                    key: "amendements",
-                    url: "http://data.assemblee-nationale.fr/static/openData/repository/17/loi/amendements_div_legis/Amendements.json.zip",
-                    filename: "amendements.zip",
-                },
-                Source {
-                    key: "dossiers",
-                    url: "http://data.assemblee-nationale.fr/static/openData/repository/17/loi/dossiers_legislatifs/Dossiers_Legislatifs.json.zip",
-                    filename: "dossiers.zip",
-                },
+                source(
+                    "deputes",
+                    "amo/deputes_actifs_mandats_actifs_organes/AMO10_deputes_actifs_mandats_actifs_organes.json.zip",
+                    "deputes.zip",
+                ),
+                source("scrutins", "loi/scrutins/Scrutins.json.zip", "scrutins.zip"),
+                source(
+                    "amendements",
+                    "loi/amendements_div_legis/Amendements.json.zip",
+                    "amendements.zip",
+                ),
+                source(
+                    "dossiers",
+                    "loi/dossiers_legislatifs/Dossiers_Legislatifs.json.zip",
+                    "dossiers.zip",
+                ),
+                // Comptes rendus des séances publiques (XML Syceron) : interventions en séance
+                optional("debats", "vp/syceronbrut/syseron.xml.zip", "debats.zip"),
+                // Questions écrites, orales sans débat et au Gouvernement
+                optional(
+                    "questions_ecrites",
+                    "questions/questions_ecrites/Questions_ecrites.json.zip",
+                    "questions_ecrites.zip",
+                ),
+                optional(
+                    "questions_orales",
+                    "questions/questions_orales_sans_debat/Questions_orales_sans_debat.json.zip",
+                    "questions_orales.zip",
+                ),
+                optional(
+                    "questions_gouvernement",
+                    "questions/questions_gouvernement/Questions_gouvernement.json.zip",
+                    "questions_gouvernement.zip",
+                ),
+                // Agenda des réunions (commissions) : présences des députés convoqués
+                optional("reunions", "vp/reunions/Agenda.json.zip", "reunions.zip"),
             ],
         }
     }
 }
 
+/// Emplacement d'un dataset : l'archive ZIP, lue entrée par entrée sans extraction, ou à défaut
+/// un répertoire déjà extrait.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DatasetLocation {
+    Zip(PathBuf),
+    Dir(PathBuf),
+}
+
+impl DatasetLocation {
+    pub fn path(&self) -> &Path {
+        match self {
+            DatasetLocation::Zip(p) | DatasetLocation::Dir(p) => p,
+        }
+    }
+
+    pub fn exists(&self) -> bool {
+        self.path().exists()
+    }
+
+    /// Fichiers JSON du dataset, triés : chemins complets des entrées du ZIP (`json/VTANR…json`),
+    /// ou chemins relatifs au répertoire extrait, sous-répertoires compris (même forme).
+    pub fn json_entries(&self) -> Result<Vec<String>> {
+        self.entries_with_extension("json")
+    }
+
+    /// Fichiers XML du dataset (comptes rendus Syceron), mêmes règles que `json_entries`.
+    pub fn xml_entries(&self) -> Result<Vec<String>> {
+        self.entries_with_extension("xml")
+    }
+
+    fn entries_with_extension(&self, ext: &str) -> Result<Vec<String>> {
+        let mut names: Vec<String> = match self {
+            DatasetLocation::Zip(path) => open_archive(path)?
+                .file_names()
+                .filter(|name| has_extension(name, ext))
+                .map(String::from)
+                .collect(),
+            DatasetLocation::Dir(dir) => {
+                let mut files = Vec::new();
+                collect_relative_files(dir, dir, &mut files)?;
+                files.retain(|name| has_extension(name, ext));
+                files
+            }
+        };
+        names.sort();
+        Ok(names)
+    }
+
+    /// Passe à `f` le contenu de chaque entrée de `names`, dans l'ordre. Une entrée illisible
+    /// (archive corrompue, fichier disparu) interrompt la lecture ; les erreurs de `f` aussi.
+    pub fn read_entries<F>(&self, names: &[String], mut f: F) -> Result<()>
+    where
+        F: FnMut(&str, &mut dyn Read) -> Result<()>,
+    {
+        match self {
+            DatasetLocation::Zip(path) => {
+                let mut archive = open_archive(path)?;
+                for name in names {
+                    let entry = archive
+                        .by_name(name)
+                        .with_context(|| format!("Entrée {name} de {}", path.display()))?;
+                    f(name, &mut BufReader::new(entry))?;
+                }
+            }
+            DatasetLocation::Dir(dir) => {
+                for name in names {
+                    let path = dir.join(name);
+                    let file = std::fs::File::open(&path)
+                        .with_context(|| format!("Lecture {}", path.display()))?;
+                    f(name, &mut BufReader::new(file))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// `read_entries` sur tous les fichiers JSON du dataset.
+    pub fn read_json<F>(&self, f: F) -> Result<()>
+    where
+        F: FnMut(&str, &mut dyn Read) -> Result<()>,
+    {
+        let names = self.json_entries()?;
+        self.read_entries(&names, f)
+    }
+}
+
+impl std::fmt::Display for DatasetLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.path().display())
+    }
+}
+
+fn open_archive(path: &Path) -> Result<zip::ZipArchive<BufReader<std::fs::File>>> {
+    let file = std::fs::File::open(path).with_context(|| format!("Ouverture {}", path.display()))?;
+    zip::ZipArchive::new(BufReader::new(file)).with_context(|| format!("ZIP invalide {}", path.display()))
+}
+
+/// Fichiers de `dir` et de ses sous-répertoires, en chemins relatifs à `root` séparés par `/`
+/// (comme les entrées d'un ZIP).
+fn collect_relative_files(root: &Path, dir: &Path, out: &mut Vec<String>) -> Result<()> {
+    for entry in std::fs::read_dir(dir).with_context(|| format!("Lecture {}", dir.display()))? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_relative_files(root, &path, out)?;
+        } else if let Ok(rel) = path.strip_prefix(root) {
+            let parts: Vec<_> = rel.components().filter_map(|c| c.as_os_str().to_str()).collect();
+            out.push(parts.join("/"));
+        }
+    }
+    Ok(())
+}
+
+fn has_extension(name: &str, ext: &str) -> bool {
+    !name.ends_with('/')
+        && Path::new(name).extension().and_then(|e| e.to_str()) == Some(ext)
+}
+
+/// Emplacements des datasets par clé de source (`deputes`, `scrutins`…) : le ZIP téléchargé
+/// `<root>/<filename>` s'il existe, sinon le répertoire extrait `<root>/<key>/` (work dirs
+/// antérieurs). Surcharge possible source par source (mode hors ligne).
 #[derive(Debug, Clone)]
+pub struct DatasetLocations {
+    root: PathBuf,
+    archives: HashMap<String, &'static str>,
+    overrides: HashMap<String, DatasetLocation>,
+}
+
+impl DatasetLocations {
+    pub fn under(root: &Path, sources: &Sources) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            archives: sources.list.iter().map(|s| (s.key.to_string(), s.filename)).collect(),
+            overrides: HashMap::new(),
+        }
+    }
+
+    pub fn location(&self, key: &str) -> DatasetLocation {
+        if let Some(location) = self.overrides.get(key) {
+            return location.clone();
+        }
+        match self.archives.get(key).map(|name| self.root.join(name)) {
+            Some(zip) if zip.is_file() => DatasetLocation::Zip(zip),
+            _ => DatasetLocation::Dir(self.root.join(key)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EtagInfo {
     pub key: String,
     pub etag: Option<String>,
@@ -73,22 +250,24 @@ pub async fn download_all(sources: &Sources, work_dir: &Path) -> Result<Vec<Etag
         let sem = Arc::clone(&semaphore);
         let work_dir = Arc::clone(&work_dir);
         let key = source.key;
-        let url = source.url;
+        let url = source.url.clone();
         let filename = source.filename;
 
         let handle = tokio::spawn(async move {
             let _permit = sem.acquire().await.unwrap();
-            download_one(&client, &etag_cache, &work_dir, key, url, filename).await
+            download_one(&client, &etag_cache, &work_dir, key, &url, filename).await
         });
-        handles.push((source.key, handle));
+        handles.push((source.key, source.optional, handle));
     }
 
     let mut results = Vec::new();
     let mut errors = Vec::new();
 
-    for (key, handle) in handles {
+    for (key, optional, handle) in handles {
         match handle.await {
             Ok(Ok(info)) => results.push(info),
+            // Source complémentaire : on garde l'éventuel ZIP précédent et on continue
+            Ok(Err(e)) if optional => warn!("Téléchargement échoué pour {key} (optionnel, ignoré): {e}"),
             Ok(Err(e)) => {
                 warn!("Téléchargement échoué pour {key}: {e}");
                 errors.push(format!("{key}: {e}"));
@@ -110,12 +289,99 @@ pub async fn download_all(sources: &Sources, work_dir: &Path) -> Result<Vec<Etag
     Ok(results)
 }
 
+/// Mode hors ligne : aucun appel HTTP, les datasets sont pris dans `from_dir`.
+///
+/// Pour chaque source, on accepte (par ordre de priorité) :
+/// 1. un répertoire déjà extrait `<from_dir>/<key>/` contenant des JSON (XML pour les débats)
+///    — utilisé tel quel ;
+/// 2. un ZIP `<from_dir>/<filename>` ou portant le nom d'origine AN (ex: `Scrutins.json.zip`)
+///    — lu directement, sans extraction (from_dir peut donc être en lecture seule).
+///
+/// Une source optionnelle absente est signalée sans faire échouer la préparation. Aucun ETag
+/// n'est repris (un `etag_cache.json` voisin peut être périmé) : le cache de parsing se fonde
+/// sur la date et la taille des fichiers locaux.
+pub fn prepare_local(sources: &Sources, from_dir: &Path, work_dir: &Path) -> Result<(Vec<EtagInfo>, DatasetLocations)> {
+    if !from_dir.is_dir() {
+        anyhow::bail!("Répertoire hors ligne introuvable: {}", from_dir.display());
+    }
+
+    let mut locations = DatasetLocations::under(work_dir, sources);
+    let mut results = Vec::new();
+    let mut missing = Vec::new();
+
+    for source in &sources.list {
+        let key = source.key;
+        let local_dir = from_dir.join(key);
+
+        if dir_has_data(&local_dir) {
+            info!("{key}: dataset extrait trouvé dans {}", local_dir.display());
+            locations.overrides.insert(key.to_string(), DatasetLocation::Dir(local_dir));
+            let zip_path = from_dir.join(source.filename);
+            results.push(EtagInfo {
+                key: key.to_string(),
+                etag: None,
+                last_modified: None,
+                size_bytes: zip_path.metadata().map(|m| m.len()).unwrap_or(0),
+            });
+            continue;
+        }
+
+        let original_name = source.url.rsplit('/').next().unwrap_or(source.filename);
+        let zip_path = [source.filename, original_name]
+            .iter()
+            .map(|name| from_dir.join(name))
+            .find(|p| p.is_file());
+
+        let Some(zip_path) = zip_path else {
+            if source.optional {
+                warn!("{key}: dataset optionnel absent de {} — ignoré", from_dir.display());
+                continue;
+            }
+            missing.push(format!("{key} ({}/ ou {})", key, source.filename));
+            continue;
+        };
+
+        info!("{key}: lecture directe de {} (hors ligne)", zip_path.display());
+        let meta = zip_path.metadata().ok();
+        results.push(EtagInfo {
+            key: key.to_string(),
+            etag: None,
+            // Date du ZIP local (format de l'en-tête HTTP Last-Modified)
+            last_modified: meta
+                .as_ref()
+                .and_then(|m| m.modified().ok())
+                .map(|t| chrono::DateTime::<chrono::Utc>::from(t).format("%a, %d %b %Y %H:%M:%S GMT").to_string()),
+            size_bytes: meta.map(|m| m.len()).unwrap_or(0),
+        });
+        locations.overrides.insert(key.to_string(), DatasetLocation::Zip(zip_path));
+    }
+
+    if !missing.is_empty() {
+        anyhow::bail!(
+            "Datasets absents de {}: {}",
+            from_dir.display(),
+            missing.join(", ")
+        );
+    }
+
+    Ok((results, locations))
+}
+
+/// Répertoire extrait contenant au moins un fichier de données (JSON, ou XML pour les débats),
+/// éventuellement dans un sous-répertoire (`json/acteur/PA….json` après `unzip`).
+fn dir_has_data(dir: &Path) -> bool {
+    let mut files = Vec::new();
+    dir.is_dir()
+        && collect_relative_files(dir, dir, &mut files).is_ok()
+        && files.iter().any(|name| has_extension(name, "json") || has_extension(name, "xml"))
+}
+
 async fn download_one(
     client: &reqwest::Client,
     etag_cache: &HashMap<String, String>,
     work_dir: &Path,
     key: &'static str,
-    url: &'static str,
+    url: &str,
     filename: &'static str,
 ) -> Result<EtagInfo> {
     info!("Téléchargement: {key} depuis {url}");
@@ -141,8 +407,9 @@ async fn download_one(
         .and_then(|v| v.to_str().ok())
         .map(String::from);
 
-    // 304 Not Modified: rien à télécharger, mais on doit avoir le répertoire extrait
-    if status == reqwest::StatusCode::NOT_MODIFIED && extract_dir.exists() {
+    // 304 Not Modified: rien à télécharger, mais on doit avoir le ZIP (ou, pour un répertoire de
+    // travail antérieur, le répertoire extrait)
+    if status == reqwest::StatusCode::NOT_MODIFIED && (zip_path.is_file() || dir_has_data(&extract_dir)) {
         info!("{key}: inchangé (304), réutilisation du cache");
         return Ok(EtagInfo {
             key: key.to_string(),
@@ -165,19 +432,15 @@ async fn download_one(
     tokio::fs::write(&zip_path, &bytes).await
         .with_context(|| format!("Écriture {}", zip_path.display()))?;
 
-    info!("{key}: {size} octets téléchargés, décompression...");
-
-    // Extraire dans un thread bloquant (zip n'est pas async)
-    let zip_path_sync = zip_path.clone();
-    let extract_dir_sync = extract_dir.clone();
-    tokio::task::spawn_blocking(move || {
-        extract_zip(&zip_path_sync, &extract_dir_sync)
-    })
-    .await
-    .with_context(|| format!("Thread blocking {key}"))?
-    .with_context(|| format!("Décompression {key}"))?;
+    info!("{key}: {size} octets téléchargés");
 
-    info!("{key}: extraction OK");
+    // Le ZIP est lu directement par le parser : l'extraction d'une version précédente du
+    // pipeline ne sert plus et masquerait le dataset à jour si le ZIP venait à manquer
+    if extract_dir.exists() {
+        if let Err(e) = tokio::fs::remove_dir_all(&extract_dir).await {
+            warn!("{key}: suppression de l'ancienne extraction {} échouée: {e}", extract_dir.display());
+        }
+    }
 
     Ok(EtagInfo {
         key: key.to_string(),
@@ -187,33 +450,6 @@ async fn download_one(
     })
 }
 
-fn extract_zip(zip_path: &Path, dest: &Path) -> Result<()> {
-    std::fs::create_dir_all(dest)?;
-    let file = std::fs::File::open(zip_path)?;
-    let mut archive = zip::ZipArchive::new(file)?;
-
-    for i in 0..archive.len() {
-        let mut zf = archive.by_index(i)?;
-        let name = zf.name().to_string();
-
-        // Ignorer les répertoires et les chemins suspects (path traversal)
-        if name.ends_with('/') || name.contains("..") {
-            continue;
-        }
-
-        // Utiliser uniquement le nom de fichier, pas le chemin complet dans le ZIP
-        let file_name = Path::new(&name)
-            .file_name()
-            .unwrap_or(std::ffi::OsStr::new(&name));
-        let out_path = dest.join(file_name);
-
-        let mut out = std::fs::File::create(&out_path)
-            .with_context(|| format!("Création fichier {:?}", out_path))?;
-        std::io::copy(&mut zf, &mut out)?;
-    }
-    Ok(())
-}
-
 fn load_etag_cache(work_dir: &Path) -> HashMap<String, String> {
     let cache_path = work_dir.join("etag_cache.json");
     std::fs::read_to_string(cache_path)
@@ -230,3 +466,107 @@ fn save_etag_cache(work_dir: &Path, etags: &[EtagInfo]) {
         let _ = std::fs::write(work_dir.join("etag_cache.json"), json);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("downloader-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_zip(path: &Path, entries: &[(&str, &str)]) {
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+        for (name, content) in entries {
+            zip.start_file(*name, zip::write::FileOptions::default()).unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+    }
+
+    #[test]
+    fn zip_entries_keep_full_paths() {
+        let dir = temp_dir("entries");
+        let zip_path = dir.join("scrutins.zip");
+        // Même nom de fichier dans deux répertoires : l'ancienne extraction n'en gardait qu'un
+        write_zip(&zip_path, &[
+            ("json/b/VTANR5L17V1.json", "{\"b\":1}"),
+            ("json/a/VTANR5L17V1.json", "{\"a\":1}"),
+            ("json/", ""),
+            ("LISEZMOI.txt", "-"),
+        ]);
+
+        let location = DatasetLocation::Zip(zip_path);
+        let names = location.json_entries().unwrap();
+        assert_eq!(names, vec!["json/a/VTANR5L17V1.json", "json/b/VTANR5L17V1.json"]);
+
+        let mut contents = Vec::new();
+        location
+            .read_json(|name, reader| {
+                let mut data = String::new();
+                reader.read_to_string(&mut data)?;
+                contents.push(format!("{name}={data}"));
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(contents, vec!["json/a/VTANR5L17V1.json={\"a\":1}", "json/b/VTANR5L17V1.json={\"b\":1}"]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn zip_preferred_over_extracted_dir() {
+        let work = temp_dir("locations");
+        let sources = Sources::for_legislature(17);
+        std::fs::create_dir_all(work.join("scrutins")).unwrap();
+        std::fs::write(work.join("scrutins/Scrutins.json"), "{}").unwrap();
+
+        let locations = DatasetLocations::under(&work, &sources);
+        assert_eq!(locations.location("scrutins"), DatasetLocation::Dir(work.join("scrutins")));
+        assert_eq!(locations.location("scrutins").json_entries().unwrap(), vec!["Scrutins.json"]);
+
+        write_zip(&work.join("scrutins.zip"), &[("json/Scrutins.json", "{}")]);
+        assert_eq!(locations.location("scrutins"), DatasetLocation::Zip(work.join("scrutins.zip")));
+
+        std::fs::remove_dir_all(&work).unwrap();
+    }
+
+    #[test]
+    fn nested_extracted_dir_used_offline() {
+        let from = temp_dir("nested");
+        let work = temp_dir("nested-work");
+        // Dump AN décompressé avec `unzip` : fichiers sous json/acteur/ et json/organe/
+        for (path, content) in [
+            ("deputes/json/acteur/PA1.json", "{\"a\":1}"),
+            ("deputes/json/organe/PO1.json", "{\"o\":1}"),
+            ("deputes/LISEZMOI.txt", "-"),
+        ] {
+            std::fs::create_dir_all(from.join(path).parent().unwrap()).unwrap();
+            std::fs::write(from.join(path), content).unwrap();
+        }
+        let sources = Sources { list: Sources::for_legislature(17).list.into_iter().filter(|s| s.key == "deputes").collect() };
+
+        let (_, locations) = prepare_local(&sources, &from, &work).unwrap();
+        let location = locations.location("deputes");
+        assert_eq!(location, DatasetLocation::Dir(from.join("deputes")));
+        assert_eq!(location.json_entries().unwrap(), vec!["json/acteur/PA1.json", "json/organe/PO1.json"]);
+
+        let mut contents = Vec::new();
+        location
+            .read_json(|name, reader| {
+                let mut data = String::new();
+                reader.read_to_string(&mut data)?;
+                contents.push(format!("{name}={data}"));
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(contents, vec!["json/acteur/PA1.json={\"a\":1}", "json/organe/PO1.json={\"o\":1}"]);
+
+        std::fs::remove_dir_all(&from).unwrap();
+        std::fs::remove_dir_all(&work).unwrap();
+    }
+}
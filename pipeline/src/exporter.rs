@@ -7,74 +7,103 @@ use std::path::Path;
 use csv::Writer;
 
 use crate::aggregator::AllAggregates;
+use crate::daily_v1;
 use crate::downloader::EtagInfo;
 use crate::group_ppl_v1;
+use crate::groupes_v1;
+use crate::manifest_v1;
+use crate::quality_v1::{self, QualityInput, QualityReport};
+use crate::questions_v1;
+use crate::scrutins_v1;
+use crate::series_v1;
 use crate::models::DeputeStats;
+use shared::{
+    AmendementEvent, AmendementsIndex, AmendementsMonthFile, AmendementsMonthMeta, Counts, DeputeInfo, DossiersMin,
+    SessionsIndex, SourceInfo, Status,
+};
 
+/// `previous_quality` : rapport du run précédent, référence des évolutions du rapport qualité.
 pub fn write_json(
     agg: &AllAggregates,
     temp_dir: &Path,
     etags: &[EtagInfo],
+    previous_quality: Option<&QualityReport>,
     now: DateTime<Utc>,
 ) -> Result<()> {
     let data_dir = temp_dir.join("data");
     std::fs::create_dir_all(&data_dir)?;
 
     // status.json
-    let sources: Vec<serde_json::Value> = etags.iter().map(|e| json!({
-        "key": e.key,
-        "etag": e.etag,
-        "last_modified": e.last_modified,
-        "size_bytes": e.size_bytes,
-    })).collect();
-
-    let status = json!({
-        "last_update": now.to_rfc3339(),
-        "last_update_readable": now.format("%d/%m/%Y à %H:%M UTC").to_string(),
-        "legislature": 17,
-        "sources": sources,
-        "counts": {
-            "deputes": agg.deputes.len(),
-        }
-    });
-    write_json_file(&data_dir.join("status.json"), &status)?;
+    let sources: Vec<SourceInfo> = etags.iter().map(|e| SourceInfo {
+        key: e.key.clone(),
+        etag: e.etag.clone(),
+        last_modified: e.last_modified.clone(),
+        size_bytes: e.size_bytes,
+    }).collect();
+
+    let status = Status {
+        last_update: now.to_rfc3339(),
+        last_update_readable: now.format("%d/%m/%Y à %H:%M UTC").to_string(),
+        legislature: agg.legislature,
+        legislature_start: Some(agg.leg_start),
+        sources,
+        counts: Counts {
+            deputes: agg.deputes.len(),
+        },
+    };
+    write_json_file(&data_dir.join("status.json"), &json!(status))?;
 
     // deputes_P30.json
     write_json_file(&data_dir.join("deputes_P30.json"), &json!(agg.p30))?;
     write_json_file(&data_dir.join("deputes_P180.json"), &json!(agg.p180))?;
     write_json_file(&data_dir.join("deputes_LEG.json"), &json!(agg.leg))?;
 
+    // sessions/ — index des sessions parlementaires + un DeputeStats par session
+    let sessions_dir = data_dir.join("sessions");
+    std::fs::create_dir_all(&sessions_dir)?;
+    for s in &agg.sessions {
+        write_json_file(&data_dir.join(s.session.stats_file()), &json!(s.stats))?;
+    }
+    let sessions_index = SessionsIndex {
+        schema_version: 1,
+        generated_at: now.to_rfc3339(),
+        sessions: agg.sessions.iter().map(|s| s.session.clone()).collect(),
+    };
+    write_json_file(&sessions_dir.join("index.json"), &json!(sessions_index))?;
+
     // deputes_pN.json — info de base pour le listing, découpée en chunks de 200
-    let deputes_base: Vec<serde_json::Value> = agg.deputes.iter().map(|d| json!({
-        "id": d.id,
-        "nom": d.nom,
-        "prenom": d.prenom,
-        "date_naissance": d.date_naissance,
-        "sexe": d.sexe,
-        "pays_naissance": d.pays_naissance,
-        "profession": d.profession,
-        "groupe_abrev": d.groupe_abrev,
-        "groupe_nom": d.groupe_nom,
-        "parti_nom": d.parti_nom,
-        "dept_code": d.dept_code,
-        "dept_nom": d.dept_nom,
-        "circo": d.circo,
-        "mandat_debut": d.mandat_debut,
-        "mandat_fin": d.mandat_fin,
-        "mandat_debut_legislature": d.mandat_debut_legislature,
-        "mandat_assemblee_episodes": d.mandat_assemblee_episodes,
-        "mandat_assemblee_episode_count": d.mandat_assemblee_episodes.len(),
-        "mandat_assemblee_episode_labels": d.mandat_assemblee_episodes.iter().enumerate().map(|(i, e)| {
+    let deputes_base: Vec<DeputeInfo> = agg.deputes.iter().map(|d| DeputeInfo {
+        id: d.id.clone(),
+        nom: d.nom.clone(),
+        prenom: d.prenom.clone(),
+        date_naissance: d.date_naissance,
+        sexe: d.sexe.clone(),
+        pays_naissance: d.pays_naissance.clone(),
+        profession: d.profession.clone(),
+        groupe_abrev: d.groupe_abrev.clone(),
+        groupe_nom: d.groupe_nom.clone(),
+        parti_nom: d.parti_nom.clone(),
+        dept_code: d.dept_code.clone(),
+        dept_nom: d.dept_nom.clone(),
+        circo: d.circo.clone(),
+        mandat_debut: d.mandat_debut,
+        mandat_fin: d.mandat_fin,
+        mandat_debut_legislature: d.mandat_debut_legislature,
+        mandat_assemblee_episodes: d.mandat_assemblee_episodes.clone(),
+        mandat_assemblee_episode_count: d.mandat_assemblee_episodes.len(),
+        mandat_assemblee_episode_labels: d.mandat_assemblee_episodes.iter().enumerate().map(|(i, e)| {
             let fin = e.date_fin.map(|x| x.to_string()).unwrap_or_else(|| "en cours".to_string());
             format!("Mandat AN épisode {}: {} → {}", i + 1, e.date_debut, fin)
-        }).collect::<Vec<String>>(),
-        "email_assemblee": d.email_assemblee,
-        "site_web": d.site_web,
-        "sites_web": d.sites_web,
-        "sites_web_sources": d.sites_web_sources,
-        "telephones": d.telephones,
-        "uri_hatvp": d.uri_hatvp,
-    })).collect();
+        }).collect(),
+        email_assemblee: d.email_assemblee.clone(),
+        site_web: d.site_web.clone(),
+        sites_web: d.sites_web.clone(),
+        sites_web_sources: d.sites_web_sources.clone(),
+        telephones: d.telephones.clone(),
+        uri_hatvp: d.uri_hatvp.clone(),
+        commissions: d.commissions.clone(),
+        groupes: d.groupes.clone(),
+    }).collect();
     // Fichier unique pour compatibilité (stats_globales, anciens clients, etc.)
     write_json_file(&data_dir.join("deputes.json"), &json!(deputes_base))?;
 
@@ -88,7 +117,39 @@ pub fn write_json(
     eprintln!("[exporter] deputes.json + {} chunk(s) de {} (total: {} députés)", chunk_count, DEPUTES_CHUNK_SIZE, deputes_base.len());
 
     // positions-groupes / PPL (V1) — shards par groupe pour limiter la bande passante
-    group_ppl_v1::write_group_ppl_json(&agg.deputes, &agg.dossiers, &data_dir, &now.to_rfc3339())?;
+    let ppl_index = group_ppl_v1::write_group_ppl_json(&agg.deputes, &agg.dossiers, &data_dir, &now.to_rfc3339())?;
+
+    // groupes/ — une fiche par groupe parlementaire (membres, cohésion, PPL…)
+    groupes_v1::write_groupes_json(
+        &agg.deputes,
+        &agg.leg,
+        &agg.organes,
+        &agg.scrutins,
+        &agg.amendements,
+        &ppl_index,
+        (agg.leg_start, now.date_naive()),
+        &data_dir,
+        &now.to_rfc3339(),
+    )?;
+
+    // scrutins/ — index + un fichier détail par scrutin (listes nominatives)
+    scrutins_v1::write_scrutins_json(
+        &agg.scrutins,
+        &agg.deputes,
+        &agg.organes,
+        &agg.dossiers,
+        &data_dir,
+        &now.to_rfc3339(),
+    )?;
+
+    // series/ — séries hebdomadaires par député (+ groupes, aperçu pour l'accueil)
+    series_v1::write_series_json(&agg.weekly, &agg.deputes, &agg.organes, &data_dir, &now.to_rfc3339())?;
+
+    // questions/ — questions au Gouvernement, une liste par député
+    questions_v1::write_questions_json(&agg.questions, &agg.deputes, &data_dir, &now.to_rfc3339())?;
+
+    // daily/deputes.json — compteurs journaliers (fenêtres personnalisées côté frontend)
+    daily_v1::write_daily_json(&agg.daily, &data_dir, &now.to_rfc3339())?;
 
     // dossiers_min.json — mapping id -> titre (utilisé par la page Amendements)
     write_dossiers_min_json(&data_dir, &agg.dossiers)?;
@@ -96,73 +157,18 @@ pub fn write_json(
     // amendements/ — calendrier jour-par-jour (shards par mois)
     write_amendements_calendar_json(&data_dir, agg, &now.to_rfc3339())?;
 
-    Ok(())
-}
+    // quality_report.json (+ exports/quality_report.md) — contrôles qualité, évolution depuis le run précédent
+    let quality = quality_v1::build_quality_report(
+        &QualityInput::from_aggregates(agg),
+        previous_quality,
+        &now.to_rfc3339(),
+    );
+    quality_v1::write_quality_report(&quality, &data_dir, &temp_dir.join("exports"))?;
 
-#[derive(Debug, Clone, Serialize)]
-struct AmdEvent {
-    /// Type d'évènement: DEPOT | CIRCULATION | EXAMEN | SORT
-    t: &'static str,
-    /// ID amendement
-    id: String,
-    /// Numéro (si présent)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    n: Option<String>,
-    /// ID auteur (député)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    aid: Option<String>,
-    /// Type d'auteur (Député, Groupe, etc.)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    aty: Option<String>,
-    /// Cosignataires IDs
-    #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    cos: Vec<String>,
-    /// ID dossier
-    #[serde(skip_serializing_if = "Option::is_none")]
-    did: Option<String>,
-    /// Article (ex: "Art. 3")
-    #[serde(skip_serializing_if = "Option::is_none")]
-    art: Option<String>,
-    /// Libellé de sort (uniquement pour t=SORT)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    s: Option<String>,
-    /// true si adopté (uniquement utile pour t=SORT)
-    #[serde(default)]
-    ok: bool,
-    /// Mission visée
-    #[serde(skip_serializing_if = "Option::is_none")]
-    mis: Option<String>,
-    /// Mission ref
-    #[serde(skip_serializing_if = "Option::is_none")]
-    mref: Option<String>,
-    /// Exposé sommaire
-    #[serde(skip_serializing_if = "Option::is_none")]
-    exp: Option<String>,
-}
+    // manifest.json — en dernier : inventaire de tout data/ (version, taille, SHA-256)
+    manifest_v1::write_manifest_json(&data_dir, &now.to_rfc3339())?;
 
-#[derive(Debug, Clone, Serialize)]
-struct MonthMeta {
-    month: String,
-    days: usize,
-    events: usize,
-}
-
-#[derive(Debug, Clone, Serialize)]
-struct AmendementsIndex {
-    schema_version: u32,
-    generated_at: String,
-    months: Vec<MonthMeta>,
-    undated_count: usize,
-    undated_file: String,
-    notes: Vec<String>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-struct AmendementsMonthFile {
-    schema_version: u32,
-    month: String,
-    /// days[YYYY-MM-DD] = [events...]
-    days: BTreeMap<String, Vec<AmdEvent>>,
+    Ok(())
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -172,7 +178,7 @@ struct MonthStat {
 }
 
 fn write_dossiers_min_json(data_dir: &Path, dossiers: &HashMap<String, crate::models::Dossier>) -> Result<()> {
-    let mut out: BTreeMap<String, String> = BTreeMap::new();
+    let mut out = DossiersMin::new();
     for (id, d) in dossiers {
         out.insert(id.clone(), d.titre.clone());
     }
@@ -188,7 +194,7 @@ fn write_amendements_calendar_json(data_dir: &Path, agg: &AllAggregates, generat
     // 1) Construire les évènements datés (vector triable) + liste sans date
     //    Note: on n'utilise ici QUE les dates structurées (dateDepot/dateExamen/dateSort/dateCirculation).
     //    Si aucune de ces dates n'est présente, l'amendement est compté "sans date".
-    let mut dated: Vec<(NaiveDate, u8, AmdEvent)> = Vec::new();
+    let mut dated: Vec<(NaiveDate, u8, AmendementEvent)> = Vec::new();
     let mut undated: Vec<serde_json::Value> = Vec::new();
 
     for a in &agg.amendements {
@@ -197,8 +203,8 @@ fn write_amendements_calendar_json(data_dir: &Path, agg: &AllAggregates, generat
         // Dépôt
         if let Some(d) = a.date_depot {
             has_any = true;
-            dated.push((d, 0, AmdEvent {
-                t: "DEPOT",
+            dated.push((d, 0, AmendementEvent {
+                t: "DEPOT".to_string(),
                 id: a.id.clone(),
                 n: a.numero.clone(),
                 aid: a.auteur_id.clone(),
@@ -217,8 +223,8 @@ fn write_amendements_calendar_json(data_dir: &Path, agg: &AllAggregates, generat
         // Circulation
         if let Some(d) = a.date_circulation {
             has_any = true;
-            dated.push((d, 1, AmdEvent {
-                t: "CIRCULATION",
+            dated.push((d, 1, AmendementEvent {
+                t: "CIRCULATION".to_string(),
                 id: a.id.clone(),
                 n: a.numero.clone(),
                 aid: a.auteur_id.clone(),
@@ -237,8 +243,8 @@ fn write_amendements_calendar_json(data_dir: &Path, agg: &AllAggregates, generat
         // Examen
         if let Some(d) = a.date_examen {
             has_any = true;
-            dated.push((d, 2, AmdEvent {
-                t: "EXAMEN",
+            dated.push((d, 2, AmendementEvent {
+                t: "EXAMEN".to_string(),
                 id: a.id.clone(),
                 n: a.numero.clone(),
                 aid: a.auteur_id.clone(),
@@ -257,8 +263,8 @@ fn write_amendements_calendar_json(data_dir: &Path, agg: &AllAggregates, generat
         // Sort
         if let Some(d) = a.date_sort {
             has_any = true;
-            dated.push((d, 3, AmdEvent {
-                t: "SORT",
+            dated.push((d, 3, AmendementEvent {
+                t: "SORT".to_string(),
                 id: a.id.clone(),
                 n: a.numero.clone(),
                 aid: a.auteur_id.clone(),
@@ -303,9 +309,9 @@ fn write_amendements_calendar_json(data_dir: &Path, agg: &AllAggregates, generat
     let mut month_stats: BTreeMap<String, MonthStat> = BTreeMap::new();
 
     let mut current_month: Option<String> = None;
-    let mut days_map: BTreeMap<String, Vec<AmdEvent>> = BTreeMap::new();
+    let mut days_map: BTreeMap<String, Vec<AmendementEvent>> = BTreeMap::new();
 
-    let mut flush_month = |month: &str, days: &BTreeMap<String, Vec<AmdEvent>>| -> Result<()> {
+    let mut flush_month = |month: &str, days: &BTreeMap<String, Vec<AmendementEvent>>| -> Result<()> {
         let payload = AmendementsMonthFile {
             schema_version: 1,
             month: month.to_string(),
@@ -342,9 +348,9 @@ fn write_amendements_calendar_json(data_dir: &Path, agg: &AllAggregates, generat
     }
 
     // 4) Index global
-    let mut months: Vec<MonthMeta> = month_stats
+    let mut months: Vec<AmendementsMonthMeta> = month_stats
         .into_iter()
-        .map(|(month, stat)| MonthMeta {
+        .map(|(month, stat)| AmendementsMonthMeta {
             month,
             days: stat.days.len(),
             events: stat.events,
@@ -378,6 +384,9 @@ pub fn write_csv(agg: &AllAggregates, temp_dir: &Path) -> Result<()> {
     write_period_csv(&exports_dir.join("deputes_activity_P30.csv"), &agg.p30)?;
     write_period_csv(&exports_dir.join("deputes_activity_P180.csv"), &agg.p180)?;
     write_period_csv(&exports_dir.join("deputes_activity_LEG.csv"), &agg.leg)?;
+    for s in &agg.sessions {
+        write_period_csv(&exports_dir.join(format!("deputes_activity_{}.csv", s.session.id)), &s.stats)?;
+    }
 
     Ok(())
 }
@@ -396,10 +405,20 @@ fn write_period_csv(path: &Path, stats: &[DeputeStats]) -> Result<()> {
         "amd_authored", "amd_adopted", "amd_adoption_rate", "amd_cosigned",
         "interventions_count", "interventions_chars",
         "top_dossier_id", "top_dossier_titre", "top_dossier_score",
+        "loyalty_votes", "dissent_count", "loyalty_rate",
+        "scrutins_solennels", "votes_solennels_exprimes", "participation_rate_solennel",
+        "scrutins_ordinaires", "votes_ordinaires_exprimes", "participation_rate_ordinaire",
+        "mises_au_point", "votes_par_delegation",
+        "questions_ecrites", "questions_orales", "questions_gouvernement",
+        "questions_repondues", "questions_answer_rate",
+        "commission_permanente", "commission_qualite",
+        "commission_reunions", "commission_presences", "commission_excuses", "commission_presence_rate",
+        "rapporteur_fond", "rapporteur_avis", "rapporteur_special",
     ])?;
 
     for s in stats {
         let top = s.top_dossiers.first();
+        let commission = shared::commission_permanente(&s.commissions);
         wtr.write_record(&[
             &s.deputy_id,
             &s.nom,
@@ -428,6 +447,31 @@ fn write_period_csv(path: &Path, stats: &[DeputeStats]) -> Result<()> {
             top.map(|t| t.dossier_id.as_str()).unwrap_or(""),
             top.map(|t| t.titre.as_str()).unwrap_or(""),
             &top.map(|t| t.score.to_string()).unwrap_or_default(),
+            &s.loyalty_votes.to_string(),
+            &s.dissent_count.to_string(),
+            &s.loyalty_rate.map(|r| format!("{:.4}", r)).unwrap_or_default(),
+            &s.scrutins_solennels.to_string(),
+            &s.votes_solennels_exprimes.to_string(),
+            &s.participation_rate_solennel.map(|r| format!("{:.4}", r)).unwrap_or_default(),
+            &s.scrutins_ordinaires.to_string(),
+            &s.votes_ordinaires_exprimes.to_string(),
+            &s.participation_rate_ordinaire.map(|r| format!("{:.4}", r)).unwrap_or_default(),
+            &s.mises_au_point.to_string(),
+            &s.votes_par_delegation.to_string(),
+            &s.questions_ecrites.to_string(),
+            &s.questions_orales.to_string(),
+            &s.questions_gouvernement.to_string(),
+            &s.questions_repondues.to_string(),
+            &s.questions_answer_rate.map(|r| format!("{:.4}", r)).unwrap_or_default(),
+            commission.map(|c| c.libelle.as_str()).unwrap_or(""),
+            commission.map(|c| c.qualite.as_str()).unwrap_or(""),
+            &s.commission_reunions.to_string(),
+            &s.commission_presences.to_string(),
+            &s.commission_excuses.to_string(),
+            &s.commission_presence_rate.map(|r| format!("{:.4}", r)).unwrap_or_default(),
+            &s.rapporteur_fond.to_string(),
+            &s.rapporteur_avis.to_string(),
+            &s.rapporteur_special.to_string(),
         ])?;
     }
     wtr.flush()?;
@@ -1,74 +1,14 @@
 use anyhow::Result;
-use serde::{Deserialize, Serialize};
+use chrono::NaiveDate;
+use serde::Serialize;
 use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::path::Path;
 
-use crate::models::{Depute, Dossier};
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct GroupPplIndex {
-    pub version: u8,
-    pub generated_at: String,
-    pub total_groups: usize,
-    pub total_ppl_links: usize,
-    pub total_unique_ppl: usize,
-    pub groups: Vec<GroupPplGroupIndexEntry>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct GroupPplGroupIndexEntry {
-    pub group_id: String,
-    pub group_label: String,
-    pub ppl_count: usize,
-    pub authored_ppl_count: usize,
-    pub cosigned_only_ppl_count: usize,
-    pub file: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct GroupPplGroupShard {
-    pub version: u8,
-    pub generated_at: String,
-    pub group_id: String,
-    pub group_label: String,
-    pub total_entries: usize,
-    pub items: Vec<GroupPplItemSummary>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct GroupPplItemSummary {
-    pub ppl_id: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub number: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub legislature: Option<u8>,
-    pub title: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub deposit_date: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_url: Option<String>,
-    pub author_count: u16,
-    pub cosigner_count: u16,
-    pub total_signers_from_group: u16,
-    pub has_author: bool,
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub signer_names_preview: Vec<String>,
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub signers_preview: Vec<SignerPreviewEntry>,
-}
-
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SignerPreviewEntry {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub deputy_id: Option<String>,
-    pub deputy_name: String,
-}
+use crate::models::{Depute, Dossier, GroupeMembership};
+use shared::{
+    DeputyPplItemSummary, DeputyPplShard, GroupPplGroupIndexEntry, GroupPplGroupShard, GroupPplIndex,
+    GroupPplItemSummary, SignerPreviewEntry,
+};
 
 #[derive(Debug, Clone)]
 struct GroupPplItemBuilder {
@@ -221,40 +161,6 @@ impl GroupBuilder {
 }
 
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct DeputyPplShard {
-    pub version: u8,
-    pub generated_at: String,
-    pub deputy_id: String,
-    pub deputy_name: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub group_id: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub group_label: Option<String>,
-    pub total_entries: usize,
-    pub authored_count: usize,
-    pub cosigned_only_count: usize,
-    pub items: Vec<DeputyPplItemSummary>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct DeputyPplItemSummary {
-    pub ppl_id: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub number: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub legislature: Option<u8>,
-    pub title: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub deposit_date: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_url: Option<String>,
-    pub is_author: bool,
-    pub is_cosigner: bool,
-}
-
 #[derive(Debug, Clone)]
 struct DeputyPplItemBuilder {
     ppl_id: String,
@@ -377,6 +283,17 @@ struct DeputyLite {
     group_id: String,
     group_label: String,
     has_group: bool,
+    groupes: Vec<GroupeMembership>,
+}
+
+impl DeputyLite {
+    /// Groupe du signataire à la date de dépôt du texte ; à défaut, son groupe actuel.
+    fn group_at(&self, date: Option<NaiveDate>) -> (String, String) {
+        match date.and_then(|d| shared::groupe_at(&self.groupes, d)) {
+            Some(g) => (g.organe_id.clone(), g.abrev.clone().unwrap_or_else(|| g.libelle.clone())),
+            None => (self.group_id.clone(), self.group_label.clone()),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -468,7 +385,7 @@ pub fn write_group_ppl_json(
     dossiers: &HashMap<String, Dossier>,
     data_root: &Path,
     generated_at_iso: &str,
-) -> Result<()> {
+) -> Result<GroupPplIndex> {
     let out_dir = data_root.join("positions-groupes").join("ppl");
     let groups_dir = out_dir.join("groups");
     std::fs::create_dir_all(&groups_dir)?;
@@ -498,6 +415,7 @@ pub fn write_group_ppl_json(
             group_id,
             group_label,
             has_group,
+            groupes: d.groupes.clone(),
         };
 
         // On indexe en exact + normalisé pour éviter les ratés triviaux (casse/espaces).
@@ -569,7 +487,7 @@ pub fn write_group_ppl_json(
             let (group_id, group_label) = match dep {
                 Some(d) if d.has_group => {
                     signers_resolved_to_group += 1;
-                    (d.group_id.clone(), d.group_label.clone())
+                    d.group_at(dossier.date_depot)
                 }
                 Some(_) => {
                     signers_unresolved_deputy_without_group += 1;
@@ -652,7 +570,7 @@ pub fn write_group_ppl_json(
             let (group_id, group_label) = match dep {
                 Some(d) if d.has_group => {
                     signers_resolved_to_group += 1;
-                    (d.group_id.clone(), d.group_label.clone())
+                    d.group_at(dossier.date_depot)
                 }
                 Some(_) => {
                     signers_unresolved_deputy_without_group += 1;
@@ -802,7 +720,7 @@ pub fn write_group_ppl_json(
     let deputy_id_audit = build_deputy_id_audit_report(deputes, generated_at_iso);
     write_minified_json(&out_dir.join("deputes_id_audit.json"), &deputy_id_audit)?;
 
-    Ok(())
+    Ok(index)
 }
 
 fn build_deputy_id_audit_report(
@@ -940,7 +858,7 @@ fn saturating_u16(v: usize) -> u16 {
     }
 }
 
-fn safe_file_stem(s: &str) -> String {
+pub(crate) fn safe_file_stem(s: &str) -> String {
     let mut out = String::with_capacity(s.len());
     for ch in s.chars() {
         let c = ch.to_ascii_lowercase();
@@ -1014,6 +932,7 @@ mod tests {
             source_url: None,
             origin_chamber: Some("assemblee".into()),
             initiateur_organe_ref: None,
+            rapporteurs: vec![],
         };
         assert!(is_proposition_de_loi(&d));
     }
@@ -1030,6 +949,8 @@ mod tests {
                 groupe_id: Some("POGRP1".into()), groupe_abrev: Some("GRP1".into()), groupe_nom: Some("Groupe 1".into()),
                 parti_id: None, parti_nom: None, email_assemblee: None, site_web: None,
                 sites_web: vec![], sites_web_sources: vec![], telephones: vec![], uri_hatvp: None,
+                commissions: vec![],
+                groupes: vec![],
             },
             Depute {
                 id: "PA2".into(), nom: "Martin".into(), prenom: "Bob".into(),
@@ -1040,6 +961,8 @@ mod tests {
                 groupe_id: Some("POGRP1".into()), groupe_abrev: Some("GRP1".into()), groupe_nom: Some("Groupe 1".into()),
                 parti_id: None, parti_nom: None, email_assemblee: None, site_web: None,
                 sites_web: vec![], sites_web_sources: vec![], telephones: vec![], uri_hatvp: None,
+                commissions: vec![],
+                groupes: vec![],
             },
         ];
 
@@ -1057,6 +980,7 @@ mod tests {
             source_url: None,
             origin_chamber: Some("assemblee".into()),
             initiateur_organe_ref: None,
+            rapporteurs: vec![],
         });
 
         let tmp = std::env::temp_dir().join("group_ppl_v1_pipe_test");
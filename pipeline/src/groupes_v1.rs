@@ -0,0 +1,383 @@
+use anyhow::Result;
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use crate::group_ppl_v1::safe_file_stem;
+use crate::models::{Amendement, Depute, DeputeStats, Organe, Scrutin};
+use shared::{
+    GroupPplIndex, GroupeCohesion, GroupeDetail, GroupeIndexEntry, GroupeMembre, GroupeMouvement, GroupePpl,
+    GroupesIndex,
+};
+
+/// Changement de groupe d'un député entre deux scrutins consécutifs.
+struct Transition<'a> {
+    deputy_id: &'a str,
+    date: NaiveDate,
+    from: &'a str,
+    to: &'a str,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_groupes_json(
+    deputes: &[Depute],
+    leg: &[DeputeStats],
+    organes: &HashMap<String, Organe>,
+    scrutins: &[Scrutin],
+    amendements: &[Amendement],
+    ppl_index: &GroupPplIndex,
+    period: (NaiveDate, NaiveDate),
+    data_root: &Path,
+    generated_at_iso: &str,
+) -> Result<()> {
+    let out_dir = data_root.join("groupes");
+    std::fs::create_dir_all(&out_dir)?;
+
+    let (period_start, period_end) = period;
+    let stats_by_id: HashMap<&str, &DeputeStats> =
+        leg.iter().map(|s| (s.deputy_id.as_str(), s)).collect();
+    let names: HashMap<&str, String> = deputes
+        .iter()
+        .map(|d| (d.id.as_str(), format!("{} {}", d.prenom, d.nom).trim().to_string()))
+        .collect();
+
+    let mut membres_par_groupe: BTreeMap<&str, Vec<&Depute>> = BTreeMap::new();
+    for d in deputes {
+        if let Some(gid) = d.groupe_id.as_deref() {
+            membres_par_groupe.entry(gid).or_default().push(d);
+        }
+    }
+
+    let in_period: Vec<&Scrutin> = scrutins
+        .iter()
+        .filter(|s| s.date.is_some_and(|d| d >= period_start && d <= period_end))
+        .collect();
+    let transitions = group_transitions(scrutins);
+    let amd_par_groupe = amendements_par_groupe(deputes, amendements, period);
+
+    // Groupes retenus : organes GP ayant des membres ou apparaissant dans les scrutins de la période
+    let mut group_ids: Vec<&str> = membres_par_groupe.keys().copied().collect();
+    for s in &in_period {
+        for g in &s.groupes {
+            if organes.get(&g.organe_ref).is_some_and(|o| o.code_type == "GP") {
+                group_ids.push(g.organe_ref.as_str());
+            }
+        }
+    }
+    group_ids.sort_unstable();
+    group_ids.dedup();
+
+    let mut entries = Vec::with_capacity(group_ids.len());
+    for gid in group_ids {
+        let organe = organes.get(gid);
+        let mut membres: Vec<GroupeMembre> = membres_par_groupe
+            .get(gid)
+            .map(|v| v.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|d| {
+                let st = stats_by_id.get(d.id.as_str());
+                GroupeMembre {
+                    deputy_id: d.id.clone(),
+                    nom: d.nom.clone(),
+                    prenom: d.prenom.clone(),
+                    scrutins_eligibles: st.map_or(0, |s| s.scrutins_eligibles),
+                    participation_rate: st.map_or(0.0, |s| s.participation_rate),
+                    amd_authored: st.map_or(0, |s| s.amd_authored),
+                    amd_adopted: st.map_or(0, |s| s.amd_adopted),
+                    loyalty_rate: st.and_then(|s| s.loyalty_rate),
+                }
+            })
+            .collect();
+        membres.sort_by(|a, b| {
+            a.nom.to_lowercase()
+                .cmp(&b.nom.to_lowercase())
+                .then_with(|| a.prenom.to_lowercase().cmp(&b.prenom.to_lowercase()))
+                .then_with(|| a.deputy_id.cmp(&b.deputy_id))
+        });
+
+        let participation_median = median(
+            membres
+                .iter()
+                .filter(|m| m.scrutins_eligibles > 0)
+                .map(|m| m.participation_rate)
+                .collect(),
+        );
+        let (amd_authored, amd_adopted) = amd_par_groupe.get(gid).copied().unwrap_or_default();
+        let amd_adoption_rate = if amd_authored > 0 {
+            Some(amd_adopted as f64 / amd_authored as f64)
+        } else {
+            None
+        };
+
+        let mut mouvements: Vec<GroupeMouvement> = transitions
+            .iter()
+            .filter(|t| t.from == gid || t.to == gid)
+            .map(|t| {
+                let (sens, autre) = if t.to == gid { ("arrivee", t.from) } else { ("depart", t.to) };
+                GroupeMouvement {
+                    deputy_id: t.deputy_id.to_string(),
+                    nom: names.get(t.deputy_id).cloned(),
+                    date: t.date,
+                    sens: sens.to_string(),
+                    autre_groupe_ref: autre.to_string(),
+                    autre_groupe_abrev: organes.get(autre).and_then(|o| o.abrev.clone()),
+                }
+            })
+            .collect();
+        mouvements.sort_by(|a, b| b.date.cmp(&a.date).then(a.deputy_id.cmp(&b.deputy_id)));
+
+        let ppl = ppl_index.groups.iter().find(|g| g.group_id == gid).map(|g| GroupePpl {
+            ppl_count: g.ppl_count,
+            authored_ppl_count: g.authored_ppl_count,
+            cosigned_only_ppl_count: g.cosigned_only_ppl_count,
+            file: g.file.clone(),
+        });
+
+        let detail = GroupeDetail {
+            schema_version: 1,
+            generated_at: generated_at_iso.to_string(),
+            id: gid.to_string(),
+            abrev: organe.and_then(|o| o.abrev.clone()),
+            libelle: organe.map(|o| o.libelle.clone()).unwrap_or_else(|| gid.to_string()),
+            couleur: organe.and_then(|o| o.couleur.clone()),
+            period_start,
+            period_end,
+            membres,
+            mouvements,
+            participation_median,
+            amd_authored,
+            amd_adopted,
+            amd_adoption_rate,
+            cohesion: cohesion_for(gid, &in_period),
+            ppl,
+        };
+
+        let file = format!("{}.json", safe_file_stem(gid));
+        write_minified_json(&out_dir.join(&file), &detail)?;
+        entries.push(GroupeIndexEntry {
+            id: detail.id,
+            abrev: detail.abrev,
+            libelle: detail.libelle,
+            membres: detail.membres.len(),
+            file,
+        });
+    }
+
+    entries.sort_by(|a, b| b.membres.cmp(&a.membres).then_with(|| a.id.cmp(&b.id)));
+    let index = GroupesIndex {
+        schema_version: 1,
+        generated_at: generated_at_iso.to_string(),
+        total: entries.len(),
+        groupes: entries,
+    };
+    write_minified_json(&out_dir.join("index.json"), &index)?;
+    eprintln!("[exporter] groupes/index.json + {} fiche(s) groupe", index.total);
+
+    Ok(())
+}
+
+/// Amendements (déposés, adoptés) par groupe : chaque amendement compte pour le groupe de son
+/// auteur à la date de l'amendement ; sans date, pour son groupe actuel.
+fn amendements_par_groupe<'a>(
+    deputes: &'a [Depute],
+    amendements: &[Amendement],
+    (period_start, period_end): (NaiveDate, NaiveDate),
+) -> HashMap<&'a str, (u32, u32)> {
+    let by_id: HashMap<&str, &Depute> = deputes.iter().map(|d| (d.id.as_str(), d)).collect();
+    let mut out: HashMap<&str, (u32, u32)> = HashMap::new();
+    for a in amendements {
+        let Some(dep) = a.auteur_id.as_deref().and_then(|id| by_id.get(id).copied()) else { continue };
+        let groupe = match a.date {
+            Some(d) if d < period_start || d > period_end => continue,
+            Some(d) => dep.groupe_id_at(d),
+            None => dep.groupe_id.as_deref(),
+        };
+        if let Some(g) = groupe {
+            let c = out.entry(g).or_default();
+            c.0 += 1;
+            if a.adopte {
+                c.1 += 1;
+            }
+        }
+    }
+    out
+}
+
+/// Changements de groupe déduits du groupe sous lequel chaque député est décompté, scrutin après scrutin.
+fn group_transitions(scrutins: &[Scrutin]) -> Vec<Transition<'_>> {
+    let mut ordered: Vec<(&Scrutin, NaiveDate)> = scrutins
+        .iter()
+        .filter_map(|s| s.date.map(|d| (s, d)))
+        .collect();
+    ordered.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.numero.cmp(&b.0.numero)));
+
+    let mut current: HashMap<&str, &str> = HashMap::new();
+    let mut out = Vec::new();
+    for (s, date) in ordered {
+        for (dep_id, groupe_ref) in &s.groupe_votants {
+            if let Some(prev) = current.insert(dep_id.as_str(), groupe_ref.as_str()) {
+                if prev != groupe_ref {
+                    out.push(Transition { deputy_id: dep_id.as_str(), date, from: prev, to: groupe_ref.as_str() });
+                }
+            }
+        }
+    }
+    out
+}
+
+fn cohesion_for(groupe_id: &str, scrutins: &[&Scrutin]) -> GroupeCohesion {
+    let mut c = GroupeCohesion::default();
+    let mut total = 0.0;
+    for s in scrutins {
+        let Some(g) = s.groupes.iter().find(|g| g.organe_ref == groupe_id) else {
+            continue;
+        };
+        let exprimes = g.pour + g.contre + g.abstentions;
+        if exprimes == 0 {
+            continue;
+        }
+        let max = g.pour.max(g.contre).max(g.abstentions);
+        c.scrutins += 1;
+        total += max as f64 / exprimes as f64;
+        if max == exprimes {
+            c.unanimes += 1;
+        }
+    }
+    if c.scrutins > 0 {
+        c.cohesion_moyenne = Some(total / c.scrutins as f64);
+    }
+    c
+}
+
+fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.total_cmp(b));
+    let mid = values.len() / 2;
+    Some(if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    })
+}
+
+fn write_minified_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let bytes = serde_json::to_vec(value)?;
+    std::fs::write(path, bytes)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ScrutinGroupe;
+
+    fn scrutin(numero: u32, date: &str, groupes: &[(&str, u32, u32)], votants: &[(&str, &str)]) -> Scrutin {
+        Scrutin {
+            id: format!("S{numero}"),
+            numero,
+            titre: String::new(),
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").ok(),
+            sort: None,
+            dossier_ref: None,
+            type_vote: None,
+            type_vote_libelle: None,
+            votes: HashMap::new(),
+            groupes: groupes
+                .iter()
+                .map(|(organe_ref, pour, contre)| ScrutinGroupe {
+                    organe_ref: organe_ref.to_string(),
+                    nombre_membres: None,
+                    position_majoritaire: None,
+                    pour: *pour,
+                    contre: *contre,
+                    abstentions: 0,
+                    non_votants: 0,
+                })
+                .collect(),
+            groupe_votants: votants.iter().map(|(d, g)| (d.to_string(), g.to_string())).collect(),
+            mises_au_point: HashMap::new(),
+            par_delegation: vec![],
+        }
+    }
+
+    #[test]
+    fn transitions_follow_chronological_group_changes() {
+        // Ordre volontairement non chronologique
+        let scrutins = vec![
+            scrutin(3, "2025-03-01", &[], &[("PA1", "PO2")]),
+            scrutin(1, "2025-01-01", &[], &[("PA1", "PO1"), ("PA2", "PO1")]),
+            scrutin(2, "2025-02-01", &[], &[("PA1", "PO1"), ("PA2", "PO1")]),
+        ];
+        let t = group_transitions(&scrutins);
+        assert_eq!(t.len(), 1);
+        assert_eq!((t[0].deputy_id, t[0].from, t[0].to), ("PA1", "PO1", "PO2"));
+        assert_eq!(t[0].date, NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+    }
+
+    #[test]
+    fn amendments_count_for_group_at_their_date() {
+        let dep: Depute = serde_json::from_value(serde_json::json!({
+            "id": "PA1", "nom": "Dupont", "prenom": "Alice", "groupe_id": "PO2",
+            "groupes": [
+                { "organe_id": "PO1", "libelle": "Groupe 1", "apparente": false,
+                  "date_debut": "2024-07-18", "date_fin": "2025-02-28", "deduite": true },
+                { "organe_id": "PO2", "libelle": "Groupe 2", "apparente": true,
+                  "date_debut": "2025-03-01", "deduite": false }
+            ]
+        }))
+        .unwrap();
+        let amd = |id: &str, date: Option<&str>, adopte: bool| Amendement {
+            id: id.to_string(),
+            numero: None,
+            auteur_id: Some("PA1".to_string()),
+            auteur_type: None,
+            cosignataires_ids: vec![],
+            sort: None,
+            date: date.map(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").unwrap()),
+            date_depot: None,
+            date_circulation: None,
+            date_examen: None,
+            date_sort: None,
+            dossier_ref: None,
+            article: None,
+            texte_ref: None,
+            adopte,
+            mission_visee: None,
+            mission_ref: None,
+            expose_sommaire: None,
+        };
+        let amendements = [
+            amd("A1", Some("2024-10-01"), true),
+            amd("A2", Some("2025-02-28"), false),
+            amd("A3", Some("2025-03-01"), false),
+            // Sans date : groupe actuel
+            amd("A4", None, true),
+            // Hors période
+            amd("A5", Some("2023-01-01"), true),
+        ];
+        let period = (NaiveDate::from_ymd_opt(2024, 7, 18).unwrap(), NaiveDate::from_ymd_opt(2025, 12, 31).unwrap());
+
+        let deputes = [dep];
+        let counts = amendements_par_groupe(&deputes, &amendements, period);
+        assert_eq!(counts["PO1"], (2, 1));
+        assert_eq!(counts["PO2"], (2, 1));
+    }
+
+    #[test]
+    fn cohesion_averages_majority_share() {
+        let s1 = scrutin(1, "2025-01-01", &[("PO1", 3, 1)], &[]);
+        let s2 = scrutin(2, "2025-01-02", &[("PO1", 0, 2)], &[]);
+        let s3 = scrutin(3, "2025-01-03", &[("PO2", 1, 0)], &[]);
+        let c = cohesion_for("PO1", &[&s1, &s2, &s3]);
+        assert_eq!(c.scrutins, 2);
+        assert_eq!(c.unanimes, 1);
+        assert_eq!(c.cohesion_moyenne, Some(0.875));
+    }
+}
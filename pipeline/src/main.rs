@@ -1,15 +1,34 @@
+mod an_json;
+mod cli;
 mod downloader;
 mod models;
+mod parse_cache;
 mod parser;
+mod syceron;
 mod aggregator;
+mod daily_v1;
 mod exporter;
 mod group_ppl_v1;
+mod groupes_v1;
+mod manifest_v1;
+mod quality_v1;
+mod questions_v1;
+mod scrutins_v1;
+mod series_v1;
 
-use anyhow::Result;
-use chrono::Utc;
-use std::path::PathBuf;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::{Path, PathBuf};
 use tracing::{info, warn, error};
 
+use cli::{Cli, Command, GlobalOpts};
+
+const SOURCES_STATE_FILE: &str = "sources.json";
+const PARSED_STATE_FILE: &str = "parsed.json";
+const AGGREGATES_STATE_FILE: &str = "aggregates.json";
+
 #[tokio::main]
 async fn main() -> Result<()> {
     tracing_subscriber::fmt()
@@ -19,64 +38,192 @@ async fn main() -> Result<()> {
         )
         .init();
 
-    info!("=== Démarrage pipeline activite-deputes ===");
+    let cli = Cli::parse();
+    let opts = cli.opts;
+    let command = cli.command.unwrap_or(Command::All);
+
+    info!("=== Démarrage pipeline activite-deputes ({command:?}, {}e législature) ===", opts.legislature);
 
-    let work_dir = PathBuf::from("pipeline/.work");
-    let temp_dir = PathBuf::from("pipeline/.temp_out");
-    let site_data = PathBuf::from("docs/data");
-    let site_exports = PathBuf::from("docs/exports");
+    std::fs::create_dir_all(&opts.work_dir)?;
 
-    std::fs::create_dir_all(&work_dir)?;
-    std::fs::create_dir_all(&temp_dir)?;
-    std::fs::create_dir_all(&site_data)?;
-    std::fs::create_dir_all(&site_exports)?;
+    match command {
+        Command::Download => {
+            run_download(&opts).await?;
+        }
+        Command::Parse => {
+            let (etags, locations) = locate_datasets(&opts)?;
+            run_parse(&opts, &etags, &locations)?;
+        }
+        Command::Aggregate => {
+            let raw: models::RawDataset = load_state(&opts.work_dir.join(PARSED_STATE_FILE))?;
+            run_aggregate(&opts, &raw, opts.reference_now())?;
+        }
+        Command::Export => {
+            let aggregates: aggregator::AllAggregates =
+                load_state(&opts.work_dir.join(AGGREGATES_STATE_FILE))?;
+            let etags: Vec<downloader::EtagInfo> =
+                load_state(&opts.work_dir.join(SOURCES_STATE_FILE))?;
+            run_export(&opts, &aggregates, &etags)?;
+        }
+        Command::All => run_all(&opts).await?,
+    }
 
-    let sources = downloader::Sources::default();
+    Ok(())
+}
 
-    info!("Téléchargement des datasets...");
-    let download_result = downloader::download_all(&sources, &work_dir).await;
+async fn run_all(opts: &GlobalOpts) -> Result<()> {
+    // Échec immédiat plutôt qu'après le téléchargement si la fenêtre LEG est inconnue
+    opts.legislature_start()?;
 
-    match download_result {
+    let (etags, locations) = match run_download(opts).await {
+        Ok(e) => e,
+        Err(e) if opts.is_offline() => return Err(e.context("Préparation des datasets hors ligne")),
         Err(e) => {
             error!("Échec téléchargement critique: {e}");
             error!("Conservation de la dernière version publiée.");
             return Ok(());
         }
-        Ok(etags) => {
-            info!("Téléchargements OK");
-
-            info!("Parsing des données...");
-            let raw = match parser::parse_all(&work_dir) {
-                Ok(r) => r,
-                Err(e) => {
-                    error!("Échec parsing: {e}");
-                    return Ok(());
-                }
-            };
-            info!("Parsing OK — {} députés, {} scrutins, {} amendements",
-                raw.deputes.len(), raw.scrutins.len(), raw.amendements.len());
-
-            info!("Calcul des agrégats...");
-            let now = Utc::now();
-            let aggregates = aggregator::compute_all(&raw, now)?;
-            info!("Agrégats calculés");
-
-            info!("Export JSON...");
-            exporter::write_json(&aggregates, &temp_dir, &etags, now)?;
-
-            info!("Export CSV...");
-            exporter::write_csv(&aggregates, &temp_dir)?;
-
-            info!("Swap atomique vers site/...");
-            swap_output(&temp_dir, &site_data, &site_exports)?;
-
-            info!("=== Pipeline terminé avec succès ===");
+    };
+
+    let raw = match run_parse(opts, &etags, &locations) {
+        Ok(r) => r,
+        Err(e) => {
+            error!("Échec parsing: {e:#}");
+            return Ok(());
         }
+    };
+
+    let aggregates = run_aggregate(opts, &raw, opts.reference_now())?;
+    run_export(opts, &aggregates, &etags)?;
+
+    info!("=== Pipeline terminé avec succès ===");
+    Ok(())
+}
+
+/// Télécharge (ou prépare hors ligne) les datasets ; renvoie leurs ETags et leurs emplacements.
+async fn run_download(opts: &GlobalOpts) -> Result<(Vec<downloader::EtagInfo>, downloader::DatasetLocations)> {
+    let sources = downloader::Sources::for_legislature(opts.legislature);
+
+    let (etags, locations) = if opts.is_offline() {
+        info!("Mode hors ligne: datasets lus depuis {}", opts.offline_dir().display());
+        downloader::prepare_local(&sources, opts.offline_dir(), &opts.work_dir)?
+    } else {
+        info!("Téléchargement des datasets...");
+        let etags = downloader::download_all(&sources, &opts.work_dir).await?;
+        info!("Téléchargements OK");
+        (etags, downloader::DatasetLocations::under(&opts.work_dir, &sources))
+    };
+
+    save_state(&opts.work_dir.join(SOURCES_STATE_FILE), &etags)?;
+    Ok((etags, locations))
+}
+
+/// Emplacements des datasets pour la commande `parse` seule : préparés depuis le répertoire
+/// hors ligne, ou ceux du dernier téléchargement.
+fn locate_datasets(opts: &GlobalOpts) -> Result<(Vec<downloader::EtagInfo>, downloader::DatasetLocations)> {
+    let sources = downloader::Sources::for_legislature(opts.legislature);
+    if opts.is_offline() {
+        return downloader::prepare_local(&sources, opts.offline_dir(), &opts.work_dir);
     }
+    // ETag du dernier téléchargement ; absent, le cache se fonde sur les fichiers présents
+    let etags: Vec<downloader::EtagInfo> =
+        load_state(&opts.work_dir.join(SOURCES_STATE_FILE)).unwrap_or_default();
+    Ok((etags, downloader::DatasetLocations::under(&opts.work_dir, &sources)))
+}
+
+fn run_parse(
+    opts: &GlobalOpts,
+    etags: &[downloader::EtagInfo],
+    locations: &downloader::DatasetLocations,
+) -> Result<models::RawDataset> {
+    let sources = downloader::Sources::for_legislature(opts.legislature);
+    let cache = if opts.no_parse_cache {
+        parse_cache::ParseCache::disabled()
+    } else {
+        let keys: Vec<&str> = sources.list.iter().map(|s| s.key).collect();
+        parse_cache::ParseCache::new(&opts.work_dir, locations, etags, &keys)
+    };
+
+    info!("Parsing des données...");
+    let raw = parser::parse_all(locations, &cache)?;
+    info!("Parsing OK — {} députés, {} scrutins, {} amendements",
+        raw.deputes.len(), raw.scrutins.len(), raw.amendements.len());
+
+    save_state(&opts.work_dir.join(PARSED_STATE_FILE), &raw)?;
+    Ok(raw)
+}
+
+fn run_aggregate(
+    opts: &GlobalOpts,
+    raw: &models::RawDataset,
+    now: DateTime<Utc>,
+) -> Result<aggregator::AllAggregates> {
+    let leg_start = opts.legislature_start()?;
+    info!("Calcul des agrégats (fenêtre LEG depuis le {leg_start})...");
+    let aggregates = aggregator::compute_all(raw, opts.legislature, leg_start, now)?;
+    info!("Agrégats calculés");
+
+    save_state(&opts.work_dir.join(AGGREGATES_STATE_FILE), &aggregates)?;
+    Ok(aggregates)
+}
 
+fn run_export(
+    opts: &GlobalOpts,
+    aggregates: &aggregator::AllAggregates,
+    etags: &[downloader::EtagInfo],
+) -> Result<()> {
+    // Même instant que le calcul des fenêtres, y compris quand `export` est lancé à part
+    let now = aggregates.now;
+    std::fs::create_dir_all(&opts.temp_dir)?;
+    std::fs::create_dir_all(&opts.data_dir)?;
+    std::fs::create_dir_all(&opts.exports_dir)?;
+
+    // Rapport qualité publié par le run précédent : référence des évolutions
+    let previous_path = opts.data_dir.join(quality_v1::QUALITY_REPORT_FILE);
+    let previous_quality: Option<quality_v1::QualityReport> = if !previous_path.exists() {
+        warn!(
+            "Aucun rapport qualité publié sous {} : évolutions non calculées pour ce run",
+            previous_path.display()
+        );
+        None
+    } else {
+        match load_state(&previous_path) {
+            Ok(report) => Some(report),
+            Err(e) => {
+                warn!("Rapport qualité précédent illisible ({e:#}) : évolutions non calculées pour ce run");
+                None
+            }
+        }
+    };
+
+    info!("Export JSON...");
+    exporter::write_json(aggregates, &opts.temp_dir, etags, previous_quality.as_ref(), now)?;
+
+    info!("Export CSV...");
+    exporter::write_csv(aggregates, &opts.temp_dir)?;
+
+    info!("Swap atomique vers {} / {}...", opts.data_dir.display(), opts.exports_dir.display());
+    swap_output(&opts.temp_dir, &opts.data_dir, &opts.exports_dir)?;
     Ok(())
 }
 
+/// Sauvegarde un état intermédiaire (entre deux étapes) dans le répertoire de travail.
+fn save_state<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    let file = std::fs::File::create(path)
+        .with_context(|| format!("Création {}", path.display()))?;
+    serde_json::to_writer(std::io::BufWriter::new(file), value)
+        .with_context(|| format!("Écriture {}", path.display()))?;
+    info!("État intermédiaire sauvegardé: {}", path.display());
+    Ok(())
+}
+
+fn load_state<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("État {} introuvable — lancer l'étape précédente d'abord", path.display()))?;
+    serde_json::from_reader(std::io::BufReader::new(file))
+        .with_context(|| format!("Lecture {}", path.display()))
+}
+
 fn swap_output(temp: &PathBuf, site_data: &PathBuf, site_exports: &PathBuf) -> Result<()> {
     let temp_data = temp.join("data");
     let temp_exports = temp.join("exports");
@@ -0,0 +1,102 @@
+use anyhow::Result;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+use shared::{Manifest, ManifestFile, DATA_SCHEMA_VERSION, MANIFEST_FILE};
+
+/// Champs de version lus en tête des fichiers exportés (le reste est ignoré).
+#[derive(Deserialize)]
+struct VersionProbe {
+    schema_version: Option<u32>,
+    version: Option<u32>,
+}
+
+/// Écrit `manifest.json` : à appeler une fois tous les fichiers de `data/` écrits.
+pub fn write_manifest_json(data_root: &Path, generated_at_iso: &str) -> Result<()> {
+    let mut paths = Vec::new();
+    collect_files(data_root, &mut paths)?;
+
+    let mut files = Vec::with_capacity(paths.len());
+    for path in paths {
+        let rel = path
+            .strip_prefix(data_root)?
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        if rel == MANIFEST_FILE {
+            continue;
+        }
+        let bytes = std::fs::read(&path)?;
+        files.push(ManifestFile {
+            path: rel,
+            schema_version: file_schema_version(&bytes),
+            bytes: bytes.len() as u64,
+            sha256: sha256_hex(&bytes),
+        });
+    }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let manifest = Manifest {
+        schema_version: DATA_SCHEMA_VERSION,
+        generated_at: generated_at_iso.to_string(),
+        total_bytes: files.iter().map(|f| f.bytes).sum(),
+        files,
+    };
+    std::fs::write(data_root.join(MANIFEST_FILE), serde_json::to_vec(&manifest)?)?;
+    eprintln!(
+        "[exporter] {} ({} fichier(s), {:.1} MB, schéma v{})",
+        MANIFEST_FILE,
+        manifest.files.len(),
+        manifest.total_bytes as f64 / (1024.0 * 1024.0),
+        manifest.schema_version
+    );
+    Ok(())
+}
+
+fn collect_files(dir: &Path, out: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, out)?;
+        } else {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Version déclarée par le fichier (`schema_version` ou `version` au premier niveau),
+/// sinon celle du contrat (tableaux, mappings et JSON non versionnés).
+fn file_schema_version(bytes: &[u8]) -> u32 {
+    serde_json::from_slice::<VersionProbe>(bytes)
+        .ok()
+        .and_then(|p| p.schema_version.or(p.version))
+        .unwrap_or(DATA_SCHEMA_VERSION)
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_version_read_from_file_or_defaulted() {
+        assert_eq!(file_schema_version(br#"{"schema_version":3,"x":[1]}"#), 3);
+        assert_eq!(file_schema_version(br#"{"version":2,"groups":[]}"#), 2);
+        assert_eq!(file_schema_version(br#"[{"schema_version":9}]"#), DATA_SCHEMA_VERSION);
+        assert_eq!(file_schema_version(br#"{"PA1":"Titre"}"#), DATA_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn sha256_is_lowercase_hex() {
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
@@ -2,6 +2,12 @@ use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+// Contrats exportés (JSON sous data/) : définis dans la crate `shared`, lue aussi par le frontend.
+pub use shared::{
+    CommissionMembership, CosignGroupBucket, CosignNetworkStats, CosignPeer, DeputeStats, DissentVote, DossierScore,
+    GroupeMembership, MandatAssembleeEpisode, Question, SiteWebSource, TopCosignataire, VotePosition, WeeklyBuckets,
+};
+
 // ─── Raw AN JSON structures ────────────────────────────────────────────────
 
 #[derive(Debug, Deserialize)]
@@ -25,43 +31,8 @@ pub struct OrganesWrapper {
     pub organe: Vec<serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize, Default)]
-pub struct ScrutinsRoot {
-    pub scrutins: Option<ScrutinsWrapper>,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ScrutinsWrapper {
-    pub scrutin: Vec<serde_json::Value>,
-}
-
-#[derive(Debug, Deserialize, Default)]
-pub struct AmendementsRoot {
-    pub amendements: Option<AmendementsWrapper>,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct AmendementsWrapper {
-    pub amendement: Vec<serde_json::Value>,
-}
-
 // ─── Normalized models ─────────────────────────────────────────────────────
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub struct SiteWebSource {
-    pub type_libelle: Option<String>,
-    pub val_elec: String,
-    #[serde(default)]
-    pub url: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub struct MandatAssembleeEpisode {
-    pub date_debut: NaiveDate,
-    #[serde(default)]
-    pub date_fin: Option<NaiveDate>,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Depute {
     pub id: String,
@@ -96,6 +67,12 @@ pub struct Depute {
     #[serde(default)]
     pub telephones: Vec<String>,
     pub uri_hatvp: Option<String>,
+    /// Mandats `COMPER`, `CMP` et `CNPE`, avec la qualité occupée.
+    #[serde(default)]
+    pub commissions: Vec<CommissionMembership>,
+    /// Groupes politiques successifs (mandats `GP`, complétés par les scrutins), par date de début.
+    #[serde(default)]
+    pub groupes: Vec<GroupeMembership>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -107,6 +84,17 @@ pub struct Organe {
     pub couleur: Option<String>,
 }
 
+impl Depute {
+    /// Groupe (organe PO…) du député à `date` d'après son parcours ; groupe actuel si le parcours
+    /// est vide.
+    pub fn groupe_id_at(&self, date: NaiveDate) -> Option<&str> {
+        if self.groupes.is_empty() {
+            return self.groupe_id.as_deref();
+        }
+        shared::groupe_at(&self.groupes, date).map(|g| g.organe_id.as_str())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Scrutin {
     pub id: String,
@@ -115,16 +103,36 @@ pub struct Scrutin {
     pub date: Option<NaiveDate>,
     pub sort: Option<String>,
     pub dossier_ref: Option<String>,
+    /// Code AN du type de vote (SPO, SPS, MOC…) et son libellé.
+    #[serde(default)]
+    pub type_vote: Option<String>,
+    #[serde(default)]
+    pub type_vote_libelle: Option<String>,
     pub votes: HashMap<String, VotePosition>,
+    /// Ventilation par groupe parlementaire (position majoritaire + décompte des voix).
+    #[serde(default)]
+    pub groupes: Vec<ScrutinGroupe>,
+    /// Groupe (organeRef) sous lequel chaque votant apparaît dans la ventilation.
+    #[serde(default)]
+    pub groupe_votants: HashMap<String, String>,
+    /// Mises au point : position que le député déclare avoir voulu exprimer.
+    #[serde(default)]
+    pub mises_au_point: HashMap<String, VotePosition>,
+    /// Votants dont la position a été exprimée par délégation (triés).
+    #[serde(default)]
+    pub par_delegation: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum VotePosition {
-    Pour,
-    Contre,
-    Abstention,
-    NonVotant,
-    Absent,
+pub struct ScrutinGroupe {
+    pub organe_ref: String,
+    pub nombre_membres: Option<u32>,
+    /// `None` si la position majoritaire est absente ou inconnue du dataset.
+    pub position_majoritaire: Option<VotePosition>,
+    pub pour: u32,
+    pub contre: u32,
+    pub abstentions: u32,
+    pub non_votants: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -180,121 +188,91 @@ pub struct Dossier {
     pub origin_chamber: Option<String>, // "assemblee" | "senat" (best effort)
     #[serde(default)]
     pub initiateur_organe_ref: Option<String>,
+    /// Rapporteurs désignés dans les actes du dossier (toutes commissions).
+    #[serde(default)]
+    pub rapporteurs: Vec<Rapporteur>,
 }
 
-// ─── Aggregated output ─────────────────────────────────────────────────────
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DeputeStats {
-    pub deputy_id: String,
-    pub nom: String,
-    pub prenom: String,
-    pub groupe_abrev: Option<String>,
-    pub groupe_nom: Option<String>,
-    pub parti_rattachement: Option<String>,
-    pub dept: Option<String>,
-    pub circo: Option<String>,
-    pub mandat_debut: Option<NaiveDate>,
-    #[serde(default)]
-    pub mandat_fin: Option<NaiveDate>,
-    #[serde(default)]
-    pub mandat_debut_legislature: Option<NaiveDate>,
-    #[serde(default)]
-    pub mandat_assemblee_episodes: Vec<MandatAssembleeEpisode>,
-    pub date_naissance: Option<NaiveDate>,
-    #[serde(default)]
-    pub sexe: Option<String>,
-    pub pays_naissance: Option<String>,
-    pub profession: Option<String>,
-    pub email_assemblee: Option<String>,
-    pub site_web: Option<String>,
+/// Nomination d'un rapporteur (acte `…-NOMIN` d'un dossier législatif).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Rapporteur {
+    pub acteur_id: String,
+    /// `fond` (y compris rapporteur général), `avis` ou `special`.
+    pub role: String,
+    /// Commission qui désigne le rapporteur.
     #[serde(default)]
-    pub sites_web: Vec<String>,
+    pub organe_ref: Option<String>,
+    /// Date de l'acte de nomination, à défaut date de dépôt du dossier.
     #[serde(default)]
-    pub sites_web_sources: Vec<SiteWebSource>,
-    #[serde(default)]
-    pub telephones: Vec<String>,
-    pub uri_hatvp: Option<String>,
-    pub period_start: NaiveDate,
-    pub period_end: NaiveDate,
-    pub scrutins_eligibles: u32,
-    pub votes_exprimes: u32,
-    pub non_votant: u32,
-    pub absent: u32,
-    pub participation_rate: f64,
-    pub pour_count: u32,
-    pub contre_count: u32,
-    pub abst_count: u32,
-    pub amd_authored: u32,
-    pub amd_adopted: u32,
-    pub amd_adoption_rate: Option<f64>,
-    pub amd_cosigned: u32,
-    pub interventions_count: u32,
-    pub interventions_chars: u32,
-    pub top_dossiers: Vec<DossierScore>,
-    #[serde(default)]
-    pub top_cosignataires: Vec<TopCosignataire>,
-    #[serde(default)]
-    pub cosign_network: Option<CosignNetworkStats>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DossierScore {
-    pub dossier_id: String,
-    pub titre: String,
-    pub votes: u32,
-    pub amendements: u32,
-    pub interventions: u32,
-    pub score: u32,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TopCosignataire {
-    pub deputy_id: String,
-    pub nom: String,
-    pub prenom: String,
-    pub groupe_abrev: Option<String>,
-    pub co_signed_count: u32,
+    pub date: Option<NaiveDate>,
 }
 
-
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct CosignNetworkStats {
-    pub total_cosignatures: u32,
-    pub unique_cosignataires: u32,
-    pub in_group_count: u32,
-    pub out_group_count: u32,
+/// Prise de parole en séance publique (compte rendu Syceron) : paragraphes consécutifs
+/// d'un même orateur dans un même point de l'ordre du jour.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Intervention {
+    pub acteur_id: String,
+    pub date: Option<NaiveDate>,
+    /// uid du compte rendu (`CRSANR5L17S2025O1N001`).
+    pub compte_rendu: String,
+    /// Titre du point débattu (rattachement au dossier).
     #[serde(default)]
-    pub in_group: Vec<CosignPeer>,
+    pub point: Option<String>,
     #[serde(default)]
-    pub out_group_groups: Vec<CosignGroupBucket>,
+    pub dossier_ref: Option<String>,
+    /// Longueur du texte prononcé (caractères, espaces normalisés).
+    pub chars: u32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct CosignPeer {
-    pub deputy_id: String,
-    pub nom: String,
-    pub prenom: String,
-    pub groupe_abrev: Option<String>,
-    pub groupe_nom: Option<String>,
-    pub count: u32,
+/// Présence d'un député convoqué à une réunion de commission.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Presence {
+    Present,
+    Absent,
+    Excuse,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct CosignGroupBucket {
-    pub groupe_abrev: Option<String>,
-    pub groupe_nom: Option<String>,
-    pub count_total: u32,
+/// Réunion de commission (dataset Agenda) avec la présence des députés convoqués.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Reunion {
+    /// uid AN (`RUANR5L17S2024IDC452011`).
+    pub id: String,
+    pub date: Option<NaiveDate>,
+    /// Organe réuni (commission, délégation…).
     #[serde(default)]
-    pub members: Vec<CosignPeer>,
+    pub organe_id: Option<String>,
+    pub participants: Vec<(String, Presence)>,
 }
 
 // ─── Full parsed dataset ───────────────────────────────────────────────────
 
+#[derive(Debug, Serialize, Deserialize)]
 pub struct RawDataset {
     pub deputes: Vec<Depute>,
     pub organes: HashMap<String, Organe>,
     pub scrutins: Vec<Scrutin>,
     pub amendements: Vec<Amendement>,
     pub dossiers: HashMap<String, Dossier>,
+    /// Interventions en séance (source optionnelle `debats`).
+    #[serde(default)]
+    pub interventions: Vec<Intervention>,
+    /// Questions écrites, orales sans débat et au Gouvernement (sources optionnelles).
+    #[serde(default)]
+    pub questions: Vec<Question>,
+    /// Réunions de commission avec présences (source optionnelle `reunions`).
+    #[serde(default)]
+    pub reunions: Vec<Reunion>,
+    /// Fichiers écartés au parsing (rapport qualité).
+    #[serde(default)]
+    pub parse_failures: Vec<ParseFailure>,
+}
+
+/// Fichier d'un dataset écarté au parsing (illisible ou JSON invalide).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParseFailure {
+    /// Clé de la source (`scrutins`, `amendements`…).
+    pub source: String,
+    /// Chemin de l'entrée dans le ZIP (ou du fichier dans le répertoire extrait).
+    pub file: String,
+    pub error: String,
 }
@@ -0,0 +1,362 @@
+use anyhow::{Context, Result};
+use serde::{de::DeserializeOwned, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use tracing::{info, warn};
+
+use crate::downloader::{DatasetLocation, DatasetLocations, EtagInfo};
+
+/// Format des entrées ; à incrémenter si l'encodage du cache lui-même change.
+/// Les changements du parser ou des types parsés invalident le cache automatiquement
+/// (empreinte du code source, voir `code_fingerprint`).
+const PARSE_CACHE_VERSION: u32 = 1;
+
+/// Cache binaire (bincode) des datasets parsés, une entrée par source :
+/// `<work_dir>/cache/<key>.bin`. Une entrée n'est réutilisée que si sa clé (identité de la
+/// source + empreinte du code de parsing) est inchangée : une source modifiée est reparsée
+/// seule, les autres sont relues telles quelles.
+pub struct ParseCache {
+    dir: Option<PathBuf>,
+    keys: HashMap<String, String>,
+}
+
+impl ParseCache {
+    /// `etags` : résultats du téléchargement ou de `prepare_local`. Clé d'une source : son ETag,
+    /// à défaut la date et la taille de son ZIP, à défaut l'empreinte de son emplacement actuel.
+    /// Hors ligne, `prepare_local` ne fournit pas d'ETag : seuls les fichiers locaux comptent.
+    pub fn new(work_dir: &Path, locations: &DatasetLocations, etags: &[EtagInfo], keys: &[&str]) -> Self {
+        let code = code_fingerprint();
+        let keys = keys
+            .iter()
+            .map(|&key| {
+                let info = etags.iter().find(|e| e.key == key);
+                let source = match info {
+                    Some(EtagInfo { etag: Some(etag), .. }) => format!("etag:{etag}"),
+                    // ZIP local (mode hors ligne) : date et taille du ZIP
+                    Some(EtagInfo { last_modified: Some(lm), size_bytes, .. }) => format!("zip:{lm}|{size_bytes}"),
+                    _ => location_fingerprint(&locations.location(key)),
+                };
+                (key.to_string(), format!("v{PARSE_CACHE_VERSION}|{code}|{source}"))
+            })
+            .collect();
+        Self { dir: Some(work_dir.join("cache")), keys }
+    }
+
+    /// Cache inactif : chaque source est reparsée et rien n'est écrit.
+    pub fn disabled() -> Self {
+        Self { dir: None, keys: HashMap::new() }
+    }
+
+    /// Relit l'entrée `key` si elle correspond à la source actuelle, sinon exécute `parse`
+    /// et enregistre son résultat. Les erreurs de `parse` ne sont jamais mises en cache.
+    pub fn get_or_parse<T, F>(&self, key: &str, parse: F) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnOnce() -> Result<T>,
+    {
+        let (Some(dir), Some(cache_key)) = (&self.dir, self.keys.get(key)) else {
+            return parse();
+        };
+        let path = dir.join(format!("{key}.bin"));
+
+        let t = Instant::now();
+        match read_entry::<T>(&path, cache_key) {
+            Ok(Some(value)) => {
+                info!("{key}: source inchangée, dataset parsé relu depuis le cache en {:?}", t.elapsed());
+                return Ok(value);
+            }
+            Ok(None) => info!("{key}: cache de parsing absent ou périmé, parsing complet"),
+            Err(e) => warn!("{key}: cache de parsing illisible ({e:#}), parsing complet"),
+        }
+
+        let value = parse()?;
+        if let Err(e) = write_entry(&path, cache_key, &value) {
+            warn!("{key}: écriture du cache de parsing échouée ({e:#})");
+        }
+        Ok(value)
+    }
+}
+
+/// L'entrée commence par sa clé, ce qui permet de l'écarter sans décoder le dataset.
+fn read_entry<T: DeserializeOwned>(path: &Path, cache_key: &str) -> Result<Option<T>> {
+    let file = match std::fs::File::open(path) {
+        Ok(f) => f,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let mut reader = BufReader::new(file);
+    let stored_key: String = bincode::deserialize_from(&mut reader)?;
+    if stored_key != cache_key {
+        return Ok(None);
+    }
+    Ok(Some(bincode::deserialize_from(&mut reader)?))
+}
+
+fn write_entry<T: Serialize>(path: &Path, cache_key: &str, value: &T) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    // Écriture dans un fichier temporaire puis renommage : pas d'entrée tronquée en cas d'arrêt
+    let tmp = path.with_extension("bin.tmp");
+    let file = std::fs::File::create(&tmp).with_context(|| format!("Création {}", tmp.display()))?;
+    let mut writer = BufWriter::new(file);
+    bincode::serialize_into(&mut writer, cache_key)?;
+    bincode::serialize_into(&mut writer, value)?;
+    writer.flush()?;
+    drop(writer);
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Empreinte du code qui produit les datasets parsés.
+fn code_fingerprint() -> String {
+    let mut h = Sha256::new();
+    for src in [
+        include_str!("parser.rs"),
+        include_str!("an_json.rs"),
+        include_str!("syceron.rs"),
+        include_str!("models.rs"),
+        include_str!("../../shared/src/deputes.rs"),
+        include_str!("../../shared/src/questions.rs"),
+    ] {
+        h.update(src.as_bytes());
+    }
+    h.finalize().iter().take(8).map(|b| format!("{b:02x}")).collect()
+}
+
+/// Empreinte de l'emplacement d'une source sans ETag ni ZIP daté.
+fn location_fingerprint(location: &DatasetLocation) -> String {
+    match location {
+        DatasetLocation::Zip(path) => {
+            let (size, modified) = path
+                .metadata()
+                .map(|m| (m.len(), modified_nanos(&m)))
+                .unwrap_or_default();
+            format!("zipfile:{size}|{modified}")
+        }
+        DatasetLocation::Dir(dir) => format!("dir:{}", dir_fingerprint(dir)),
+    }
+}
+
+/// Empreinte d'un répertoire extrait : chemins relatifs, tailles et dates de modification.
+fn dir_fingerprint(dir: &Path) -> String {
+    let mut entries = Vec::new();
+    collect_entries(dir, dir, &mut entries);
+    entries.sort();
+    let mut h = Sha256::new();
+    for entry in &entries {
+        h.update(entry.as_bytes());
+        h.update(b"\n");
+    }
+    h.finalize().iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn collect_entries(root: &Path, dir: &Path, out: &mut Vec<String>) {
+    let Ok(read) = std::fs::read_dir(dir) else { return };
+    for entry in read.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        let Ok(meta) = entry.metadata() else { continue };
+        if meta.is_dir() {
+            collect_entries(root, &path, out);
+            continue;
+        }
+        let rel = path.strip_prefix(root).unwrap_or(&path).to_string_lossy().into_owned();
+        out.push(format!("{rel}\t{}\t{}", meta.len(), modified_nanos(&meta)));
+    }
+}
+
+fn modified_nanos(meta: &std::fs::Metadata) -> u128 {
+    meta.modified()
+        .ok()
+        .and_then(|m| m.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_nanos())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::downloader::Sources;
+
+    fn temp_work_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("parse-cache-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn etag(key: &str, value: &str) -> EtagInfo {
+        EtagInfo { key: key.to_string(), etag: Some(value.to_string()), last_modified: None, size_bytes: 0 }
+    }
+
+    #[test]
+    fn entry_reused_until_etag_changes() {
+        let work = temp_work_dir("etag");
+        let locations = DatasetLocations::under(&work, &Sources::for_legislature(17));
+        let mut calls = 0;
+
+        let cache = ParseCache::new(&work, &locations, &[etag("scrutins", "\"a\"")], &["scrutins"]);
+        let first: Vec<u32> = cache.get_or_parse("scrutins", || { calls += 1; Ok(vec![1, 2, 3]) }).unwrap();
+        let again: Vec<u32> = cache.get_or_parse("scrutins", || { calls += 1; Ok(vec![9]) }).unwrap();
+        assert_eq!((first, again, calls), (vec![1, 2, 3], vec![1, 2, 3], 1));
+
+        let cache = ParseCache::new(&work, &locations, &[etag("scrutins", "\"b\"")], &["scrutins"]);
+        let changed: Vec<u32> = cache.get_or_parse("scrutins", || { calls += 1; Ok(vec![4]) }).unwrap();
+        assert_eq!((changed, calls), (vec![4], 2));
+
+        std::fs::remove_dir_all(&work).unwrap();
+    }
+
+    #[test]
+    fn unreadable_entry_and_parse_errors_fall_back_to_parsing() {
+        let work = temp_work_dir("corrupt");
+        let locations = DatasetLocations::under(&work, &Sources::for_legislature(17));
+        let cache = ParseCache::new(&work, &locations, &[], &["dossiers"]);
+
+        std::fs::create_dir_all(work.join("cache")).unwrap();
+        std::fs::write(work.join("cache/dossiers.bin"), b"\xff\xff\xff").unwrap();
+        let parsed: Vec<String> = cache.get_or_parse("dossiers", || Ok(vec!["PA1".to_string()])).unwrap();
+        assert_eq!(parsed, vec!["PA1".to_string()]);
+
+        let failed: Result<Vec<String>> = ParseCache::new(&work, &locations, &[], &["deputes"])
+            .get_or_parse("deputes", || anyhow::bail!("dump invalide"));
+        assert!(failed.is_err());
+        assert!(!work.join("cache/deputes.bin").exists());
+
+        std::fs::remove_dir_all(&work).unwrap();
+    }
+
+    #[test]
+    fn offline_zip_change_invalidates_entry_despite_stale_etag_cache() {
+        let from = temp_work_dir("offline-from");
+        let work = temp_work_dir("offline-work");
+        let sources = Sources {
+            list: Sources::for_legislature(17).list.into_iter().filter(|s| s.key == "scrutins").collect(),
+        };
+        // etag_cache.json laissé par un ancien téléchargement, jamais mis à jour
+        std::fs::write(from.join("etag_cache.json"), r#"{"scrutins":"\"ancien\""}"#).unwrap();
+        let write_zip = |content: &str| {
+            let mut zip = zip::ZipWriter::new(std::fs::File::create(from.join("scrutins.zip")).unwrap());
+            zip.start_file("json/Scrutins.json", zip::write::FileOptions::default()).unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+            zip.finish().unwrap();
+        };
+        let mut calls = 0;
+
+        write_zip("{}");
+        let (etags, locations) = crate::downloader::prepare_local(&sources, &from, &work).unwrap();
+        let cache = ParseCache::new(&work, &locations, &etags, &["scrutins"]);
+        let first: Vec<u32> = cache.get_or_parse("scrutins", || { calls += 1; Ok(vec![1]) }).unwrap();
+
+        write_zip(r#"{"scrutins":{"scrutin":[]}}"#);
+        let (etags, locations) = crate::downloader::prepare_local(&sources, &from, &work).unwrap();
+        let cache = ParseCache::new(&work, &locations, &etags, &["scrutins"]);
+        let second: Vec<u32> = cache.get_or_parse("scrutins", || { calls += 1; Ok(vec![2]) }).unwrap();
+        assert_eq!((first, second, calls), (vec![1], vec![2], 2));
+
+        std::fs::remove_dir_all(&from).unwrap();
+        std::fs::remove_dir_all(&work).unwrap();
+    }
+
+    /// Écrit `value` dans le cache puis la relit (le second parsing échouerait) ; compare
+    /// les deux via leur forme JSON, les modèles parsés n'implémentant pas `PartialEq`.
+    fn round_trip<T: Serialize + DeserializeOwned>(cache: &ParseCache, key: &str, value: T) {
+        let expected = serde_json::to_value(&value).unwrap();
+        let written: T = cache.get_or_parse(key, || Ok(value)).unwrap();
+        assert_eq!(serde_json::to_value(&written).unwrap(), expected, "{key}: écriture");
+        let read: T = cache
+            .get_or_parse(key, || anyhow::bail!("{key}: entrée non relue depuis le cache"))
+            .unwrap();
+        assert_eq!(serde_json::to_value(&read).unwrap(), expected, "{key}: relecture");
+    }
+
+    #[test]
+    fn parsed_models_round_trip_through_cache() {
+        use crate::models::{Amendement, Depute, Organe, ParseFailure, Question, Scrutin};
+        use serde_json::json;
+
+        let work = temp_work_dir("models");
+        let locations = DatasetLocations::under(&work, &Sources::for_legislature(17));
+        let keys = ["deputes", "scrutins", "amendements", "questions_ecrites"];
+        let etags: Vec<_> = keys.iter().map(|k| etag(k, "\"v1\"")).collect();
+        let cache = ParseCache::new(&work, &locations, &etags, &keys);
+
+        // Champs optionnels renseignés et vides, listes imbriquées
+        let depute: Depute = serde_json::from_value(json!({
+            "id": "PA1", "nom": "Dupont", "prenom": "Marie", "date_naissance": "1970-03-02",
+            "sexe": "F", "pays_naissance": null, "profession": "Avocate",
+            "dept_code": "75", "dept_nom": "Paris", "circo": "3",
+            "mandat_debut": "2024-07-07", "mandat_fin": null, "mandat_debut_legislature": "2024-07-07",
+            "mandat_assemblee_episodes": [{ "date_debut": "2024-07-07", "date_fin": null }],
+            "groupe_id": "PO1", "groupe_abrev": "ABC", "groupe_nom": "Groupe ABC",
+            "parti_id": null, "parti_nom": null, "email_assemblee": "marie.dupont@assemblee-nationale.fr",
+            "site_web": null, "sites_web": ["https://example.org"],
+            "sites_web_sources": [{ "type_libelle": "Site internet", "val_elec": "example.org", "url": "https://example.org" }],
+            "telephones": [], "uri_hatvp": null,
+            "commissions": [{
+                "organe_id": "PO10", "type_organe": "COMPER", "libelle": "Commission des lois",
+                "abrev": null, "qualite": "Membre", "date_debut": "2024-07-20", "date_fin": null
+            }],
+            "groupes": [{
+                "organe_id": "PO1", "abrev": "ABC", "libelle": "Groupe ABC", "qualite": "Membre",
+                "apparente": false, "date_debut": "2024-07-07", "date_fin": null, "deduite": false
+            }]
+        }))
+        .unwrap();
+        let organe = Organe {
+            id: "PO1".to_string(),
+            code_type: "GP".to_string(),
+            libelle: "Groupe ABC".to_string(),
+            abrev: Some("ABC".to_string()),
+            couleur: None,
+        };
+        let failure = ParseFailure { source: "deputes".to_string(), file: "PA2.json".to_string(), error: "EOF".to_string() };
+        round_trip(&cache, "deputes", ((vec![depute], [("PO1".to_string(), organe)].into_iter().collect::<HashMap<_, _>>()), vec![failure]));
+
+        let scrutin: Scrutin = serde_json::from_value(json!({
+            "id": "VTANR5L17V1", "numero": 1, "titre": "l'ensemble du projet de loi",
+            "date": "2024-10-01", "sort": "adopté", "dossier_ref": null,
+            "type_vote": "SPS", "type_vote_libelle": "scrutin public solennel",
+            "votes": { "PA1": "Pour", "PA2": "NonVotant" },
+            "groupes": [{
+                "organe_ref": "PO1", "nombre_membres": 30, "position_majoritaire": null,
+                "pour": 20, "contre": 3, "abstentions": 1, "non_votants": 0
+            }],
+            "groupe_votants": { "PA1": "PO1" },
+            "mises_au_point": { "PA2": "Contre" },
+            "par_delegation": ["PA1"]
+        }))
+        .unwrap();
+        round_trip(&cache, "scrutins", (vec![scrutin], Vec::<ParseFailure>::new()));
+
+        let amendement: Amendement = serde_json::from_value(json!({
+            "id": "AMANR5L17PO1B1", "numero": "12", "auteur_id": "PA1", "auteur_type": "Député",
+            "cosignataires_ids": ["PA2", "PA3"], "sort": "Adopté", "date": "2024-10-02",
+            "date_depot": "2024-10-02", "date_circulation": null, "date_examen": null, "date_sort": "2024-10-05",
+            "dossier_ref": "DLR1", "article": "ART. 2", "texte_ref": null, "adopte": true,
+            "mission_visee": null, "mission_ref": null, "expose_sommaire": "<p>Exposé</p>"
+        }))
+        .unwrap();
+        round_trip(&cache, "amendements", (vec![amendement], Vec::<ParseFailure>::new()));
+
+        let question = Question {
+            id: "QANR5L17QE1".to_string(),
+            type_question: "QE".to_string(),
+            numero: Some(1),
+            auteur_id: "PA1".to_string(),
+            ministere: Some("Ministère de l'intérieur".to_string()),
+            rubrique: None,
+            titre: Some("Sécurité routière".to_string()),
+            date_question: chrono::NaiveDate::from_ymd_opt(2024, 10, 8),
+            date_reponse: None,
+            repondue: false,
+            cloture: None,
+        };
+        round_trip(&cache, "questions_ecrites", (vec![question], Vec::<ParseFailure>::new()));
+
+        std::fs::remove_dir_all(&work).unwrap();
+    }
+}
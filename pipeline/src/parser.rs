@@ -1,10 +1,14 @@
 use anyhow::{Context, Result};
 use chrono::NaiveDate;
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
 use std::time::Instant;
 use tracing::{info, warn};
 
+use crate::an_json::{self, AmendementAn, Node, QuestionAn, ReunionAn, Scalar, ScrutinAn, TexteJo};
+use crate::downloader::{DatasetLocation, DatasetLocations};
+use crate::parse_cache::ParseCache;
+use crate::syceron;
 use crate::models::*;
 
 // ─── OneOrMany: gère le pattern JSON de l'AN ({} quand 1 seul, [] quand plusieurs)
@@ -225,17 +229,20 @@ fn is_mandat_parlementaire_assemblee(m: &serde_json::Value) -> bool {
     xsi_type == "MandatParlementaire_type" && is_type_organe_assemblee(type_organe)
 }
 
-pub fn parse_all(work_dir: &Path) -> Result<RawDataset> {
-    let deputes_dir = work_dir.join("deputes");
-    let scrutins_dir = work_dir.join("scrutins");
-    let amendements_dir = work_dir.join("amendements");
-    let dossiers_dir = work_dir.join("dossiers");
+pub fn parse_all(locations: &DatasetLocations, cache: &ParseCache) -> Result<RawDataset> {
+    let deputes_src = locations.location("deputes");
+    let scrutins_src = locations.location("scrutins");
+    let amendements_src = locations.location("amendements");
+    let dossiers_src = locations.location("dossiers");
+    let debats_src = locations.location("debats");
+    let reunions_src = locations.location("reunions");
 
     let t_all = Instant::now();
     info!("Parsing détaillé: début");
 
     let t = Instant::now();
-    let (deputes, organes) = parse_deputes(&deputes_dir)
+    let ((mut deputes, organes), mut parse_failures) = cache
+        .get_or_parse("deputes", || parse_deputes(&deputes_src))
         .context("Parsing députés")?;
     info!(
         "Parsing députés OK en {:?} (deputes={}, organes={})",
@@ -245,13 +252,19 @@ pub fn parse_all(work_dir: &Path) -> Result<RawDataset> {
     );
 
     let t = Instant::now();
-    let scrutins = parse_scrutins(&scrutins_dir)
+    let (scrutins, failures) = cache
+        .get_or_parse("scrutins", || parse_scrutins(&scrutins_src))
         .context("Parsing scrutins")?;
+    parse_failures.extend(failures);
+    // Hors cache : dépend des députés et des scrutins
+    complete_groupe_history(&mut deputes, &scrutins, &organes);
     info!("Parsing scrutins OK en {:?} (scrutins={})", t.elapsed(), scrutins.len());
 
     let t = Instant::now();
-    let amendements = parse_amendements(&amendements_dir)
+    let (amendements, failures) = cache
+        .get_or_parse("amendements", || parse_amendements(&amendements_src))
         .context("Parsing amendements")?;
+    parse_failures.extend(failures);
     info!(
         "Parsing amendements OK en {:?} (amendements={})",
         t.elapsed(),
@@ -259,75 +272,152 @@ pub fn parse_all(work_dir: &Path) -> Result<RawDataset> {
     );
 
     let t = Instant::now();
-    let dossiers = match parse_dossiers(&dossiers_dir) {
-        Ok(d) => d,
+    let dossiers = match cache.get_or_parse("dossiers", || parse_dossiers(&dossiers_src)) {
+        Ok((d, failures)) => {
+            parse_failures.extend(failures);
+            d
+        }
         Err(e) => {
             warn!("Parsing dossiers échoué ({e}) — fallback dossiers vides");
+            parse_failures.push(parse_failure("dossiers", &dossiers_src.to_string(), format!("{e:#}")));
             HashMap::new()
         }
     };
     info!("Parsing dossiers OK en {:?} (dossiers={})", t.elapsed(), dossiers.len());
 
+    let t = Instant::now();
+    let mut interventions = match cache.get_or_parse("debats", || parse_debats(&debats_src)) {
+        Ok((i, failures)) => {
+            parse_failures.extend(failures);
+            i
+        }
+        Err(e) => {
+            warn!("Parsing débats échoué ({e}) — fallback interventions vides");
+            parse_failures.push(parse_failure("debats", &debats_src.to_string(), format!("{e:#}")));
+            Vec::new()
+        }
+    };
+    // Hors cache : dépend aussi des dossiers, qui peuvent changer sans les débats
+    syceron::attribute_dossiers(&mut interventions, &dossiers);
+    info!(
+        "Parsing débats OK en {:?} (interventions={}, rattachées à un dossier={})",
+        t.elapsed(),
+        interventions.len(),
+        interventions.iter().filter(|i| i.dossier_ref.is_some()).count()
+    );
+
+    let t = Instant::now();
+    let mut questions = Vec::new();
+    for (key, type_question) in QUESTION_SOURCES {
+        let src = locations.location(key);
+        match cache.get_or_parse(key, || parse_questions(&src, key, type_question)) {
+            Ok((mut parsed, failures)) => {
+                parse_failures.extend(failures);
+                questions.append(&mut parsed);
+            }
+            Err(e) => {
+                warn!("Parsing {key} échoué ({e}) — fallback questions vides");
+                parse_failures.push(parse_failure(key, &src.to_string(), format!("{e:#}")));
+            }
+        }
+    }
+    info!("Parsing questions OK en {:?} (questions={})", t.elapsed(), questions.len());
+
+    let t = Instant::now();
+    let reunions = match cache.get_or_parse("reunions", || parse_reunions(&reunions_src)) {
+        Ok((r, failures)) => {
+            parse_failures.extend(failures);
+            r
+        }
+        Err(e) => {
+            warn!("Parsing réunions échoué ({e}) — fallback réunions vides");
+            parse_failures.push(parse_failure("reunions", &reunions_src.to_string(), format!("{e:#}")));
+            Vec::new()
+        }
+    };
+    info!("Parsing réunions OK en {:?} (réunions={})", t.elapsed(), reunions.len());
+
     info!("Parsing détaillé: terminé en {:?}", t_all.elapsed());
 
-    Ok(RawDataset { deputes, organes, scrutins, amendements, dossiers })
+    if !parse_failures.is_empty() {
+        warn!("Parsing: {} fichier(s) écarté(s) (voir quality_report.json)", parse_failures.len());
+    }
+
+    Ok(RawDataset {
+        deputes,
+        organes,
+        scrutins,
+        amendements,
+        dossiers,
+        interventions,
+        questions,
+        reunions,
+        parse_failures,
+    })
 }
 
-fn parse_deputes(dir: &Path) -> Result<(Vec<Depute>, HashMap<String, Organe>)> {
-    if !dir.exists() {
-        anyhow::bail!("Répertoire non trouvé: {:?}", dir);
-    }
+fn parse_failure(source: &str, file: &str, error: impl std::fmt::Display) -> ParseFailure {
+    ParseFailure { source: source.to_string(), file: file.to_string(), error: error.to_string() }
+}
 
-    let mut json_files: Vec<std::path::PathBuf> = std::fs::read_dir(dir)?
-        .filter_map(|e| e.ok().map(|e| e.path()))
-        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("json"))
-        .collect();
+/// Députés et organes, avec les fichiers écartés (mode multi-fichiers).
+type DeputesParsed = ((Vec<Depute>, HashMap<String, Organe>), Vec<ParseFailure>);
 
-    json_files.sort();
+fn parse_deputes(src: &DatasetLocation) -> Result<DeputesParsed> {
+    if !src.exists() {
+        anyhow::bail!("Dataset non trouvé: {src}");
+    }
+
+    let json_files = src.json_entries()?;
 
     if json_files.is_empty() {
-        anyhow::bail!("Aucun fichier JSON dans {:?}", dir);
+        anyhow::bail!("Aucun fichier JSON dans {src}");
     }
 
     // Heuristique: si on voit beaucoup de PA*/PO*, le ZIP est au format "multi-fichiers"
     // (1 fichier JSON par acteur / organe). L'ancien format est un JSON agrégé avec "export".
     let mut pa = 0usize;
     let mut po = 0usize;
-    for p in &json_files {
-        if let Some(name) = p.file_name().and_then(|s| s.to_str()) {
-            if name.starts_with("PA") {
-                pa += 1;
-            } else if name.starts_with("PO") {
-                po += 1;
-            }
+    for name in &json_files {
+        let base = entry_basename(name);
+        if base.starts_with("PA") {
+            pa += 1;
+        } else if base.starts_with("PO") {
+            po += 1;
         }
     }
 
     if pa + po >= 50 {
         info!("Députés: détection format multi-fichiers (acteurs≈{}, organes≈{})", pa, po);
-        return parse_deputes_multifile(&json_files);
+        return parse_deputes_multifile(src, &json_files);
     }
 
     // Sinon, on tente le format agrégé (export.acteurs / export.organes)
-    match parse_deputes_aggregated(&json_files) {
-        Ok(ok) => Ok(ok),
+    match parse_deputes_aggregated(src, &json_files) {
+        Ok(ok) => Ok((ok, Vec::new())),
         Err(e) => {
             warn!("Parsing format agrégé échoué ({e}), tentative multi-fichiers...");
-            parse_deputes_multifile(&json_files)
+            parse_deputes_multifile(src, &json_files)
         }
     }
 }
 
-fn parse_deputes_aggregated(json_files: &[std::path::PathBuf]) -> Result<(Vec<Depute>, HashMap<String, Organe>)> {
+/// Nom de fichier d'une entrée (`json/acteur/PA1.json` → `PA1.json`).
+fn entry_basename(name: &str) -> &str {
+    name.rsplit('/').next().unwrap_or(name)
+}
+
+fn parse_deputes_aggregated(src: &DatasetLocation, json_files: &[String]) -> Result<(Vec<Depute>, HashMap<String, Organe>)> {
     let mut best_root: Option<serde_json::Value> = None;
     let mut best_score: usize = 0;
-    let mut best_path: Option<std::path::PathBuf> = None;
+    let mut best_path: Option<String> = None;
 
-    for path in json_files {
-        let data = std::fs::read_to_string(path)
-            .with_context(|| format!("Lecture {:?}", path))?;
+    src.read_entries(json_files, |name, reader| {
+        let mut data = String::new();
+        reader.read_to_string(&mut data)
+            .with_context(|| format!("Lecture {name}"))?;
         let root: serde_json::Value = serde_json::from_str(&data)
-            .with_context(|| format!("JSON invalide {:?}", path))?;
+            .with_context(|| format!("JSON invalide {name}"))?;
 
         let export = root.get("export").unwrap_or(&root);
         let acteurs = one_or_many(&export["acteurs"]["acteur"]);
@@ -337,9 +427,10 @@ fn parse_deputes_aggregated(json_files: &[std::path::PathBuf]) -> Result<(Vec<De
         if score > best_score {
             best_score = score;
             best_root = Some(root);
-            best_path = Some(path.clone());
+            best_path = Some(name.to_string());
         }
-    }
+        Ok(())
+    })?;
 
     if best_score == 0 {
         anyhow::bail!("Aucun JSON agrégé exploitable trouvé (score=0)");
@@ -362,44 +453,57 @@ fn parse_deputes_aggregated(json_files: &[std::path::PathBuf]) -> Result<(Vec<De
         .collect();
 
     if deputes.is_empty() {
-        anyhow::bail!("0 député parsé depuis {:?} (format agrégé)", picked);
+        anyhow::bail!("0 député parsé depuis {picked} (format agrégé)");
     }
 
     info!("Députés: format agrégé OK (deputes={}, organes={})", deputes.len(), organes.len());
     Ok((deputes, organes))
 }
 
-fn parse_deputes_multifile(json_files: &[std::path::PathBuf]) -> Result<(Vec<Depute>, HashMap<String, Organe>)> {
-    let mut organes: HashMap<String, Organe> = HashMap::new();
-
-    // Pass 1: organe (PO*.json)
-    for path in json_files.iter().filter(|p| {
-        p.file_name()
-            .and_then(|s| s.to_str())
-            .map(|n| n.starts_with("PO"))
-            .unwrap_or(false)
-    }) {
-        let data = match std::fs::read_to_string(path) {
-            Ok(s) => s,
-            Err(e) => {
-                warn!("Organe: lecture échouée {:?}: {e}", path);
-                continue;
-            }
-        };
+/// Document JSON d'une entrée ; `None` (avec un warning) si elle est illisible ou invalide.
+/// L'échec est aussi consigné dans `failures` (source `source`).
+fn read_entry_json(
+    source: &str,
+    kind: &str,
+    name: &str,
+    reader: &mut dyn Read,
+    failures: &mut Vec<ParseFailure>,
+) -> Option<serde_json::Value> {
+    let mut data = String::new();
+    if let Err(e) = reader.read_to_string(&mut data) {
+        warn!("{kind}: lecture échouée {name}: {e}");
+        failures.push(parse_failure(source, name, format!("lecture échouée: {e}")));
+        return None;
+    }
+    match serde_json::from_str(&data) {
+        Ok(v) => Some(v),
+        Err(e) => {
+            warn!("{kind}: JSON invalide {name}: {e}");
+            failures.push(parse_failure(source, name, format!("JSON invalide: {e}")));
+            None
+        }
+    }
+}
 
-        let root: serde_json::Value = match serde_json::from_str(&data) {
-            Ok(v) => v,
-            Err(e) => {
-                warn!("Organe: JSON invalide {:?}: {e}", path);
-                continue;
-            }
-        };
+fn parse_deputes_multifile(src: &DatasetLocation, json_files: &[String]) -> Result<DeputesParsed> {
+    let with_prefix = |prefix: &str| -> Vec<String> {
+        json_files.iter()
+            .filter(|name| entry_basename(name).starts_with(prefix))
+            .cloned()
+            .collect()
+    };
 
+    // Pass 1: organe (PO*.json)
+    let mut failures = Vec::new();
+    let mut organes: HashMap<String, Organe> = HashMap::new();
+    src.read_entries(&with_prefix("PO"), |name, reader| {
+        let Some(root) = read_entry_json("deputes", "Organe", name, reader, &mut failures) else { return Ok(()) };
         let node = root.get("organe").unwrap_or(&root);
         if let Some(o) = parse_organe(node) {
             organes.insert(o.id.clone(), o);
         }
-    }
+        Ok(())
+    })?;
 
     if organes.is_empty() {
         warn!("Députés: 0 organe parsé en mode multi-fichiers (les groupes/partis seront vides)");
@@ -407,33 +511,14 @@ fn parse_deputes_multifile(json_files: &[std::path::PathBuf]) -> Result<(Vec<Dep
 
     // Pass 2: acteurs (PA*.json)
     let mut deputes: Vec<Depute> = Vec::new();
-    for path in json_files.iter().filter(|p| {
-        p.file_name()
-            .and_then(|s| s.to_str())
-            .map(|n| n.starts_with("PA"))
-            .unwrap_or(false)
-    }) {
-        let data = match std::fs::read_to_string(path) {
-            Ok(s) => s,
-            Err(e) => {
-                warn!("Acteur: lecture échouée {:?}: {e}", path);
-                continue;
-            }
-        };
-
-        let root: serde_json::Value = match serde_json::from_str(&data) {
-            Ok(v) => v,
-            Err(e) => {
-                warn!("Acteur: JSON invalide {:?}: {e}", path);
-                continue;
-            }
-        };
-
+    src.read_entries(&with_prefix("PA"), |name, reader| {
+        let Some(root) = read_entry_json("deputes", "Acteur", name, reader, &mut failures) else { return Ok(()) };
         let node = root.get("acteur").unwrap_or(&root);
         if let Some(d) = parse_depute(node, &organes) {
             deputes.push(d);
         }
-    }
+        Ok(())
+    })?;
 
     if deputes.is_empty() {
         anyhow::bail!(
@@ -442,7 +527,7 @@ fn parse_deputes_multifile(json_files: &[std::path::PathBuf]) -> Result<(Vec<Dep
     }
 
     info!("Députés: format multi-fichiers OK (deputes={}, organes={})", deputes.len(), organes.len());
-    Ok((deputes, organes))
+    Ok(((deputes, organes), failures))
 }
 
 fn parse_organe(v: &serde_json::Value) -> Option<Organe> {
@@ -601,6 +686,8 @@ fn parse_depute(v: &serde_json::Value, organes: &HashMap<String, Organe>) -> Opt
     let mut parti_id: Option<String> = None;
     let mut parti_nom: Option<String> = None;
     let mut parti_debut: Option<NaiveDate> = None;
+    let mut commissions: Vec<CommissionMembership> = Vec::new();
+    let mut groupes: Vec<GroupeMembership> = Vec::new();
 
     for m in &mandats {
         let type_organe = m["typeOrgane"].as_str().unwrap_or("");
@@ -655,8 +742,11 @@ fn parse_depute(v: &serde_json::Value, organes: &HashMap<String, Organe>) -> Opt
             }
         }
 
-        // Groupe parlementaire — on retient le plus récent par dateDebut
+        // Groupe parlementaire — parcours complet, et groupe courant : le plus récent par dateDebut
         if type_organe == "GP" {
+            if let Some(g) = parse_groupe_membership(m, organes) {
+                groupes.push(g);
+            }
             let debut = m["dateDebut"].as_str().and_then(parse_date);
             let fin = m["dateFin"].as_str().and_then(parse_date);
             // Ignorer les mandats de groupe terminés (sauf si c'est le seul)
@@ -699,7 +789,16 @@ fn parse_depute(v: &serde_json::Value, organes: &HashMap<String, Organe>) -> Opt
                 }
             }
         }
+
+        // Commissions : toutes les appartenances, avec la qualité occupée
+        if COMMISSION_TYPES.contains(&type_organe) {
+            if let Some(c) = parse_commission_membership(m, type_organe, organes) {
+                commissions.push(c);
+            }
+        }
     }
+    sort_commissions(&mut commissions);
+    sort_groupes(&mut groupes);
 
     let mut mandat_assemblee_episodes: Vec<MandatAssembleeEpisode> = mandat_windows_candidates
         .into_iter()
@@ -748,24 +847,186 @@ fn parse_depute(v: &serde_json::Value, organes: &HashMap<String, Organe>) -> Opt
         sites_web_sources,
         telephones,
         uri_hatvp,
+        commissions,
+        groupes,
+    })
+}
+
+fn parse_groupe_membership(m: &serde_json::Value, organes: &HashMap<String, Organe>) -> Option<GroupeMembership> {
+    let organe_id = opt_non_empty_str(&m["organes"]["organeRef"])?;
+    let qualite = opt_non_empty_str(&m["infosQualite"]["codeQualite"])
+        .or_else(|| opt_non_empty_str(&m["infosQualite"]["libQualite"]))
+        .unwrap_or_default();
+    Some(GroupeMembership {
+        apparente: qualite.to_lowercase().contains("apparent"),
+        qualite,
+        date_debut: m["dateDebut"].as_str().and_then(parse_date),
+        date_fin: m["dateFin"].as_str().and_then(parse_date),
+        deduite: false,
+        ..groupe_from_organe(&organe_id, organes)
     })
 }
 
-fn parse_scrutins(dir: &Path) -> Result<Vec<Scrutin>> {
-    if !dir.exists() {
-        return Ok(vec![]);
+/// Appartenance à `organe_id` sans qualité ni dates, libellés pris dans les organes.
+fn groupe_from_organe(organe_id: &str, organes: &HashMap<String, Organe>) -> GroupeMembership {
+    let organe = organes.get(organe_id);
+    GroupeMembership {
+        organe_id: organe_id.to_string(),
+        abrev: organe.and_then(|o| o.abrev.clone()),
+        libelle: organe
+            .map(|o| o.libelle.clone())
+            .filter(|l| !l.trim().is_empty())
+            .unwrap_or_else(|| organe_id.to_string()),
+        qualite: String::new(),
+        apparente: false,
+        date_debut: None,
+        date_fin: None,
+        deduite: false,
     }
-    let mut scrutins = Vec::new();
-    for entry in std::fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.extension().and_then(|e| e.to_str()) == Some("json") {
-            match parse_scrutin_file(&path) {
-                Ok(mut v) => scrutins.append(&mut v),
-                Err(e) => warn!("Scrutin file {:?}: {e}", path),
+}
+
+fn sort_groupes(groupes: &mut Vec<GroupeMembership>) {
+    groupes.sort_by(|a, b| {
+        a.date_debut
+            .cmp(&b.date_debut)
+            .then(a.date_fin.is_none().cmp(&b.date_fin.is_none()))
+            .then(a.date_fin.cmp(&b.date_fin))
+            .then(a.organe_id.cmp(&b.organe_id))
+    });
+    groupes.dedup();
+}
+
+/// Complète le parcours de chaque député par le groupe sous lequel il est décompté dans les
+/// scrutins, aux dates qu'aucun mandat `GP` ne couvre : le dataset des députés en exercice ne
+/// publie que les mandats en cours, un changement de groupe y efface le groupe précédent.
+fn complete_groupe_history(deputes: &mut [Depute], scrutins: &[Scrutin], organes: &HashMap<String, Organe>) {
+    let mut observations: HashMap<&str, Vec<(NaiveDate, &str)>> = HashMap::new();
+    for s in scrutins {
+        let Some(date) = s.date else { continue };
+        for (dep_id, groupe_ref) in &s.groupe_votants {
+            observations.entry(dep_id.as_str()).or_default().push((date, groupe_ref.as_str()));
+        }
+    }
+    let mut completed = 0usize;
+    for dep in deputes.iter_mut() {
+        let Some(obs) = observations.get_mut(dep.id.as_str()) else { continue };
+        obs.sort_unstable();
+        obs.dedup();
+        let deduites = deduce_groupe_periods(&dep.groupes, obs, organes);
+        if !deduites.is_empty() {
+            completed += 1;
+            dep.groupes.extend(deduites);
+            sort_groupes(&mut dep.groupes);
+        }
+    }
+    if completed > 0 {
+        info!("Groupes: parcours complété par les scrutins pour {completed} député(s)");
+    }
+}
+
+/// Périodes déduites des observations `(date, groupe)` triées non couvertes par `known` : une
+/// période par suite d'observations consécutives dans le même groupe, close la veille de
+/// l'observation suivante (ou du mandat connu suivant).
+fn deduce_groupe_periods(
+    known: &[GroupeMembership],
+    obs: &[(NaiveDate, &str)],
+    organes: &HashMap<String, Organe>,
+) -> Vec<GroupeMembership> {
+    let mut out: Vec<GroupeMembership> = Vec::new();
+    // Période en cours de construction : (groupe, première date)
+    let mut run: Option<(&str, NaiveDate)> = None;
+    let close = |out: &mut Vec<GroupeMembership>, (groupe, debut): (&str, NaiveDate), next: Option<NaiveDate>| {
+        let next_known = known.iter().filter_map(|m| m.date_debut).filter(|d| *d > debut).min();
+        let fin = [next, next_known].into_iter().flatten().min().and_then(|d| d.pred_opt());
+        out.push(GroupeMembership {
+            date_debut: Some(debut),
+            date_fin: fin,
+            deduite: true,
+            ..groupe_from_organe(groupe, organes)
+        });
+    };
+    for &(date, groupe) in obs {
+        let covered = known.iter().any(|m| m.covers(date));
+        match run {
+            Some((g, _)) if !covered && g == groupe => {}
+            Some(current) => {
+                // Même date, autre groupe : la période suivante commence le lendemain
+                if current.1 < date {
+                    close(&mut out, current, Some(date));
+                }
+                run = (!covered).then_some((groupe, date));
             }
+            None => run = (!covered).then_some((groupe, date)),
         }
     }
+    if let Some(current) = run {
+        close(&mut out, current, None);
+    }
+    out
+}
+
+/// Types d'organes retenus comme commissions : permanentes, mixtes paritaires, d'enquête.
+const COMMISSION_TYPES: [&str; 3] = ["COMPER", "CMP", "CNPE"];
+
+fn parse_commission_membership(
+    m: &serde_json::Value,
+    type_organe: &str,
+    organes: &HashMap<String, Organe>,
+) -> Option<CommissionMembership> {
+    let organe_id = opt_non_empty_str(&m["organes"]["organeRef"])?;
+    let organe = organes.get(&organe_id);
+    let qualite = opt_non_empty_str(&m["infosQualite"]["codeQualite"])
+        .or_else(|| opt_non_empty_str(&m["infosQualite"]["libQualite"]))
+        .unwrap_or_else(|| "Membre".to_string());
+    Some(CommissionMembership {
+        libelle: organe
+            .map(|o| o.libelle.clone())
+            .filter(|l| !l.trim().is_empty())
+            .unwrap_or_else(|| organe_id.clone()),
+        abrev: organe.and_then(|o| o.abrev.clone()),
+        organe_id,
+        type_organe: type_organe.to_string(),
+        qualite,
+        date_debut: m["dateDebut"].as_str().and_then(parse_date),
+        date_fin: m["dateFin"].as_str().and_then(parse_date),
+    })
+}
+
+/// Commissions permanentes, puis d'enquête, puis mixtes paritaires ; en cours d'abord, puis
+/// les plus récentes. Les doublons exacts (même organe, qualité et dates) sont retirés.
+fn sort_commissions(commissions: &mut Vec<CommissionMembership>) {
+    let rank = |t: &str| match t {
+        "COMPER" => 0,
+        "CNPE" => 1,
+        _ => 2,
+    };
+    commissions.sort_by(|a, b| {
+        rank(&a.type_organe)
+            .cmp(&rank(&b.type_organe))
+            .then(a.date_fin.is_some().cmp(&b.date_fin.is_some()))
+            .then(b.date_debut.cmp(&a.date_debut))
+            .then(a.organe_id.cmp(&b.organe_id))
+            .then(a.qualite.cmp(&b.qualite))
+    });
+    commissions.dedup();
+}
+
+fn parse_scrutins(src: &DatasetLocation) -> Result<(Vec<Scrutin>, Vec<ParseFailure>)> {
+    if !src.exists() {
+        return Ok((vec![], vec![]));
+    }
+    let mut scrutins = Vec::new();
+    let mut failures = Vec::new();
+    src.read_json(|name, reader| {
+        match parse_scrutin_reader(reader) {
+            Ok(mut v) => scrutins.append(&mut v),
+            Err(e) => {
+                warn!("Scrutin file {name}: {e}");
+                failures.push(parse_failure("scrutins", name, e));
+            }
+        }
+        Ok(())
+    })?;
 
     let total_votes: usize = scrutins.iter().map(|s| s.votes.len()).sum();
     let avg = if scrutins.is_empty() {
@@ -780,178 +1041,237 @@ fn parse_scrutins(dir: &Path) -> Result<Vec<Scrutin>> {
         avg
     );
 
-    Ok(scrutins)
+    Ok((scrutins, failures))
 }
 
-fn parse_scrutin_file(path: &Path) -> Result<Vec<Scrutin>> {
-    let data = std::fs::read_to_string(path)?;
-    let root: serde_json::Value = serde_json::from_str(&data)?;
-
-    // Plusieurs structures possibles selon le fichier AN
-    let scrutins_raw = one_or_many(&root["scrutins"]["scrutin"])
-        .into_iter()
-        .chain(one_or_many(&root["scrutin"]))
-        .collect::<Vec<_>>();
-
+/// Scrutins d'un document AN (`scrutins.scrutin` et/ou `scrutin`), convertis au fil de la lecture.
+fn parse_scrutin_reader(reader: impl Read) -> Result<Vec<Scrutin>> {
     // Dédupliquer par uid pour éviter les doublons si les deux clés existent
-    let mut seen = std::collections::HashSet::new();
-    let scrutins_raw: Vec<_> = scrutins_raw.into_iter()
-        .filter(|s| {
-            if let Some(uid) = s["uid"].as_str() {
-                seen.insert(uid.to_string())
-            } else {
-                true
+    let mut seen = HashSet::new();
+    let mut scrutins = Vec::new();
+    an_json::stream_elements(reader, "scrutins", "scrutin", |raw: ScrutinAn| {
+        if let Some(scrutin) = parse_scrutin(&raw) {
+            if seen.insert(scrutin.id.clone()) {
+                scrutins.push(scrutin);
             }
-        })
-        .collect();
-
-    Ok(scrutins_raw.iter().filter_map(parse_scrutin).collect())
+        }
+    })?;
+    Ok(scrutins)
 }
 
-fn parse_scrutin(v: &serde_json::Value) -> Option<Scrutin> {
-    let id = v["uid"].as_str()?.to_string();
-    let numero = v["numero"].as_str()
-        .and_then(|s| s.parse::<u32>().ok())
-        .or_else(|| v["numero"].as_u64().map(|n| n as u32))
-        .unwrap_or(0);
-    let titre = v["titre"].as_str()
-        .or_else(|| v["objet"]["libelle"].as_str())
+fn parse_scrutin(v: &ScrutinAn) -> Option<Scrutin> {
+    let id = v.uid.to_string_opt()?;
+    let numero = v.numero.as_u32().unwrap_or(0);
+    let titre = v.titre.as_str()
+        .or_else(|| v.objet.libelle.as_str())
         .unwrap_or("")
         .to_string();
-    let date = v["dateScrutin"].as_str().and_then(parse_date);
-    let sort = v["sort"]["value"].as_str()
-        .or_else(|| v["sort"]["libelle"].as_str())
-        .or_else(|| v["sort"]["code"].as_str())
-        .or_else(|| v["sort"].as_str())
+    let date = v.date_scrutin.as_str().and_then(parse_date);
+    let sort = v.sort.value.as_str()
+        .or_else(|| v.sort.libelle.as_str())
+        .or_else(|| v.sort.code.as_str())
+        .or(v.sort.text.as_deref())
         .map(String::from);
-    let dossier_ref = v["dossierRef"].as_str()
-        .or_else(|| v["objet"]["dossierLegislatif"].as_str())
+    let dossier_ref = v.dossier_ref.as_str()
+        .or_else(|| v.objet.dossier_legislatif.as_str())
         .map(String::from);
+    let type_vote = non_empty(&v.type_vote.code_type_vote);
+    let type_vote_libelle = non_empty(&v.type_vote.libelle_type_vote);
 
     let mut votes: HashMap<String, VotePosition> = HashMap::new();
+    let mut scrutin_groupes: Vec<ScrutinGroupe> = Vec::new();
+    let mut groupe_votants: HashMap<String, String> = HashMap::new();
+    let mut par_delegation: Vec<String> = Vec::new();
 
     // Schéma AN observé (scrutins.zip):
     // ventilationVotes.organe.groupes.groupe[].vote.decompteNominatif.{pours,contres,abstentions,nonVotants}.votant
     // + fallbacks pour variantes historiques/agrégées.
-    let org_nodes = one_or_many(&v["ventilationVotes"]["organe"]);
-
-    for org in &org_nodes {
-        let groupes = one_or_many(&org["groupes"]["groupe"])
-            .into_iter()
-            .chain(one_or_many(&org["groupes"]["organe"]))
-            .chain(one_or_many(&org["groupe"]))
-            .chain(one_or_many(&org["organe"]))
-            .collect::<Vec<_>>();
-
-        for groupe in &groupes {
-            let decompte = if groupe["vote"]["decompteNominatif"].is_object() {
-                &groupe["vote"]["decompteNominatif"]
-            } else if groupe["votes"].is_object() {
-                &groupe["votes"]
-            } else if groupe["vote"].is_object() {
-                &groupe["vote"]
+    for org in v.ventilation_votes.organe.iter() {
+        for groupe in org.all_groupes() {
+            let decompte = if groupe.vote.decompte_nominatif.is_object {
+                &*groupe.vote.decompte_nominatif
+            } else if groupe.votes.is_object {
+                &*groupe.votes
+            } else if groupe.vote.is_object {
+                &groupe.vote.direct
             } else {
                 continue;
             };
 
+            let groupe_ref = groupe.organe_ref.as_str();
+            // Décompte nominatif du groupe, utilisé si decompteVoix est absent
+            let mut nominatif = [0u32; 4];
+
             let cats = [
-                ("pours", VotePosition::Pour),
-                ("pour", VotePosition::Pour),
-                ("contres", VotePosition::Contre),
-                ("contre", VotePosition::Contre),
-                ("abstentions", VotePosition::Abstention),
-                ("abstention", VotePosition::Abstention),
-                ("nonVotants", VotePosition::NonVotant),
-                ("nonVotant", VotePosition::NonVotant),
+                (&decompte.pours, VotePosition::Pour),
+                (&decompte.pour, VotePosition::Pour),
+                (&decompte.contres, VotePosition::Contre),
+                (&decompte.contre, VotePosition::Contre),
+                (&decompte.abstentions, VotePosition::Abstention),
+                (&decompte.abstention, VotePosition::Abstention),
+                (&decompte.non_votants, VotePosition::NonVotant),
+                (&decompte.non_votant, VotePosition::NonVotant),
             ];
 
-            for (cat, pos) in &cats {
-                let bucket = &decompte[*cat];
-                if bucket.is_null() {
-                    continue;
-                }
-
-                let votants = one_or_many(&bucket["votant"])
-                    .into_iter()
-                    .chain(one_or_many(&bucket["votants"]["votant"]))
-                    .collect::<Vec<_>>();
-
+            for (bucket, pos) in &cats {
+                let votants = bucket.votant.iter().chain(bucket.votants.votant.iter());
                 for votant in votants {
-                    let dep_id = votant["acteurRef"].as_str()
-                        .or_else(|| votant["acteur"]["acteurRef"].as_str())
-                        .or_else(|| votant["acteur"]["uid"].as_str())
-                        .or_else(|| votant["uid"].as_str());
-                    if let Some(dep_id) = dep_id {
+                    nominatif[vote_position_slot(pos)] += 1;
+                    if let Some(dep_id) = votant.acteur_ref() {
                         votes.insert(dep_id.to_string(), pos.clone());
+                        if let Some(groupe_ref) = groupe_ref {
+                            groupe_votants.insert(dep_id.to_string(), groupe_ref.to_string());
+                        }
+                        if votant.par_delegation.as_bool() {
+                            par_delegation.push(dep_id.to_string());
+                        }
                     }
                 }
             }
+
+            if let Some(organe_ref) = groupe_ref {
+                let voix = &groupe.vote.decompte_voix;
+                let count = |keys: [&Scalar; 2], slot: usize| {
+                    keys.iter()
+                        .find_map(|k| k.as_u32())
+                        .unwrap_or(nominatif[slot])
+                };
+                scrutin_groupes.push(ScrutinGroupe {
+                    organe_ref: organe_ref.to_string(),
+                    nombre_membres: groupe.nombre_membres_groupe.as_u32(),
+                    position_majoritaire: groupe.vote.position_majoritaire
+                        .as_str()
+                        .and_then(parse_position_majoritaire),
+                    pour: count([&voix.pour, &voix.pours], 0),
+                    contre: count([&voix.contre, &voix.contres], 1),
+                    abstentions: count([&voix.abstentions, &voix.abstention], 2),
+                    non_votants: count([&voix.non_votants, &voix.non_votant], 3),
+                });
+            }
         }
     }
 
-    Some(Scrutin { id, numero, titre, date, sort, dossier_ref, votes })
-}
-
-fn parse_amendements(dir: &Path) -> Result<Vec<Amendement>> {
-    if !dir.exists() {
-        return Ok(vec![]);
-    }
-    let mut amendements = Vec::new();
-    for entry in std::fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.extension().and_then(|e| e.to_str()) == Some("json") {
-            match parse_amendement_file(&path) {
-                Ok(mut v) => amendements.append(&mut v),
-                Err(e) => warn!("Amendement file {:?}: {e}", path),
+    par_delegation.sort();
+    par_delegation.dedup();
+
+    // miseAuPoint : position que le député déclare avoir voulu exprimer (après la clôture du vote).
+    // Les corrections pour dysfonctionnement du système de vote ont la même structure.
+    let mut mises_au_point: HashMap<String, VotePosition> = HashMap::new();
+    let map_node = &*v.mise_au_point;
+    let dysfonctionnement = map_node.dysfonctionnement.as_deref().map(|n| &**n);
+    for node in std::iter::once(map_node).chain(dysfonctionnement) {
+        let cats = [
+            (&node.pours, VotePosition::Pour),
+            (&node.contres, VotePosition::Contre),
+            (&node.abstentions, VotePosition::Abstention),
+            (&node.non_votants, VotePosition::NonVotant),
+            (&node.non_votants_volontaires, VotePosition::NonVotant),
+        ];
+        for (bucket, pos) in &cats {
+            for votant in bucket.votant.iter() {
+                if let Some(dep_id) = votant.acteur_ref() {
+                    mises_au_point.insert(dep_id.to_string(), pos.clone());
+                }
             }
         }
     }
-    Ok(amendements)
+
+    Some(Scrutin {
+        id,
+        numero,
+        titre,
+        date,
+        sort,
+        dossier_ref,
+        type_vote,
+        type_vote_libelle,
+        votes,
+        groupes: scrutin_groupes,
+        groupe_votants,
+        mises_au_point,
+        par_delegation,
+    })
 }
 
-fn parse_amendement_file(path: &Path) -> Result<Vec<Amendement>> {
-    let data = std::fs::read_to_string(path)?;
-    let root: serde_json::Value = serde_json::from_str(&data)?;
+/// Chaîne AN non vide (après trim).
+fn non_empty(v: &Scalar) -> Option<String> {
+    v.as_str()
+        .map(str::trim)
+        .filter(|s| !s.is_empty() && *s != "null")
+        .map(String::from)
+}
 
-    let arr = one_or_many(&root["amendements"]["amendement"])
-        .into_iter()
-        .chain(one_or_many(&root["amendement"]))
-        .collect::<Vec<_>>();
+fn vote_position_slot(pos: &VotePosition) -> usize {
+    match pos {
+        VotePosition::Pour => 0,
+        VotePosition::Contre => 1,
+        VotePosition::Abstention => 2,
+        VotePosition::NonVotant | VotePosition::Absent => 3,
+    }
+}
 
-    if arr.is_empty() {
-        return Ok(vec![]);
+/// `positionMajoritaire` AN : "pour", "contre", "abstention" (casse variable).
+fn parse_position_majoritaire(raw: &str) -> Option<VotePosition> {
+    match raw.trim().to_lowercase().as_str() {
+        "pour" => Some(VotePosition::Pour),
+        "contre" => Some(VotePosition::Contre),
+        "abstention" | "abstentions" => Some(VotePosition::Abstention),
+        "nonvotant" | "nonvotants" | "non votant" => Some(VotePosition::NonVotant),
+        _ => None,
     }
+}
 
-    // Dédupliquer par uid
-    let mut seen = std::collections::HashSet::new();
-    let arr: Vec<_> = arr.into_iter()
-        .filter(|a| {
-            if let Some(uid) = a["uid"].as_str() {
-                seen.insert(uid.to_string())
-            } else {
-                true
+fn parse_amendements(src: &DatasetLocation) -> Result<(Vec<Amendement>, Vec<ParseFailure>)> {
+    if !src.exists() {
+        return Ok((vec![], vec![]));
+    }
+    let mut amendements = Vec::new();
+    let mut failures = Vec::new();
+    src.read_json(|name, reader| {
+        match parse_amendement_reader(reader) {
+            Ok(mut v) => amendements.append(&mut v),
+            Err(e) => {
+                warn!("Amendement file {name}: {e}");
+                failures.push(parse_failure("amendements", name, e));
             }
-        })
-        .collect();
+        }
+        Ok(())
+    })?;
+    Ok((amendements, failures))
+}
 
-    Ok(arr.iter().filter_map(parse_amendement).collect())
+/// Amendements d'un document AN (`amendements.amendement` et/ou `amendement`), convertis au fil
+/// de la lecture : le dump complet n'est jamais matérialisé en `serde_json::Value`.
+fn parse_amendement_reader(reader: impl Read) -> Result<Vec<Amendement>> {
+    // Dédupliquer par uid
+    let mut seen = HashSet::new();
+    let mut amendements = Vec::new();
+    an_json::stream_elements(reader, "amendements", "amendement", |raw: AmendementAn| {
+        if let Some(amendement) = parse_amendement(&raw) {
+            if seen.insert(amendement.id.clone()) {
+                amendements.push(amendement);
+            }
+        }
+    })?;
+    Ok(amendements)
 }
 
-fn parse_amendement(v: &serde_json::Value) -> Option<Amendement> {
-    let id = v["uid"].as_str()?.to_string();
-    let numero = v["identificatif"]["numero"].as_str().map(String::from);
+fn parse_amendement(v: &AmendementAn) -> Option<Amendement> {
+    let id = v.uid.to_string_opt()?;
+    let numero = v.identificatif.numero.to_string_opt();
+    let cycle = &v.cycle_de_vie;
+    let etats = &cycle.etat_des_traitements;
 
     // Chaîne de fallback pour le sort : plusieurs emplacements possibles dans le JSON AN
     // IMPORTANT: dans les exports multi-fichiers 17e législature, `cycleDeVie.sort` est souvent
     // une chaîne directe (ex: "Adopté", "Rejeté"), pas un objet `{ value: ... }`.
     // Si on lit `etat.libelle` avant, on récupère souvent "Discuté" => adopte=false partout.
-    let sort_val = v["cycleDeVie"]["sort"].as_str().map(String::from)
-        .or_else(|| v["cycleDeVie"]["sort"]["value"].as_str().map(String::from))
-        .or_else(|| v["cycleDeVie"]["etatDesTraitements"]["sousEtat"]["libelle"].as_str().map(String::from))
-        .or_else(|| v["cycleDeVie"]["etatDesTraitements"]["sousEtat"]["code"].as_str().map(String::from))
-        .or_else(|| v["cycleDeVie"]["etatDesTraitements"]["etat"]["libelle"].as_str().map(String::from))
-        .or_else(|| v["cycleDeVie"]["etatDesTraitements"]["etat"]["code"].as_str().map(String::from));
+    let sort_val = cycle.sort.text.clone()
+        .or_else(|| cycle.sort.value.to_string_opt())
+        .or_else(|| etats.sous_etat.libelle.to_string_opt())
+        .or_else(|| etats.sous_etat.code.to_string_opt())
+        .or_else(|| etats.etat.libelle.to_string_opt())
+        .or_else(|| etats.etat.code.to_string_opt());
 
     let adopte = sort_val.as_deref()
         .map(|s| {
@@ -961,43 +1281,27 @@ fn parse_amendement(v: &serde_json::Value) -> Option<Amendement> {
         .unwrap_or(false);
 
     // auteur principal — peut être sous plusieurs clés
-    let auteur_id = v["signataires"]["auteur"]["acteurRef"].as_str()
-        .or_else(|| v["signataires"]["signataire"]["acteurRef"].as_str())
-        .map(String::from);
+    let signataires = &v.signataires;
+    let auteur_id = signataires.auteur.acteur_ref.to_string_opt()
+        .or_else(|| signataires.signataire.acteur_ref.to_string_opt());
 
     // typeAuteur (ex: "Député", "Groupe")
-    let auteur_type = v["signataires"]["auteur"]["typeAuteur"].as_str()
-        .or_else(|| v["signataires"]["signataire"]["typeAuteur"].as_str())
-        .map(String::from);
+    let auteur_type = signataires.auteur.type_auteur.to_string_opt()
+        .or_else(|| signataires.signataire.type_auteur.to_string_opt());
 
     // cosignataires (schéma 17e législature observé):
     // signataires.cosignataires.acteurRef = "PA..." | ["PA...", ...]
     // + fallback historique: signataires.cosignataires.cosignataire[].acteurRef
-    let mut cosignataires_ids: Vec<String> = Vec::new();
-    let cosignataires = &v["signataires"]["cosignataires"];
-
-    match &cosignataires["acteurRef"] {
-        serde_json::Value::String(s) => {
-            let s = s.trim();
-            if !s.is_empty() {
-                cosignataires_ids.push(s.to_string());
-            }
-        }
-        serde_json::Value::Array(arr) => {
-            for id in arr.iter().filter_map(|x| x.as_str()) {
-                let id = id.trim();
-                if !id.is_empty() {
-                    cosignataires_ids.push(id.to_string());
-                }
-            }
-        }
-        _ => {}
-    }
+    let cosignataires = &signataires.cosignataires;
+    let mut cosignataires_ids: Vec<String> = cosignataires.acteur_ref.0.iter()
+        .map(|id| id.trim())
+        .filter(|id| !id.is_empty())
+        .map(String::from)
+        .collect();
 
     if cosignataires_ids.is_empty() {
-        cosignataires_ids = one_or_many(&cosignataires["cosignataire"])
-            .into_iter()
-            .filter_map(|c| c["acteurRef"].as_str().map(String::from))
+        cosignataires_ids = cosignataires.cosignataire.iter()
+            .filter_map(|c| c.acteur_ref.to_string_opt())
             .collect();
     }
 
@@ -1005,58 +1309,47 @@ fn parse_amendement(v: &serde_json::Value) -> Option<Amendement> {
     cosignataires_ids.dedup();
 
     // Dates structurées (si présentes)
-    let date_depot = v["cycleDeVie"]["dateDepot"].as_str().and_then(parse_date);
-    let date_circulation = v["cycleDeVie"]["dateCirculation"].as_str().and_then(parse_date);
-    let date_sort = v["cycleDeVie"]["dateSort"].as_str().and_then(parse_date);
-    let date_examen = v["cycleDeVie"]["dateExamen"].as_str().and_then(parse_date);
+    let date_depot = cycle.date_depot.as_str().and_then(parse_date);
+    let date_circulation = cycle.date_circulation.as_str().and_then(parse_date);
+    let date_sort = cycle.date_sort.as_str().and_then(parse_date);
+    let date_examen = cycle.date_examen.as_str().and_then(parse_date);
 
     // Date best-effort : chaîne de fallback étendue — le dataset AN est très irrégulier sur ce point
     let date = date_depot
         .or(date_circulation)
         .or(date_sort)
         .or(date_examen)
-        .or_else(|| {
-            // Fallback: chercher n'importe quelle date dans cycleDeVie
-            if let Some(obj) = v["cycleDeVie"].as_object() {
-                for (_, val) in obj {
-                    if let Some(s) = val.as_str() {
-                        if let Some(d) = parse_date(s) {
-                            return Some(d);
-                        }
-                    }
-                }
-            }
-            None
-        });
+        // Fallback: chercher n'importe quelle date dans cycleDeVie
+        .or_else(|| cycle.autres.values().find_map(|val| val.as_str().and_then(parse_date)));
 
     // pointeurFragmentTexte : peut se trouver sous amendement.pointeurFragmentTexte,
     // corps.pointeurFragmentTexte, ou directement pointeurFragmentTexte selon le format AN.
-    let pft = if v["amendement"]["pointeurFragmentTexte"].is_object() {
-        &v["amendement"]["pointeurFragmentTexte"]
-    } else if v["corps"]["pointeurFragmentTexte"].is_object() {
-        &v["corps"]["pointeurFragmentTexte"]
+    let pft = if v.amendement.pointeur_fragment_texte.is_object {
+        &v.amendement.pointeur_fragment_texte
+    } else if v.corps.pointeur_fragment_texte.is_object {
+        &v.corps.pointeur_fragment_texte
     } else {
-        &v["pointeurFragmentTexte"]
+        &v.pointeur_fragment_texte
     };
 
-    let dossier_ref = v["dossierRef"].as_str().map(String::from)
-        .or_else(|| pft["texteLegislatifRef"].as_str().map(String::from));
-    let article = pft["division"]["titre"].as_str().map(String::from);
-    let texte_ref = pft["texteLegislatifRef"].as_str().map(String::from);
+    let dossier_ref = v.dossier_ref.to_string_opt()
+        .or_else(|| pft.texte_legislatif_ref.to_string_opt());
+    let article = pft.division.titre.to_string_opt();
+    let texte_ref = pft.texte_legislatif_ref.to_string_opt();
 
     // Mission visée
-    let mission_visee = pft["missionVisee"]["libelleMission"].as_str().map(String::from);
-    let mission_ref = pft["missionVisee"]["missionRef"].as_str().map(String::from);
+    let mission_visee = pft.mission_visee.libelle_mission.to_string_opt();
+    let mission_ref = pft.mission_visee.mission_ref.to_string_opt();
 
     // Exposé sommaire — nettoyé (HTML strippé, whitespace collapsé)
-    let expose_sommaire = v["exposeSommaire"].as_str()
-        .or_else(|| v["corps"]["contenuAuteur"]["exposeSommaire"].as_str())
+    let expose_sommaire = v.expose_sommaire.as_str()
+        .or_else(|| v.corps.contenu_auteur.expose_sommaire.as_str())
         .map(|s| normalize_expose_sommaire(s, EXPOSE_MAX_CHARS))
         .filter(|s| !s.is_empty())
         .or_else(|| {
             // Fallback : certains amendements retirés avant publication n'ont pas d'exposé
             // mais portent l'information dans corps.cartoucheInformatif.
-            v["corps"]["cartoucheInformatif"].as_str()
+            v.corps.cartouche_informatif.as_str()
                 .map(|s| normalize_expose_sommaire(s, EXPOSE_MAX_CHARS))
                 .filter(|s| s.to_lowercase().contains("retiré avant publication"))
         });
@@ -1083,33 +1376,14 @@ fn parse_amendement(v: &serde_json::Value) -> Option<Amendement> {
     })
 }
 
-fn parse_dossiers(dir: &Path) -> Result<HashMap<String, Dossier>> {
-    if !dir.exists() {
-        return Ok(HashMap::new());
+fn parse_dossiers(src: &DatasetLocation) -> Result<(HashMap<String, Dossier>, Vec<ParseFailure>)> {
+    if !src.exists() {
+        return Ok((HashMap::new(), vec![]));
     }
     let mut dossiers = HashMap::new();
-    for entry in std::fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.extension().and_then(|e| e.to_str()) != Some("json") {
-            continue;
-        }
-
-        let data = match std::fs::read_to_string(&path) {
-            Ok(s) => s,
-            Err(e) => {
-                warn!("Dossier: lecture échouée {:?}: {e}", path);
-                continue;
-            }
-        };
-
-        let root: serde_json::Value = match serde_json::from_str(&data) {
-            Ok(v) => v,
-            Err(e) => {
-                warn!("Dossier: JSON invalide {:?}: {e}", path);
-                continue;
-            }
-        };
+    let mut failures = Vec::new();
+    src.read_json(|name, reader| {
+        let Some(root) = read_entry_json("dossiers", "Dossier", name, reader, &mut failures) else { return Ok(()) };
 
         // Supporte:
         // 1) format agrégé: {"dossiers":{"dossier":[...]}}
@@ -1130,8 +1404,9 @@ fn parse_dossiers(dir: &Path) -> Result<HashMap<String, Dossier>> {
                 dossiers.insert(dossier.id.clone(), dossier);
             }
         }
-    }
-    Ok(dossiers)
+        Ok(())
+    })?;
+    Ok((dossiers, failures))
 }
 
 fn collect_actor_refs_from_value(v: &serde_json::Value, out: &mut Vec<String>) {
@@ -1355,6 +1630,11 @@ fn parse_dossier(v: &serde_json::Value) -> Option<Dossier> {
 
     let (origin_chamber, initiateur_organe_ref) = detect_dossier_origin(v);
     let (auteur_id, cosignataires_ids) = parse_dossier_signers(v);
+    let mut rapporteurs = Vec::new();
+    collect_rapporteurs(&v["actesLegislatifs"], &mut rapporteurs);
+    for r in rapporteurs.iter_mut().filter(|r| r.date.is_none()) {
+        r.date = date_depot;
+    }
 
     Some(Dossier {
         id,
@@ -1369,9 +1649,246 @@ fn parse_dossier(v: &serde_json::Value) -> Option<Dossier> {
         source_url,
         origin_chamber,
         initiateur_organe_ref,
+        rapporteurs,
+    })
+}
+
+/// Rapporteurs des actes législatifs (arborescence `actesLegislatifs.acteLegislatif`, actes de
+/// nomination `…-NOMIN`), dédoublonnés par député, rôle et commission.
+fn collect_rapporteurs(v: &serde_json::Value, out: &mut Vec<Rapporteur>) {
+    match v {
+        serde_json::Value::Array(arr) => {
+            for item in arr {
+                collect_rapporteurs(item, out);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            let code_acte = v["codeActe"].as_str().unwrap_or_default();
+            for r in one_or_many(&v["rapporteurs"]["rapporteur"]) {
+                let Some(acteur_id) = opt_non_empty_str(&r["acteurRef"]) else { continue };
+                let type_rapporteur = opt_non_empty_str(&r["typeRapporteur"]).unwrap_or_default();
+                let rapporteur = Rapporteur {
+                    acteur_id,
+                    role: rapporteur_role(&type_rapporteur, code_acte).to_string(),
+                    organe_ref: opt_non_empty_str(&v["organeRef"]),
+                    date: v["dateActe"].as_str().and_then(parse_date),
+                };
+                let doublon = out.iter().any(|o| {
+                    o.acteur_id == rapporteur.acteur_id && o.role == rapporteur.role && o.organe_ref == rapporteur.organe_ref
+                });
+                if !doublon {
+                    out.push(rapporteur);
+                }
+            }
+            for (k, child) in map {
+                if k != "rapporteurs" && (child.is_object() || child.is_array()) {
+                    collect_rapporteurs(child, out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Rôle d'après `typeRapporteur` (« rapporteur », « rapporteur pour avis », « rapporteur
+/// spécial »…), à défaut d'après le code de l'acte (`AN1-COM-AVIS-NOMIN`).
+fn rapporteur_role(type_rapporteur: &str, code_acte: &str) -> &'static str {
+    let t = type_rapporteur.to_lowercase();
+    if t.contains("spécial") || t.contains("special") {
+        "special"
+    } else if t.contains("avis") || code_acte.contains("-AVIS") {
+        "avis"
+    } else {
+        "fond"
+    }
+}
+
+/// Interventions en séance des comptes rendus Syceron (un fichier XML par compte rendu).
+/// Source optionnelle : absente, aucune intervention.
+fn parse_debats(src: &DatasetLocation) -> Result<(Vec<Intervention>, Vec<ParseFailure>)> {
+    if !src.exists() {
+        info!("Débats: dataset absent ({src}) — interventions non renseignées");
+        return Ok((vec![], vec![]));
+    }
+    let names = src.xml_entries()?;
+    let mut interventions = Vec::new();
+    let mut failures = Vec::new();
+    let mut comptes_rendus = 0usize;
+    src.read_entries(&names, |name, reader| {
+        let mut xml = String::new();
+        let parsed = reader
+            .read_to_string(&mut xml)
+            .map_err(anyhow::Error::from)
+            .and_then(|_| syceron::parse_compte_rendu(&xml));
+        match parsed {
+            Ok(mut v) => {
+                comptes_rendus += 1;
+                interventions.append(&mut v);
+            }
+            Err(e) => {
+                warn!("Compte rendu {name}: {e}");
+                failures.push(parse_failure("debats", name, e));
+            }
+        }
+        Ok(())
+    })?;
+
+    info!(
+        "Débats: {} comptes rendus, {} interventions extraites",
+        comptes_rendus,
+        interventions.len()
+    );
+    Ok((interventions, failures))
+}
+
+/// Sources de questions et type attribué quand le document ne le précise pas.
+const QUESTION_SOURCES: [(&str, &str); 3] = [
+    ("questions_ecrites", "QE"),
+    ("questions_orales", "QOSD"),
+    ("questions_gouvernement", "QAG"),
+];
+
+/// Questions d'un dataset (un fichier JSON par question). Source optionnelle : absente, aucune
+/// question.
+fn parse_questions(src: &DatasetLocation, key: &str, type_question: &str) -> Result<(Vec<Question>, Vec<ParseFailure>)> {
+    if !src.exists() {
+        info!("{key}: dataset absent ({src}) — questions non renseignées");
+        return Ok((vec![], vec![]));
+    }
+    let mut seen = HashSet::new();
+    let mut questions = Vec::new();
+    let mut failures = Vec::new();
+    src.read_json(|name, reader| {
+        let result = an_json::stream_elements(reader, "questions", "question", |raw: QuestionAn| {
+            if let Some(q) = parse_question(&raw, type_question) {
+                if seen.insert(q.id.clone()) {
+                    questions.push(q);
+                }
+            }
+        });
+        if let Err(e) = result {
+            warn!("Question file {name}: {e}");
+            failures.push(parse_failure(key, name, e));
+        }
+        Ok(())
+    })?;
+    let repondues = questions.iter().filter(|q| q.repondue).count();
+    info!("{key}: {} questions ({} avec réponse)", questions.len(), repondues);
+    Ok((questions, failures))
+}
+
+fn parse_question(v: &QuestionAn, type_par_defaut: &str) -> Option<Question> {
+    let id = non_empty(&v.uid)?;
+    let auteur_id = non_empty(&v.auteur.identite.acteur_ref)?;
+    // L'AN code les questions au Gouvernement `QG`
+    let type_question = match non_empty(&v.type_question).as_deref() {
+        Some("QG" | "QAG") => "QAG",
+        Some("QE") => "QE",
+        Some("QOSD") => "QOSD",
+        _ => type_par_defaut,
+    };
+
+    let date_jo = |t: &Node<TexteJo>| t.info_jo.date_jo.as_str().and_then(parse_date);
+    let date_question = v.textes_question.texte_question.iter().filter_map(date_jo).min();
+    let date_reponse = v.textes_reponse.texte_reponse.iter().filter_map(date_jo).min();
+    let cloture = non_empty(&v.cloture.libelle_cloture);
+    let repondue = date_reponse.is_some()
+        || non_empty(&v.cloture.code_cloture).as_deref() == Some("REP_PUB")
+        || cloture.as_deref().is_some_and(|c| c.to_lowercase().contains("réponse"));
+
+    let titre = v
+        .indexation_an
+        .analyses
+        .analyse
+        .0
+        .iter()
+        .map(|a| a.trim())
+        .find(|a| !a.is_empty())
+        .map(String::from)
+        .or_else(|| non_empty(&v.indexation_an.tete_analyse));
+
+    Some(Question {
+        id,
+        type_question: type_question.to_string(),
+        numero: v.identifiant.numero.as_u32(),
+        auteur_id,
+        ministere: non_empty(&v.min_int.developpe).or_else(|| non_empty(&v.min_int.abrege)),
+        rubrique: non_empty(&v.indexation_an.rubrique),
+        titre,
+        date_question,
+        date_reponse,
+        repondue,
+        cloture,
+    })
+}
+
+/// Réunions de l'agenda AN (un fichier JSON par réunion) ayant une liste de présence. Source
+/// optionnelle : absente, aucune réunion.
+fn parse_reunions(src: &DatasetLocation) -> Result<(Vec<Reunion>, Vec<ParseFailure>)> {
+    if !src.exists() {
+        info!("Réunions: dataset absent ({src}) — présences en commission non renseignées");
+        return Ok((vec![], vec![]));
+    }
+    let mut seen = HashSet::new();
+    let mut reunions = Vec::new();
+    let mut failures = Vec::new();
+    src.read_json(|name, reader| {
+        let result = an_json::stream_elements(reader, "reunions", "reunion", |raw: ReunionAn| {
+            if let Some(r) = parse_reunion(&raw) {
+                if seen.insert(r.id.clone()) {
+                    reunions.push(r);
+                }
+            }
+        });
+        if let Err(e) = result {
+            warn!("Réunion file {name}: {e}");
+            failures.push(parse_failure("reunions", name, e));
+        }
+        Ok(())
+    })?;
+    let presences: usize = reunions.iter().map(|r| r.participants.len()).sum();
+    info!("Réunions: {} réunions avec présences, {} convocations", reunions.len(), presences);
+    Ok((reunions, failures))
+}
+
+/// Réunion tenue avec au moins un participant dont la présence est connue ; les réunions
+/// annulées ou supprimées, et les séances publiques (sans liste de présence), sont écartées.
+fn parse_reunion(v: &ReunionAn) -> Option<Reunion> {
+    let id = non_empty(&v.uid)?;
+    let etat = non_empty(&v.cycle_de_vie.etat).unwrap_or_default().to_lowercase();
+    if etat.starts_with("annul") || etat.starts_with("supprim") {
+        return None;
+    }
+
+    let mut participants: Vec<(String, Presence)> = Vec::new();
+    for p in v.participants.participants_internes.participant_interne.iter() {
+        let Some(acteur) = non_empty(&p.acteur_ref) else { continue };
+        let Some(presence) = p.presence.as_str().and_then(parse_presence) else { continue };
+        if !participants.iter().any(|(a, _)| *a == acteur) {
+            participants.push((acteur, presence));
+        }
+    }
+    if participants.is_empty() {
+        return None;
+    }
+
+    Some(Reunion {
+        id,
+        date: v.time_stamp_debut.as_str().and_then(parse_date),
+        organe_id: non_empty(&v.organe_reuni_ref),
+        participants,
     })
 }
 
+fn parse_presence(s: &str) -> Option<Presence> {
+    match s.trim().to_lowercase().as_str() {
+        "présent" | "present" | "présente" | "presente" => Some(Presence::Present),
+        "absent" | "absente" => Some(Presence::Absent),
+        "excusé" | "excuse" | "excusée" | "excusee" => Some(Presence::Excuse),
+        _ => None,
+    }
+}
+
 fn parse_date(s: &str) -> Option<NaiveDate> {
     let s = s.trim();
     if s.is_empty() || s == "null" {
@@ -1393,6 +1910,11 @@ fn parse_date(s: &str) -> Option<NaiveDate> {
 mod tests {
     use super::*;
 
+    /// Élément AN typé construit depuis un littéral JSON.
+    fn from_value<T: serde::de::DeserializeOwned>(json: serde_json::Value) -> T {
+        serde_json::from_value(json).expect("élément AN valide")
+    }
+
     // ─── parse_date ────────────────────────────────────────────────────────
     #[test]
     fn parse_date_iso() {
@@ -1532,7 +2054,7 @@ mod tests {
             "exposeSommaire": "<p>Cet amendement <b>vise</b> à clarifier.</p>"
         });
 
-        let result = parse_amendement(&json).expect("should parse");
+        let result = parse_amendement(&from_value(json)).expect("should parse");
         assert_eq!(result.id, "AMANR5L17PO123456-1");
         assert_eq!(result.numero, Some("42".to_string()));
         assert_eq!(result.auteur_id, Some("PA1234".to_string()));
@@ -1553,7 +2075,7 @@ mod tests {
         let json = serde_json::json!({
             "uid": "AMD-MINIMAL"
         });
-        let result = parse_amendement(&json).expect("should parse minimal");
+        let result = parse_amendement(&from_value(json)).expect("should parse minimal");
         assert_eq!(result.id, "AMD-MINIMAL");
         assert_eq!(result.auteur_id, None);
         assert_eq!(result.cosignataires_ids, Vec::<String>::new());
@@ -1571,7 +2093,7 @@ mod tests {
                 }
             }
         });
-        let result = parse_amendement(&json).expect("should parse nested expose");
+        let result = parse_amendement(&from_value(json)).expect("should parse nested expose");
         assert_eq!(result.expose_sommaire, Some("Texte sous contenuAuteur.".to_string()));
     }
 
@@ -1585,7 +2107,7 @@ mod tests {
                 "cartoucheInformatif": "Retiré avant publication"
             }
         });
-        let result = parse_amendement(&json).expect("should parse");
+        let result = parse_amendement(&from_value(json)).expect("should parse");
         assert_eq!(result.expose_sommaire, Some("Retiré avant publication".to_string()));
     }
 
@@ -1599,7 +2121,7 @@ mod tests {
                 "cartoucheInformatif": "Retiré avant publication"
             }
         });
-        let result = parse_amendement(&json).expect("should parse");
+        let result = parse_amendement(&from_value(json)).expect("should parse");
         assert_eq!(result.expose_sommaire, Some("Un vrai exposé".to_string()));
     }
 
@@ -1616,8 +2138,411 @@ mod tests {
                 }
             }
         });
-        let result = parse_amendement(&json).expect("should parse");
+        let result = parse_amendement(&from_value(json)).expect("should parse");
         assert_eq!(result.sort, Some("A discuter".to_string()));
         assert!(!result.adopte);
     }
+
+    // ─── parse_scrutin ─────────────────────────────────────────────────────
+    #[test]
+    fn parse_scrutin_extracts_group_breakdown() {
+        let json = serde_json::json!({
+            "uid": "VTANR5L17V42",
+            "numero": "42",
+            "dateScrutin": "2024-10-15",
+            "sort": { "code": "adopté" },
+            "typeVote": { "codeTypeVote": "SPS", "libelleTypeVote": "scrutin public solennel" },
+            "ventilationVotes": { "organe": { "groupes": { "groupe": [
+                {
+                    "organeRef": "PO1",
+                    "nombreMembresGroupe": "3",
+                    "vote": {
+                        "positionMajoritaire": "pour",
+                        "decompteVoix": { "pour": "2", "contre": "1", "abstentions": "0", "nonVotants": "0" },
+                        "decompteNominatif": {
+                            "pours": { "votant": [ { "acteurRef": "PA1" }, { "acteurRef": "PA2", "parDelegation": "true" } ] },
+                            "contres": { "votant": { "acteurRef": "PA3" } },
+                            "abstentions": null,
+                            "nonVotants": null
+                        }
+                    }
+                },
+                {
+                    "organeRef": "PO2",
+                    "vote": {
+                        "positionMajoritaire": "Contre",
+                        "decompteNominatif": {
+                            "contres": { "votant": [ { "acteurRef": "PA4" }, { "acteurRef": "PA5" } ] }
+                        }
+                    }
+                }
+            ] } } },
+            "miseAuPoint": {
+                "pours": null,
+                "contres": { "votant": { "acteurRef": "PA1", "parDelegation": "false" } },
+                "dysfonctionnement": {
+                    "abstentions": { "votant": [ { "acteurRef": "PA6" } ] }
+                }
+            }
+        });
+
+        let result = parse_scrutin(&from_value(json)).expect("should parse");
+        assert_eq!(result.type_vote.as_deref(), Some("SPS"));
+        assert_eq!(result.par_delegation, vec!["PA2".to_string()]);
+        assert_eq!(result.mises_au_point.len(), 2);
+        assert_eq!(result.mises_au_point.get("PA1"), Some(&VotePosition::Contre));
+        assert_eq!(result.mises_au_point.get("PA6"), Some(&VotePosition::Abstention));
+        assert_eq!(result.votes.len(), 5);
+        assert_eq!(result.votes.get("PA3"), Some(&VotePosition::Contre));
+        assert_eq!(result.groupe_votants.get("PA4").map(String::as_str), Some("PO2"));
+        assert_eq!(result.groupes.len(), 2);
+
+        let g1 = &result.groupes[0];
+        assert_eq!(g1.organe_ref, "PO1");
+        assert_eq!(g1.nombre_membres, Some(3));
+        assert_eq!(g1.position_majoritaire, Some(VotePosition::Pour));
+        assert_eq!((g1.pour, g1.contre, g1.abstentions, g1.non_votants), (2, 1, 0, 0));
+
+        // Sans decompteVoix : repli sur le décompte nominatif
+        let g2 = &result.groupes[1];
+        assert_eq!(g2.position_majoritaire, Some(VotePosition::Contre));
+        assert_eq!((g2.pour, g2.contre, g2.abstentions, g2.non_votants), (0, 2, 0, 0));
+    }
+
+    // ─── parse_dossier ─────────────────────────────────────────────────────
+    #[test]
+    fn parse_dossier_extracts_rapporteurs() {
+        let json = serde_json::json!({
+            "uid": "DLR5L17N50000",
+            "titreDossier": { "titre": "Projet de loi de finances pour 2025", "dateDepot": "2024-10-10" },
+            "actesLegislatifs": { "acteLegislatif": {
+                "codeActe": "AN1",
+                "actesLegislatifs": { "acteLegislatif": [
+                    { "codeActe": "AN1-COM",
+                      "actesLegislatifs": { "acteLegislatif": [
+                          { "codeActe": "AN1-COM-FOND-NOMIN", "organeRef": "PO59048",
+                            "dateActe": "2024-10-02T00:00:00.000+02:00",
+                            "rapporteurs": { "rapporteur": [
+                                { "acteurRef": "PA1", "typeRapporteur": "rapporteur" },
+                                { "acteurRef": "PA2", "typeRapporteur": "rapporteur spécial" }
+                            ] } },
+                          { "codeActe": "AN1-COM-AVIS-NOMIN", "organeRef": "PO419604",
+                            "rapporteurs": { "rapporteur": { "acteurRef": "PA3", "typeRapporteur": null } } },
+                          // Nomination répétée (même rôle, même commission)
+                          { "codeActe": "AN1-COM-FOND-NOMIN", "organeRef": "PO59048",
+                            "dateActe": "2024-10-09T00:00:00.000+02:00",
+                            "rapporteurs": { "rapporteur": { "acteurRef": "PA1", "typeRapporteur": "rapporteur" } } }
+                      ] } }
+                ] }
+            } }
+        });
+
+        let dossier = parse_dossier(&json).expect("should parse");
+        let got: Vec<(&str, &str, Option<&str>, Option<NaiveDate>)> = dossier
+            .rapporteurs
+            .iter()
+            .map(|r| (r.acteur_id.as_str(), r.role.as_str(), r.organe_ref.as_deref(), r.date))
+            .collect();
+        assert_eq!(
+            got,
+            [
+                ("PA1", "fond", Some("PO59048"), NaiveDate::from_ymd_opt(2024, 10, 2)),
+                ("PA2", "special", Some("PO59048"), NaiveDate::from_ymd_opt(2024, 10, 2)),
+                // Sans date d'acte : date de dépôt du dossier
+                ("PA3", "avis", Some("PO419604"), NaiveDate::from_ymd_opt(2024, 10, 10)),
+            ]
+        );
+    }
+
+    // ─── parse_question ────────────────────────────────────────────────────
+    #[test]
+    fn parse_question_extracts_fields() {
+        let json = serde_json::json!({
+            "uid": "QANR5L17QG12",
+            "type": "QG",
+            "identifiant": { "numero": "12", "legislature": "17" },
+            "indexationAN": {
+                "rubrique": "énergie et carburants",
+                "analyses": { "analyse": ["", "Prix du carburant"] }
+            },
+            "auteur": { "identite": { "acteurRef": "PA1" } },
+            "minInt": { "abrege": "Économie", "developpe": "Économie, finances et souveraineté industrielle" },
+            "textesQuestion": { "texteQuestion": [
+                { "infoJO": { "typeJO": "JO_QUESTION", "dateJO": "2025-02-11" } },
+                { "infoJO": { "typeJO": "JO_QUESTION", "dateJO": "2025-02-04" } }
+            ] },
+            "textesReponse": null,
+            "cloture": { "codeCloture": "REP_PUB", "libelleCloture": "Réponse publiée" }
+        });
+
+        let q = parse_question(&from_value(json), "QE").expect("should parse");
+        assert_eq!(q.type_question, "QAG");
+        assert_eq!(q.numero, Some(12));
+        assert_eq!(q.auteur_id, "PA1");
+        assert_eq!(q.ministere.as_deref(), Some("Économie, finances et souveraineté industrielle"));
+        assert_eq!(q.rubrique.as_deref(), Some("énergie et carburants"));
+        assert_eq!(q.titre.as_deref(), Some("Prix du carburant"));
+        assert_eq!(q.date_question, NaiveDate::from_ymd_opt(2025, 2, 4));
+        assert_eq!(q.date_reponse, None);
+        assert!(q.repondue);
+    }
+
+    #[test]
+    fn parse_question_without_answer_or_author() {
+        let sans_reponse = serde_json::json!({
+            "uid": "QANR5L17QOSD3",
+            "auteur": { "identite": { "acteurRef": "PA2" } },
+            "cloture": { "codeCloture": "RET", "libelleCloture": "Retrait" }
+        });
+        let q = parse_question(&from_value(sans_reponse), "QOSD").expect("should parse");
+        assert_eq!(q.type_question, "QOSD");
+        assert!(!q.repondue);
+        assert_eq!(q.cloture.as_deref(), Some("Retrait"));
+
+        let sans_auteur = serde_json::json!({ "uid": "QANR5L17QE4" });
+        assert!(parse_question(&from_value(sans_auteur), "QE").is_none());
+    }
+
+    // ─── commissions ───────────────────────────────────────────────────────
+    #[test]
+    fn parse_depute_keeps_commission_memberships() {
+        let organe = |id: &str, code_type: &str, libelle: &str| {
+            (id.to_string(), Organe {
+                id: id.to_string(),
+                code_type: code_type.to_string(),
+                libelle: libelle.to_string(),
+                abrev: None,
+                couleur: None,
+            })
+        };
+        let organes: HashMap<String, Organe> = [
+            organe("PO1", "COMPER", "Commission des finances"),
+            organe("PO2", "CMP", "CMP projet de loi de finances"),
+            organe("PO3", "GP", "Groupe 1"),
+        ]
+        .into_iter()
+        .collect();
+        let json = serde_json::json!({
+            "uid": { "#text": "PA1" },
+            "etatCivil": { "ident": { "nom": "Dupont", "prenom": "Alice" } },
+            "mandats": { "mandat": [
+                { "typeOrgane": "CMP", "dateDebut": "2024-12-10", "dateFin": "2024-12-20",
+                  "infosQualite": { "codeQualite": "Membre titulaire" },
+                  "organes": { "organeRef": "PO2" } },
+                { "typeOrgane": "COMPER", "dateDebut": "2024-07-19", "dateFin": null,
+                  "infosQualite": { "codeQualite": "Rapporteur général" },
+                  "organes": { "organeRef": "PO1" } },
+                { "typeOrgane": "CNPE", "dateDebut": "2025-01-15",
+                  "infosQualite": { "libQualite": "Secrétaire" },
+                  "organes": { "organeRef": "PO9" } },
+                { "typeOrgane": "GP", "dateDebut": "2024-07-18",
+                  "infosQualite": { "codeQualite": "Membre" },
+                  "organes": { "organeRef": "PO3" } }
+            ] }
+        });
+
+        let dep = parse_depute(&json, &organes).expect("should parse");
+        let got: Vec<(&str, &str, &str)> = dep
+            .commissions
+            .iter()
+            .map(|c| (c.type_organe.as_str(), c.libelle.as_str(), c.qualite.as_str()))
+            .collect();
+        assert_eq!(
+            got,
+            [
+                ("COMPER", "Commission des finances", "Rapporteur général"),
+                // Organe absent du dataset : libellé = identifiant
+                ("CNPE", "PO9", "Secrétaire"),
+                ("CMP", "CMP projet de loi de finances", "Membre titulaire"),
+            ]
+        );
+        assert!(dep.commissions[0].has_role());
+        assert!(!dep.commissions[2].has_role());
+        assert_eq!(dep.commissions[2].date_fin, NaiveDate::from_ymd_opt(2024, 12, 20));
+        assert_eq!(dep.groupe_id.as_deref(), Some("PO3"));
+    }
+
+    #[test]
+    fn parse_depute_keeps_group_history() {
+        let organes: HashMap<String, Organe> = [("PO1", "GRP1"), ("PO2", "GRP2")]
+            .into_iter()
+            .map(|(id, abrev)| {
+                (id.to_string(), Organe {
+                    id: id.to_string(),
+                    code_type: "GP".to_string(),
+                    libelle: format!("Groupe {abrev}"),
+                    abrev: Some(abrev.to_string()),
+                    couleur: None,
+                })
+            })
+            .collect();
+        let json = serde_json::json!({
+            "uid": { "#text": "PA1" },
+            "etatCivil": { "ident": { "nom": "Dupont", "prenom": "Alice" } },
+            "mandats": { "mandat": [
+                { "typeOrgane": "GP", "dateDebut": "2025-03-01",
+                  "infosQualite": { "codeQualite": "Membre apparenté" },
+                  "organes": { "organeRef": "PO2" } },
+                { "typeOrgane": "GP", "dateDebut": "2024-07-18", "dateFin": "2025-02-28",
+                  "infosQualite": { "codeQualite": "Membre" },
+                  "organes": { "organeRef": "PO1" } }
+            ] }
+        });
+
+        let dep = parse_depute(&json, &organes).expect("should parse");
+        let got: Vec<(&str, bool, Option<NaiveDate>)> =
+            dep.groupes.iter().map(|g| (g.organe_id.as_str(), g.apparente, g.date_fin)).collect();
+        assert_eq!(
+            got,
+            [("PO1", false, NaiveDate::from_ymd_opt(2025, 2, 28)), ("PO2", true, None)]
+        );
+        assert_eq!(dep.groupe_id.as_deref(), Some("PO2"));
+        assert_eq!(dep.groupe_id_at(NaiveDate::from_ymd_opt(2024, 10, 1).unwrap()), Some("PO1"));
+        assert_eq!(dep.groupe_id_at(NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()), Some("PO2"));
+    }
+
+    #[test]
+    fn deduce_groupe_periods_fills_uncovered_dates() {
+        let d = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        // Seul le mandat en cours est publié
+        let known = [GroupeMembership {
+            organe_id: "PO2".to_string(),
+            abrev: None,
+            libelle: "PO2".to_string(),
+            qualite: "Membre".to_string(),
+            apparente: false,
+            date_debut: Some(d("2025-03-01")),
+            date_fin: None,
+            deduite: false,
+        }];
+        let obs = [
+            (d("2024-10-01"), "PO1"),
+            (d("2024-11-05"), "PO1"),
+            (d("2025-01-14"), "PO3"),
+            (d("2025-03-04"), "PO2"),
+        ];
+
+        let deduites = deduce_groupe_periods(&known, &obs, &HashMap::new());
+        assert!(deduites.iter().all(|g| g.deduite));
+        let got: Vec<(&str, Option<NaiveDate>, Option<NaiveDate>)> =
+            deduites.iter().map(|g| (g.organe_id.as_str(), g.date_debut, g.date_fin)).collect();
+        assert_eq!(
+            got,
+            [
+                ("PO1", Some(d("2024-10-01")), Some(d("2025-01-13"))),
+                // Close la veille du mandat connu
+                ("PO3", Some(d("2025-01-14")), Some(d("2025-02-28"))),
+            ]
+        );
+    }
+
+    // ─── parse_reunion ─────────────────────────────────────────────────────
+    #[test]
+    fn parse_reunion_extracts_presences() {
+        let json = serde_json::json!({
+            "uid": "RUANR5L17S2024IDC452011",
+            "timeStampDebut": "2024-10-02T09:30:00.000+02:00",
+            "organeReuniRef": "PO1",
+            "cycleDeVie": { "etat": "Confirmé" },
+            "participants": { "participantsInternes": { "participantInterne": [
+                { "acteurRef": "PA1", "presence": "présent" },
+                { "acteurRef": "PA2", "presence": "Excusé" },
+                { "acteurRef": "PA3", "presence": "absent" },
+                { "acteurRef": "PA4", "presence": "inconnu" },
+                { "acteurRef": "PA1", "presence": "absent" }
+            ] } }
+        });
+        let r = parse_reunion(&from_value(json)).expect("should parse");
+        assert_eq!(r.date, NaiveDate::from_ymd_opt(2024, 10, 2));
+        assert_eq!(r.organe_id.as_deref(), Some("PO1"));
+        assert_eq!(
+            r.participants,
+            vec![
+                ("PA1".to_string(), Presence::Present),
+                ("PA2".to_string(), Presence::Excuse),
+                ("PA3".to_string(), Presence::Absent),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_reunion_skips_cancelled_or_without_participants() {
+        let annulee = serde_json::json!({
+            "uid": "RU1",
+            "timeStampDebut": "2024-10-02T09:30:00.000+02:00",
+            "cycleDeVie": { "etat": "Annulé" },
+            "participants": { "participantsInternes": { "participantInterne": { "acteurRef": "PA1", "presence": "présent" } } }
+        });
+        assert!(parse_reunion(&from_value(annulee)).is_none());
+
+        let seance = serde_json::json!({
+            "uid": "RU2",
+            "timeStampDebut": "2024-10-02T15:00:00.000+02:00",
+            "cycleDeVie": { "etat": "Confirmé" },
+            "participants": null
+        });
+        assert!(parse_reunion(&from_value(seance)).is_none());
+    }
+
+    // ─── lecture en flux ───────────────────────────────────────────────────
+    #[test]
+    fn scrutin_reader_merges_layouts_and_dedups_uid() {
+        let doc = r#"{
+            "scrutins": { "scrutin": [
+                { "uid": "VTANR5L17V1", "numero": 1, "dateScrutin": "2024-10-01" },
+                { "uid": "VTANR5L17V2", "numero": "2", "sort": "rejeté" },
+                "texte isolé"
+            ] },
+            "scrutin": { "uid": "VTANR5L17V1", "numero": "99" }
+        }"#;
+        let scrutins = parse_scrutin_reader(doc.as_bytes()).unwrap();
+        let ids: Vec<_> = scrutins.iter().map(|s| (s.id.as_str(), s.numero)).collect();
+        assert_eq!(ids, vec![("VTANR5L17V1", 1), ("VTANR5L17V2", 2)]);
+        assert_eq!(scrutins[1].sort.as_deref(), Some("rejeté"));
+
+        let single = parse_scrutin_reader(r#"{ "scrutin": { "uid": "VTANR5L17V3" } }"#.as_bytes()).unwrap();
+        assert_eq!(single.len(), 1);
+    }
+
+    #[test]
+    fn amendement_reader_tolerates_irregular_fields() {
+        // Types inattendus (nombres, tableaux, null) : ignorés champ par champ, sans faire
+        // échouer le document
+        let doc = r#"{ "amendements": { "amendement": [
+            {
+                "uid": "AMANR5L17PO1A1",
+                "identificatif": { "numero": 12 },
+                "cycleDeVie": {
+                    "sort": ["Adopté"],
+                    "etatDesTraitements": { "etat": { "libelle": "Discuté" }, "sousEtat": null },
+                    "dateDepot": null,
+                    "dateFoo": "2024-11-02",
+                    "autre": { "x": 1 }
+                },
+                "signataires": {
+                    "auteur": { "acteurRef": "PA1", "typeAuteur": 3 },
+                    "cosignataires": { "acteurRef": [" PA3 ", 7, "PA2", "PA3", ""] }
+                },
+                "corps": { "pointeurFragmentTexte": "", "contenuAuteur": { "exposeSommaire": 42 } }
+            },
+            { "uid": 5 },
+            { "uid": "AMANR5L17PO1A1" }
+        ] } }"#;
+        let amendements = parse_amendement_reader(doc.as_bytes()).unwrap();
+        assert_eq!(amendements.len(), 1);
+        let a = &amendements[0];
+        assert_eq!(a.numero, None);
+        assert_eq!(a.sort.as_deref(), Some("Discuté"));
+        assert!(!a.adopte);
+        assert_eq!(a.auteur_id.as_deref(), Some("PA1"));
+        assert_eq!(a.auteur_type, None);
+        assert_eq!(a.cosignataires_ids, vec!["PA2".to_string(), "PA3".to_string()]);
+        assert_eq!(a.date, NaiveDate::from_ymd_opt(2024, 11, 2));
+        assert_eq!(a.expose_sommaire, None);
+    }
+
+    #[test]
+    fn reader_rejects_malformed_json() {
+        assert!(parse_amendement_reader(r#"{ "amendements": { "amendement": [ { "uid": "A1" } "#.as_bytes()).is_err());
+    }
 }
@@ -0,0 +1,333 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::aggregator::AllAggregates;
+use crate::models::{Amendement, Depute, Dossier, ParseFailure, Scrutin};
+
+/// Rapport sous `data/` ; la version publiée sert de référence au run suivant.
+pub const QUALITY_REPORT_FILE: &str = "quality_report.json";
+/// Version lisible, sous `exports/`.
+const QUALITY_REPORT_MD: &str = "quality_report.md";
+/// Identifiants d'exemple conservés par contrôle.
+const MAX_EXAMPLES: usize = 20;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualityReport {
+    pub schema_version: u32,
+    pub generated_at: String,
+    /// Rapport du run précédent, référence des évolutions (`None` au premier run).
+    pub previous_generated_at: Option<String>,
+    pub checks: Vec<QualityCheck>,
+    pub parse_failures: Vec<ParseFailure>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualityCheck {
+    pub id: String,
+    pub label: String,
+    pub count: usize,
+    /// Effectif contrôlé (ex. nombre total d'amendements), `None` s'il n'a pas de sens.
+    pub total: Option<usize>,
+    /// Valeur au run précédent (absente au premier run ou pour un nouveau contrôle).
+    pub previous: Option<usize>,
+    pub delta: Option<i64>,
+    /// Identifiants concernés, triés et dédoublonnés (au plus `MAX_EXAMPLES`).
+    pub examples: Vec<String>,
+}
+
+/// Données contrôlées : le dataset parsé, tel que repris par les agrégats.
+pub struct QualityInput<'a> {
+    pub deputes: &'a [Depute],
+    pub scrutins: &'a [Scrutin],
+    pub amendements: &'a [Amendement],
+    pub dossiers: &'a HashMap<String, Dossier>,
+    pub parse_failures: &'a [ParseFailure],
+}
+
+impl<'a> QualityInput<'a> {
+    pub fn from_aggregates(agg: &'a AllAggregates) -> Self {
+        Self {
+            deputes: &agg.deputes,
+            scrutins: &agg.scrutins,
+            amendements: &agg.amendements,
+            dossiers: &agg.dossiers,
+            parse_failures: &agg.parse_failures,
+        }
+    }
+}
+
+pub fn build_quality_report(
+    input: &QualityInput,
+    previous: Option<&QualityReport>,
+    generated_at_iso: &str,
+) -> QualityReport {
+    let mut checks = Vec::new();
+    let mut push = |id: &str, label: &str, total: Option<usize>, ids: Vec<String>| {
+        checks.push(QualityCheck {
+            id: id.to_string(),
+            label: label.to_string(),
+            count: ids.len(),
+            total,
+            previous: None,
+            delta: None,
+            examples: examples(ids),
+        });
+    };
+
+    let amendements = input.amendements;
+    push(
+        "amendements_sans_date",
+        "Amendements sans date",
+        Some(amendements.len()),
+        amendements.iter().filter(|a| a.date.is_none()).map(|a| a.id.clone()).collect(),
+    );
+    push(
+        "amendements_sans_auteur",
+        "Amendements sans auteur",
+        Some(amendements.len()),
+        amendements.iter().filter(|a| a.auteur_id.is_none()).map(|a| a.id.clone()).collect(),
+    );
+
+    let scrutins = input.scrutins;
+    push(
+        "scrutins_sans_date",
+        "Scrutins sans date",
+        Some(scrutins.len()),
+        scrutins.iter().filter(|s| s.date.is_none()).map(|s| s.id.clone()).collect(),
+    );
+    push(
+        "scrutins_sans_dossier",
+        "Scrutins sans dossier législatif",
+        Some(scrutins.len()),
+        scrutins.iter().filter(|s| s.dossier_ref.is_none()).map(|s| s.id.clone()).collect(),
+    );
+
+    let dossiers = input.dossiers;
+    push(
+        "dossiers_sans_titre",
+        "Dossiers sans titre",
+        Some(dossiers.len()),
+        dossiers.values().filter(|d| d.titre.trim().is_empty()).map(|d| d.id.clone()).collect(),
+    );
+
+    let deputes = input.deputes;
+    push(
+        "deputes_sans_groupe",
+        "Députés sans groupe politique",
+        Some(deputes.len()),
+        deputes
+            .iter()
+            .filter(|d| d.groupe_id.as_deref().is_none_or(|g| g.trim().is_empty()))
+            .map(|d| d.id.clone())
+            .collect(),
+    );
+
+    // Votes nominatifs dont l'acteur n'est pas dans le dataset députés (anciens députés,
+    // identifiants mal formés) : compte de votes, exemples = acteurs les plus fréquents
+    let known: HashSet<&str> = deputes.iter().map(|d| d.id.as_str()).collect();
+    let mut unknown: HashMap<&str, usize> = HashMap::new();
+    let mut total_votes = 0;
+    for s in scrutins {
+        total_votes += s.votes.len();
+        for acteur in s.votes.keys().filter(|a| !known.contains(a.as_str())) {
+            *unknown.entry(acteur).or_default() += 1;
+        }
+    }
+    let mut by_freq: Vec<(&str, usize)> = unknown.into_iter().collect();
+    by_freq.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    checks.push(QualityCheck {
+        id: "votes_acteur_inconnu".to_string(),
+        label: "Votes d'un acteur inconnu du dataset députés".to_string(),
+        count: by_freq.iter().map(|(_, n)| n).sum(),
+        total: Some(total_votes),
+        previous: None,
+        delta: None,
+        examples: by_freq.iter().take(MAX_EXAMPLES).map(|(id, n)| format!("{id} ({n})")).collect(),
+    });
+
+    checks.push(QualityCheck {
+        id: "fichiers_en_echec".to_string(),
+        label: "Fichiers écartés au parsing".to_string(),
+        count: input.parse_failures.len(),
+        total: None,
+        previous: None,
+        delta: None,
+        examples: examples(input.parse_failures.iter().map(|f| format!("{}/{}", f.source, f.file)).collect()),
+    });
+
+    if let Some(prev) = previous {
+        let prev_counts: HashMap<&str, usize> = prev.checks.iter().map(|c| (c.id.as_str(), c.count)).collect();
+        for check in &mut checks {
+            check.previous = prev_counts.get(check.id.as_str()).copied();
+            check.delta = check.previous.map(|p| check.count as i64 - p as i64);
+        }
+    }
+
+    QualityReport {
+        schema_version: 1,
+        generated_at: generated_at_iso.to_string(),
+        previous_generated_at: previous.map(|p| p.generated_at.clone()),
+        checks,
+        parse_failures: input.parse_failures.to_vec(),
+    }
+}
+
+fn examples(mut ids: Vec<String>) -> Vec<String> {
+    ids.sort();
+    ids.dedup();
+    ids.truncate(MAX_EXAMPLES);
+    ids
+}
+
+/// Écrit `quality_report.json` (sous `data/`) et sa version Markdown (sous `exports/`).
+pub fn write_quality_report(report: &QualityReport, data_root: &Path, exports_root: &Path) -> Result<()> {
+    std::fs::create_dir_all(data_root)?;
+    std::fs::create_dir_all(exports_root)?;
+    std::fs::write(data_root.join(QUALITY_REPORT_FILE), serde_json::to_vec(report)?)?;
+    std::fs::write(exports_root.join(QUALITY_REPORT_MD), render_markdown(report))?;
+
+    let flagged = report.checks.iter().filter(|c| c.count > 0).count();
+    let worse = report.checks.iter().filter(|c| c.delta.is_some_and(|d| d > 0)).count();
+    eprintln!(
+        "[exporter] {} + {} : {} contrôle(s) non nul(s), {} en hausse depuis le run précédent",
+        QUALITY_REPORT_FILE, QUALITY_REPORT_MD, flagged, worse
+    );
+    Ok(())
+}
+
+fn render_markdown(report: &QualityReport) -> String {
+    let mut md = String::new();
+    let _ = writeln!(md, "# Rapport qualité des données\n");
+    let _ = writeln!(md, "Généré le {}.", report.generated_at);
+    match &report.previous_generated_at {
+        Some(prev) => {
+            let _ = writeln!(md, "Évolutions calculées par rapport au run du {prev}.\n");
+        }
+        None => {
+            let _ = writeln!(md, "Premier rapport : pas de run précédent à comparer.\n");
+        }
+    }
+
+    let _ = writeln!(md, "| Contrôle | Nombre | Sur | Précédent | Évolution |");
+    let _ = writeln!(md, "|----------|-------:|----:|----------:|----------:|");
+    for c in &report.checks {
+        let total = match c.total {
+            Some(t) if t > 0 => format!("{t} ({})", format_pct(c.count, t)),
+            Some(t) => t.to_string(),
+            None => "—".to_string(),
+        };
+        let previous = c.previous.map(|p| p.to_string()).unwrap_or_else(|| "—".to_string());
+        let delta = match c.delta {
+            Some(d) if d > 0 => format!("+{d}"),
+            Some(d) => d.to_string(),
+            None => "—".to_string(),
+        };
+        let _ = writeln!(md, "| {} | {} | {} | {} | {} |", c.label, c.count, total, previous, delta);
+    }
+
+    let with_examples: Vec<_> = report
+        .checks
+        .iter()
+        .filter(|c| !c.examples.is_empty() && c.id != "fichiers_en_echec")
+        .collect();
+    if !with_examples.is_empty() {
+        let _ = writeln!(md, "\n## Exemples\n");
+        for c in with_examples {
+            let list = c.examples.iter().map(|e| format!("`{e}`")).collect::<Vec<_>>().join(", ");
+            let more = if c.count > c.examples.len() { ", …" } else { "" };
+            let _ = writeln!(md, "- **{}** : {list}{more}", c.label);
+        }
+    }
+
+    if !report.parse_failures.is_empty() {
+        let _ = writeln!(md, "\n## Fichiers écartés au parsing\n");
+        let _ = writeln!(md, "| Source | Fichier | Erreur |");
+        let _ = writeln!(md, "|--------|---------|--------|");
+        for f in &report.parse_failures {
+            let error = f.error.replace('|', "\\|").replace('\n', " ");
+            let _ = writeln!(md, "| {} | `{}` | {} |", f.source, f.file, error);
+        }
+    }
+    md
+}
+
+fn format_pct(count: usize, total: usize) -> String {
+    format!("{:.1} %", count as f64 * 100.0 / total as f64).replace('.', ",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn input_parts() -> (Vec<Depute>, Vec<Scrutin>, Vec<Amendement>, HashMap<String, Dossier>) {
+        let deputes = serde_json::from_value(json!([
+            { "id": "PA1", "nom": "A", "prenom": "A", "groupe_id": "PO1" },
+            { "id": "PA2", "nom": "B", "prenom": "B", "groupe_id": " " },
+        ]))
+        .unwrap();
+        let scrutins = serde_json::from_value(json!([
+            { "id": "VT1", "numero": 1, "titre": "", "date": "2024-10-01", "dossier_ref": "DL1",
+              "votes": { "PA1": "Pour", "PA9": "Contre", "PA8": "Pour" } },
+            { "id": "VT2", "numero": 2, "titre": "", "votes": { "PA9": "Pour" } },
+        ]))
+        .unwrap();
+        let amendements = serde_json::from_value(json!([
+            { "id": "AM1", "auteur_id": "PA1", "cosignataires_ids": [], "date": "2024-10-02", "adopte": false },
+            { "id": "AM2", "cosignataires_ids": [], "adopte": false },
+        ]))
+        .unwrap();
+        let dossiers = serde_json::from_value(json!({
+            "DL1": { "id": "DL1", "titre": "Projet de loi" },
+            "DL2": { "id": "DL2", "titre": "" },
+        }))
+        .unwrap();
+        (deputes, scrutins, amendements, dossiers)
+    }
+
+    #[test]
+    fn checks_count_issues_and_compare_with_previous_run() {
+        let (deputes, scrutins, amendements, dossiers) = input_parts();
+        let failures = vec![ParseFailure {
+            source: "scrutins".to_string(),
+            file: "json/VT3.json".to_string(),
+            error: "JSON invalide: EOF".to_string(),
+        }];
+        let input = QualityInput {
+            deputes: &deputes,
+            scrutins: &scrutins,
+            amendements: &amendements,
+            dossiers: &dossiers,
+            parse_failures: &failures,
+        };
+
+        let first = build_quality_report(&input, None, "2025-04-01T00:00:00+00:00");
+        let count = |r: &QualityReport, id: &str| r.checks.iter().find(|c| c.id == id).map(|c| (c.count, c.total));
+        assert_eq!(count(&first, "amendements_sans_date"), Some((1, Some(2))));
+        assert_eq!(count(&first, "amendements_sans_auteur"), Some((1, Some(2))));
+        assert_eq!(count(&first, "scrutins_sans_date"), Some((1, Some(2))));
+        assert_eq!(count(&first, "scrutins_sans_dossier"), Some((1, Some(2))));
+        assert_eq!(count(&first, "dossiers_sans_titre"), Some((1, Some(2))));
+        assert_eq!(count(&first, "deputes_sans_groupe"), Some((1, Some(2))));
+        assert_eq!(count(&first, "votes_acteur_inconnu"), Some((3, Some(4))));
+        assert_eq!(count(&first, "fichiers_en_echec"), Some((1, None)));
+        let inconnus = first.checks.iter().find(|c| c.id == "votes_acteur_inconnu").unwrap();
+        assert_eq!(inconnus.examples, vec!["PA9 (2)".to_string(), "PA8 (1)".to_string()]);
+        assert!(first.checks.iter().all(|c| c.previous.is_none() && c.delta.is_none()));
+
+        let input = QualityInput { parse_failures: &[], amendements: &amendements[..1], ..input };
+        let second = build_quality_report(&input, Some(&first), "2025-04-08T00:00:00+00:00");
+        assert_eq!(second.previous_generated_at.as_deref(), Some("2025-04-01T00:00:00+00:00"));
+        let sans_date = second.checks.iter().find(|c| c.id == "amendements_sans_date").unwrap();
+        assert_eq!((sans_date.count, sans_date.previous, sans_date.delta), (0, Some(1), Some(-1)));
+        let scrutins = second.checks.iter().find(|c| c.id == "scrutins_sans_date").unwrap();
+        assert_eq!(scrutins.delta, Some(0));
+
+        let md = render_markdown(&second);
+        assert!(md.contains("| Amendements sans date | 0 | 1 (0,0 %) | 1 | -1 |"));
+        assert!(md.contains("`PA9 (2)`"));
+    }
+}
@@ -0,0 +1,101 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::group_ppl_v1::safe_file_stem;
+use crate::models::{Depute, Question};
+use shared::DeputeQuestions;
+
+/// Écrit `questions/deputes/<id>.json` pour chaque député (liste vide s'il n'a posé aucune
+/// question), questions les plus récentes en premier.
+pub fn write_questions_json(
+    questions: &[Question],
+    deputes: &[Depute],
+    data_root: &Path,
+    generated_at_iso: &str,
+) -> Result<()> {
+    let out_dir = data_root.join("questions").join("deputes");
+    std::fs::create_dir_all(&out_dir)?;
+
+    let by_depute = questions_by_depute(questions);
+    let mut total = 0usize;
+    for dep in deputes {
+        let questions = by_depute.get(dep.id.as_str()).cloned().unwrap_or_default();
+        total += questions.len();
+        let file = DeputeQuestions {
+            schema_version: 1,
+            generated_at: generated_at_iso.to_string(),
+            deputy_id: dep.id.clone(),
+            questions,
+        };
+        write_minified_json(&out_dir.join(format!("{}.json", safe_file_stem(&dep.id))), &file)?;
+    }
+    eprintln!("[exporter] questions/ : {} député(s), {} question(s)", deputes.len(), total);
+    Ok(())
+}
+
+/// Questions par auteur, triées par date de question décroissante (sans date en dernier),
+/// puis par identifiant.
+fn questions_by_depute(questions: &[Question]) -> HashMap<&str, Vec<Question>> {
+    let mut out: HashMap<&str, Vec<Question>> = HashMap::new();
+    for q in questions {
+        out.entry(q.auteur_id.as_str()).or_default().push(q.clone());
+    }
+    for list in out.values_mut() {
+        list.sort_by(|a, b| {
+            b.date_question
+                .is_some()
+                .cmp(&a.date_question.is_some())
+                .then(b.date_question.cmp(&a.date_question))
+                .then(a.id.cmp(&b.id))
+        });
+    }
+    out
+}
+
+fn write_minified_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let bytes = serde_json::to_vec(value)?;
+    std::fs::write(path, bytes)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn question(id: &str, auteur: &str, date: Option<&str>) -> Question {
+        Question {
+            id: id.to_string(),
+            type_question: "QE".to_string(),
+            numero: None,
+            auteur_id: auteur.to_string(),
+            ministere: None,
+            rubrique: None,
+            titre: None,
+            date_question: date.map(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").unwrap()),
+            date_reponse: None,
+            repondue: false,
+            cloture: None,
+        }
+    }
+
+    #[test]
+    fn questions_grouped_by_author_most_recent_first() {
+        let questions = [
+            question("Q1", "PA1", Some("2024-10-01")),
+            question("Q2", "PA1", None),
+            question("Q3", "PA2", Some("2024-11-05")),
+            question("Q4", "PA1", Some("2025-01-14")),
+        ];
+        let by_depute = questions_by_depute(&questions);
+        let ids: Vec<&str> = by_depute["PA1"].iter().map(|q| q.id.as_str()).collect();
+        assert_eq!(ids, ["Q4", "Q1", "Q2"]);
+        assert_eq!(by_depute["PA2"].len(), 1);
+    }
+}
@@ -0,0 +1,197 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::group_ppl_v1::safe_file_stem;
+use crate::models::{Depute, Dossier, Organe, Scrutin, VotePosition};
+use shared::{
+    ScrutinDetail, ScrutinDetailGroupe, ScrutinIndexEntry, ScrutinMiseAuPoint, ScrutinVotant, ScrutinsIndex,
+    VoteTotals,
+};
+
+pub fn write_scrutins_json(
+    scrutins: &[Scrutin],
+    deputes: &[Depute],
+    organes: &HashMap<String, Organe>,
+    dossiers: &HashMap<String, Dossier>,
+    data_root: &Path,
+    generated_at_iso: &str,
+) -> Result<()> {
+    let out_dir = data_root.join("scrutins");
+    let detail_dir = out_dir.join("detail");
+    std::fs::create_dir_all(&detail_dir)?;
+
+    let names: HashMap<&str, String> = deputes
+        .iter()
+        .map(|d| (d.id.as_str(), format!("{} {}", d.prenom, d.nom).trim().to_string()))
+        .collect();
+
+    let mut ordered: Vec<&Scrutin> = scrutins.iter().collect();
+    ordered.sort_by(|a, b| b.date.cmp(&a.date).then(b.numero.cmp(&a.numero)).then(a.id.cmp(&b.id)));
+
+    let mut entries = Vec::with_capacity(ordered.len());
+    for s in ordered {
+        let rel_file = format!("detail/{}.json", safe_file_stem(&s.id));
+        let detail = build_detail(s, &names, organes, dossiers, generated_at_iso);
+        write_minified_json(&out_dir.join(&rel_file), &detail)?;
+
+        entries.push(ScrutinIndexEntry {
+            id: s.id.clone(),
+            numero: s.numero,
+            date: s.date,
+            titre: s.titre.clone(),
+            sort: s.sort.clone(),
+            dossier_ref: s.dossier_ref.clone(),
+            type_vote: s.type_vote.clone(),
+            pour: detail.totals.pour,
+            contre: detail.totals.contre,
+            abstentions: detail.totals.abstentions,
+            non_votants: detail.totals.non_votants,
+        });
+    }
+
+    let index = ScrutinsIndex {
+        schema_version: 1,
+        generated_at: generated_at_iso.to_string(),
+        total: entries.len(),
+        scrutins: entries,
+    };
+    write_minified_json(&out_dir.join("index.json"), &index)?;
+    eprintln!("[exporter] scrutins/index.json + {} fichier(s) détail", index.total);
+
+    Ok(())
+}
+
+fn build_detail(
+    s: &Scrutin,
+    names: &HashMap<&str, String>,
+    organes: &HashMap<String, Organe>,
+    dossiers: &HashMap<String, Dossier>,
+    generated_at_iso: &str,
+) -> ScrutinDetail {
+    let mut pour = Vec::new();
+    let mut contre = Vec::new();
+    let mut abstention = Vec::new();
+    let mut non_votant = Vec::new();
+
+    for (dep_id, position) in &s.votes {
+        let votant = ScrutinVotant {
+            deputy_id: dep_id.clone(),
+            nom: names.get(dep_id.as_str()).cloned(),
+            groupe_ref: s.groupe_votants.get(dep_id).cloned(),
+            par_delegation: s.par_delegation.binary_search(dep_id).is_ok(),
+        };
+        match position {
+            VotePosition::Pour => pour.push(votant),
+            VotePosition::Contre => contre.push(votant),
+            VotePosition::Abstention => abstention.push(votant),
+            VotePosition::NonVotant => non_votant.push(votant),
+            VotePosition::Absent => {}
+        }
+    }
+    for list in [&mut pour, &mut contre, &mut abstention, &mut non_votant] {
+        sort_votants(list);
+    }
+
+    let mut mises_au_point: Vec<ScrutinMiseAuPoint> = s
+        .mises_au_point
+        .iter()
+        .map(|(dep_id, voulue)| ScrutinMiseAuPoint {
+            deputy_id: dep_id.clone(),
+            nom: names.get(dep_id.as_str()).cloned(),
+            position_enregistree: s.votes.get(dep_id).cloned(),
+            position_voulue: voulue.clone(),
+        })
+        .collect();
+    // Même ordre que les listes nominatives : par nom, députés hors référentiel en fin
+    mises_au_point.sort_by_key(|m| {
+        (m.nom.is_none(), m.nom.as_deref().unwrap_or("").to_lowercase(), m.deputy_id.clone())
+    });
+
+    let groupes: Vec<ScrutinDetailGroupe> = s
+        .groupes
+        .iter()
+        .map(|g| {
+            let organe = organes.get(&g.organe_ref);
+            ScrutinDetailGroupe {
+                organe_ref: g.organe_ref.clone(),
+                abrev: organe.and_then(|o| o.abrev.clone()),
+                libelle: organe.map(|o| o.libelle.clone()),
+                nombre_membres: g.nombre_membres,
+                position_majoritaire: g.position_majoritaire.clone(),
+                pour: g.pour,
+                contre: g.contre,
+                abstentions: g.abstentions,
+                non_votants: g.non_votants,
+            }
+        })
+        .collect();
+
+    // Totaux : somme des décomptes de groupes si disponibles, sinon liste nominative
+    let totals = if groupes.is_empty() {
+        VoteTotals {
+            pour: pour.len() as u32,
+            contre: contre.len() as u32,
+            abstentions: abstention.len() as u32,
+            non_votants: non_votant.len() as u32,
+        }
+    } else {
+        groupes.iter().fold(VoteTotals::default(), |mut t, g| {
+            t.pour += g.pour;
+            t.contre += g.contre;
+            t.abstentions += g.abstentions;
+            t.non_votants += g.non_votants;
+            t
+        })
+    };
+
+    let dossier_titre = s
+        .dossier_ref
+        .as_ref()
+        .and_then(|r| dossiers.get(r))
+        .map(|d| d.titre.clone())
+        .filter(|t| !t.is_empty());
+
+    ScrutinDetail {
+        schema_version: 1,
+        generated_at: generated_at_iso.to_string(),
+        id: s.id.clone(),
+        numero: s.numero,
+        date: s.date,
+        titre: s.titre.clone(),
+        sort: s.sort.clone(),
+        dossier_ref: s.dossier_ref.clone(),
+        dossier_titre,
+        type_vote: s.type_vote.clone(),
+        type_vote_libelle: s.type_vote_libelle.clone(),
+        totals,
+        groupes,
+        pour,
+        contre,
+        abstention,
+        non_votant,
+        mises_au_point,
+    }
+}
+
+fn sort_votants(list: &mut [ScrutinVotant]) {
+    list.sort_by(|a, b| {
+        let ka = a.nom.as_deref().unwrap_or("").to_lowercase();
+        let kb = b.nom.as_deref().unwrap_or("").to_lowercase();
+        // Votants hors référentiel (sans nom) en fin de liste
+        a.nom.is_none()
+            .cmp(&b.nom.is_none())
+            .then(ka.cmp(&kb))
+            .then_with(|| a.deputy_id.cmp(&b.deputy_id))
+    });
+}
+
+fn write_minified_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let bytes = serde_json::to_vec(value)?;
+    std::fs::write(path, bytes)?;
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use crate::aggregator::WeeklySeries;
+use crate::group_ppl_v1::safe_file_stem;
+use crate::models::{Depute, Organe};
+use shared::{DeputeSeries, GroupeSeries, GroupesSeries, SeriesIndex, WeeklyBuckets};
+
+/// Nombre de semaines reprises dans `series/index.json` (sparklines du tableau d'accueil).
+const INDEX_WEEKS: usize = 26;
+
+pub fn write_series_json(
+    weekly: &WeeklySeries,
+    deputes: &[Depute],
+    organes: &HashMap<String, Organe>,
+    data_root: &Path,
+    generated_at_iso: &str,
+) -> Result<()> {
+    let out_dir = data_root.join("series");
+    let deputes_dir = out_dir.join("deputes");
+    std::fs::create_dir_all(&deputes_dir)?;
+
+    let tail_start = weekly.weeks.saturating_sub(INDEX_WEEKS);
+    let mut participation = BTreeMap::new();
+
+    for dep in deputes {
+        let Some(buckets) = weekly.deputes.get(&dep.id) else { continue };
+        let file = DeputeSeries {
+            schema_version: 1,
+            generated_at: generated_at_iso.to_string(),
+            deputy_id: dep.id.clone(),
+            week_start: weekly.week_start,
+            weeks: weekly.weeks,
+            series: buckets.clone(),
+        };
+        write_minified_json(&deputes_dir.join(format!("{}.json", safe_file_stem(&dep.id))), &file)?;
+        participation.insert(dep.id.clone(), participation_pct_tail(buckets, tail_start));
+    }
+
+    // Effectif actuel : les séries, elles, suivent le groupe de chaque député à la date
+    let mut membres_actuels: HashMap<&str, usize> = HashMap::new();
+    for dep in deputes {
+        if let Some(g) = dep.groupe_id.as_deref() {
+            *membres_actuels.entry(g).or_default() += 1;
+        }
+    }
+    let mut groupes: Vec<GroupeSeries> = weekly
+        .groupes
+        .iter()
+        .map(|(id, buckets)| GroupeSeries {
+            id: id.clone(),
+            abrev: organes.get(id).and_then(|o| o.abrev.clone()),
+            membres_actuels: membres_actuels.get(id.as_str()).copied().unwrap_or(0),
+            series: buckets.clone(),
+        })
+        .collect();
+    groupes.sort_by(|a, b| b.membres_actuels.cmp(&a.membres_actuels).then(a.id.cmp(&b.id)));
+    write_minified_json(
+        &out_dir.join("groupes.json"),
+        &GroupesSeries {
+            schema_version: 2,
+            generated_at: generated_at_iso.to_string(),
+            week_start: weekly.week_start,
+            weeks: weekly.weeks,
+            groupes,
+        },
+    )?;
+
+    let index = SeriesIndex {
+        schema_version: 1,
+        generated_at: generated_at_iso.to_string(),
+        week_start: weekly.week_start + chrono::Duration::weeks(tail_start as i64),
+        weeks: weekly.weeks - tail_start,
+        participation,
+    };
+    write_minified_json(&out_dir.join("index.json"), &index)?;
+    eprintln!(
+        "[exporter] series/ : {} député(s), {} groupe(s), {} semaine(s)",
+        index.participation.len(),
+        weekly.groupes.len(),
+        weekly.weeks
+    );
+
+    Ok(())
+}
+
+/// Participation en pourcentage entier sur les semaines `[tail_start..]`.
+fn participation_pct_tail(buckets: &WeeklyBuckets, tail_start: usize) -> Vec<Option<u8>> {
+    buckets.participation()[tail_start..]
+        .iter()
+        .map(|p| p.map(|r| (r * 100.0).round() as u8))
+        .collect()
+}
+
+fn write_minified_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let bytes = serde_json::to_vec(value)?;
+    std::fs::write(path, bytes)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn participation_tail_rounds_and_keeps_empty_weeks() {
+        let buckets = WeeklyBuckets {
+            scrutins_eligibles: vec![4, 3, 0, 8],
+            votes_exprimes: vec![4, 2, 0, 1],
+            ..WeeklyBuckets::with_weeks(4)
+        };
+        assert_eq!(participation_pct_tail(&buckets, 1), vec![Some(67), None, Some(13)]);
+        assert_eq!(participation_pct_tail(&buckets, 4), Vec::<Option<u8>>::new());
+    }
+}
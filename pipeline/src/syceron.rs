@@ -0,0 +1,565 @@
+//! Comptes rendus des séances publiques au format XML Syceron (dataset `syseron.xml`, un
+//! fichier par compte rendu) : chaque prise de parole est rattachée à un acteur (PA…), à la
+//! date de la séance et, quand le titre du point débattu l'identifie, à un dossier législatif.
+//!
+//! Aucune dépendance XML : le format ne demande qu'un lecteur minimal (balises, attributs,
+//! texte, CDATA, entités prédéfinies et numériques), qui vérifie l'imbrication des balises
+//! pour écarter les fichiers tronqués.
+
+use anyhow::{bail, Result};
+use chrono::NaiveDate;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::models::{Dossier, Intervention};
+
+// ─── Lecteur XML ───────────────────────────────────────────────────────────
+
+#[derive(Debug, PartialEq)]
+pub enum XmlEvent<'a> {
+    /// Balise ouvrante ; une balise vide (`<a/>`) produit `Start` puis `End`.
+    Start { name: &'a str, attrs: Vec<(&'a str, Cow<'a, str>)> },
+    End { name: &'a str },
+    /// Texte (entités décodées) ou section CDATA.
+    Text(Cow<'a, str>),
+}
+
+/// Lecteur XML en flux sur un document en mémoire. Les noms sont rendus sans préfixe
+/// d'espace de noms (`ns:texte` → `texte`).
+pub struct XmlReader<'a> {
+    src: &'a str,
+    pos: usize,
+    open: Vec<&'a str>,
+    pending_end: Option<&'a str>,
+}
+
+impl<'a> XmlReader<'a> {
+    pub fn new(src: &'a str) -> Self {
+        Self { src, pos: 0, open: Vec::new(), pending_end: None }
+    }
+
+    /// Événement suivant, `None` en fin de document.
+    pub fn next_event(&mut self) -> Result<Option<XmlEvent<'a>>> {
+        if let Some(name) = self.pending_end.take() {
+            return Ok(Some(XmlEvent::End { name }));
+        }
+        loop {
+            let rest = &self.src[self.pos..];
+            if rest.is_empty() {
+                if let Some(name) = self.open.last() {
+                    bail!("XML tronqué: balise <{name}> non fermée");
+                }
+                return Ok(None);
+            }
+
+            if !rest.starts_with('<') {
+                let len = rest.find('<').unwrap_or(rest.len());
+                self.pos += len;
+                let raw = &rest[..len];
+                if self.open.is_empty() {
+                    // Espaces hors de l'élément racine
+                    if !raw.trim().is_empty() {
+                        bail!("XML invalide: texte hors de l'élément racine");
+                    }
+                    continue;
+                }
+                return Ok(Some(XmlEvent::Text(decode_entities(raw))));
+            }
+
+            if let Some(body) = rest.strip_prefix("<![CDATA[") {
+                let end = body.find("]]>").ok_or_else(|| anyhow::anyhow!("XML tronqué: CDATA non fermé"))?;
+                self.pos += "<![CDATA[".len() + end + "]]>".len();
+                return Ok(Some(XmlEvent::Text(Cow::Borrowed(&body[..end]))));
+            }
+            if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+                continue;
+            }
+            if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+                continue;
+            }
+            if rest.starts_with("<!") {
+                // DOCTYPE (sous-ensemble interne éventuel entre crochets)
+                let close = if rest.contains('[') && rest.find('[') < rest.find('>') { "]>" } else { ">" };
+                self.skip_past(close)?;
+                continue;
+            }
+
+            let end = tag_end(rest).ok_or_else(|| anyhow::anyhow!("XML tronqué: balise non fermée"))?;
+            let tag = &rest[1..end];
+            self.pos += end + 1;
+
+            if let Some(name) = tag.strip_prefix('/') {
+                let name = local_name(name.trim_end());
+                match self.open.pop() {
+                    Some(open) if open == name => return Ok(Some(XmlEvent::End { name })),
+                    Some(open) => bail!("XML invalide: </{name}> ferme <{open}>"),
+                    None => bail!("XML invalide: </{name}> sans balise ouvrante"),
+                }
+            }
+
+            let (tag, empty) = match tag.strip_suffix('/') {
+                Some(t) => (t, true),
+                None => (tag, false),
+            };
+            let name_end = tag.find(|c: char| c.is_whitespace()).unwrap_or(tag.len());
+            let name = local_name(&tag[..name_end]);
+            if name.is_empty() {
+                bail!("XML invalide: balise sans nom");
+            }
+            let attrs = parse_attrs(&tag[name_end..])?;
+            if empty {
+                self.pending_end = Some(name);
+            } else {
+                self.open.push(name);
+            }
+            return Ok(Some(XmlEvent::Start { name, attrs }));
+        }
+    }
+
+    fn skip_past(&mut self, delimiter: &str) -> Result<()> {
+        match self.src[self.pos..].find(delimiter) {
+            Some(i) => {
+                self.pos += i + delimiter.len();
+                Ok(())
+            }
+            None => bail!("XML tronqué: `{delimiter}` attendu"),
+        }
+    }
+}
+
+/// Position du `>` fermant la balise qui commence `s`, hors valeurs d'attributs.
+fn tag_end(s: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+fn parse_attrs(mut s: &str) -> Result<Vec<(&str, Cow<'_, str>)>> {
+    let mut attrs = Vec::new();
+    loop {
+        s = s.trim_start();
+        if s.is_empty() {
+            return Ok(attrs);
+        }
+        let eq = s.find('=').ok_or_else(|| anyhow::anyhow!("XML invalide: attribut sans valeur"))?;
+        let name = local_name(s[..eq].trim());
+        let value = s[eq + 1..].trim_start();
+        let quote = value.chars().next().filter(|c| matches!(c, '"' | '\''));
+        let Some(quote) = quote else { bail!("XML invalide: valeur d'attribut {name} sans guillemets") };
+        let close = value[1..]
+            .find(quote)
+            .ok_or_else(|| anyhow::anyhow!("XML invalide: attribut {name} non fermé"))?;
+        attrs.push((name, decode_entities(&value[1..1 + close])));
+        s = &value[close + 2..];
+    }
+}
+
+/// Décode les entités prédéfinies XML et numériques ; une entité inconnue reste telle quelle.
+fn decode_entities(raw: &str) -> Cow<'_, str> {
+    if !raw.contains('&') {
+        return Cow::Borrowed(raw);
+    }
+    let mut out = String::with_capacity(raw.len());
+    let mut rest = raw;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest.find(';').filter(|&semi| semi <= 10).and_then(|semi| {
+            let entity = &rest[1..semi];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => entity.strip_prefix('#').and_then(|n| {
+                    match n.strip_prefix('x').or_else(|| n.strip_prefix('X')) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                        None => n.parse().ok(),
+                    }
+                    .and_then(char::from_u32)
+                }),
+            };
+            c.map(|c| (c, semi))
+        });
+        match decoded {
+            Some((c, semi)) => {
+                out.push(c);
+                rest = &rest[semi + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+// ─── Compte rendu ──────────────────────────────────────────────────────────
+
+/// Paragraphe en cours de lecture.
+struct Paragraphe {
+    acteur_id: Option<String>,
+    presidence: bool,
+    texte: String,
+}
+
+/// Interventions d'un compte rendu. Les paragraphes consécutifs d'un même orateur dans un
+/// même point forment une seule intervention ; ceux de la présidence de séance (`roledebat`
+/// président) et les paragraphes sans acteur identifié ne sont pas comptés.
+pub fn parse_compte_rendu(xml: &str) -> Result<Vec<Intervention>> {
+    let mut reader = XmlReader::new(xml);
+    let mut path: Vec<&str> = Vec::new();
+
+    let mut uid = String::new();
+    let mut date = None;
+    // Titre du point de niveau 1 en cours (rattachement au dossier)
+    let mut titre_point: Option<String> = None;
+    // (numéro du point, niveau) des points ouverts ; le numéro distingue deux points successifs
+    let mut points: Vec<(u32, u32)> = Vec::new();
+    let mut point_seq = 0u32;
+    let mut titre_en_cours: Option<(u32, String)> = None;
+    let mut paragraphe: Option<Paragraphe> = None;
+    // Dernier orateur compté : (acteur, numéro du point)
+    let mut dernier: Option<(String, u32)> = None;
+    let mut interventions: Vec<Intervention> = Vec::new();
+
+    while let Some(event) = reader.next_event()? {
+        match event {
+            XmlEvent::Start { name, attrs } => {
+                let attr = |key: &str| attrs.iter().find(|(k, _)| *k == key).map(|(_, v)| v.trim());
+                match name {
+                    "point" => {
+                        point_seq += 1;
+                        let niveau = attr("nivpoint")
+                            .and_then(|n| n.parse().ok())
+                            .unwrap_or(points.len() as u32 + 1);
+                        points.push((point_seq, niveau));
+                        if niveau <= 1 {
+                            titre_en_cours = Some((point_seq, String::new()));
+                            titre_point = None;
+                        }
+                    }
+                    "paragraphe" => {
+                        paragraphe = Some(Paragraphe {
+                            acteur_id: attr("id_acteur").and_then(normalize_acteur_id),
+                            presidence: attr("roledebat").is_some_and(is_presidence),
+                            texte: String::new(),
+                        });
+                    }
+                    _ => {}
+                }
+                path.push(name);
+            }
+            XmlEvent::End { name } => {
+                path.pop();
+                match name {
+                    "point" => {
+                        points.pop();
+                    }
+                    "texte" if path.last() == Some(&"point") => {
+                        // Texte du point de niveau 1 : son titre
+                        let current = points.last().map(|p| p.0);
+                        if titre_en_cours.as_ref().map(|t| t.0) == current {
+                            if let Some((_, titre)) = titre_en_cours.take() {
+                                let titre = normalize_whitespace(&titre);
+                                titre_point = (!titre.is_empty()).then_some(titre);
+                            }
+                        }
+                    }
+                    "paragraphe" => {
+                        let Some(p) = paragraphe.take() else { continue };
+                        let point = points.last().map(|p| p.0).unwrap_or(0);
+                        let chars = normalize_whitespace(&p.texte).chars().count() as u32;
+                        let acteur = match p.acteur_id {
+                            Some(a) if !p.presidence => a,
+                            _ => {
+                                dernier = None;
+                                continue;
+                            }
+                        };
+                        let suite = dernier.as_ref().is_some_and(|(a, pt)| *a == acteur && *pt == point);
+                        match interventions.last_mut() {
+                            Some(last) if suite => last.chars += chars,
+                            _ => interventions.push(Intervention {
+                                acteur_id: acteur.clone(),
+                                date,
+                                compte_rendu: uid.clone(),
+                                point: titre_point.clone(),
+                                dossier_ref: None,
+                                chars,
+                            }),
+                        }
+                        dernier = Some((acteur, point));
+                    }
+                    _ => {}
+                }
+            }
+            XmlEvent::Text(text) => {
+                let texte = path.iter().rposition(|n| *n == "texte");
+                match path.as_slice() {
+                    [.., "compteRendu", "uid"] => uid.push_str(text.trim()),
+                    [.., "metadonnees", "dateSeance"] => date = date.or_else(|| parse_date_seance(&text)),
+                    _ if texte.is_some_and(|t| t > 0 && path[t - 1] == "point") => {
+                        if let Some((seq, titre)) = titre_en_cours.as_mut() {
+                            if points.last().map(|p| p.0) == Some(*seq) {
+                                titre.push_str(&text);
+                            }
+                        }
+                    }
+                    _ => {
+                        let Some(p) = paragraphe.as_mut() else { continue };
+                        let in_paragraphe = path.iter().rposition(|n| *n == "paragraphe");
+                        match (in_paragraphe, texte) {
+                            (Some(para), Some(texte)) if texte > para => p.texte.push_str(&text),
+                            // Orateur sans id_acteur : identifiant numérique de l'acteur
+                            (Some(_), _) if path.ends_with(&["orateur", "id"]) && p.acteur_id.is_none() => {
+                                p.acteur_id = normalize_acteur_id(&text);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if uid.is_empty() {
+        bail!("compte rendu sans uid");
+    }
+    for i in interventions.iter_mut().filter(|i| i.compte_rendu.is_empty()) {
+        // uid publié après le contenu (ordre inhabituel)
+        i.compte_rendu = uid.clone();
+    }
+    Ok(interventions)
+}
+
+/// `PA720614` ou `720614` → `PA720614` ; vide, `0` ou `-1` → `None`.
+fn normalize_acteur_id(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    if raw.starts_with("PA") && raw.len() > 2 {
+        return Some(raw.to_string());
+    }
+    (!raw.is_empty() && raw.bytes().all(|b| b.is_ascii_digit()) && !raw.trim_start_matches('0').is_empty())
+        .then(|| format!("PA{raw}"))
+}
+
+/// Présidence de séance (et non président de commission ou de groupe).
+fn is_presidence(role: &str) -> bool {
+    matches!(role.to_lowercase().as_str(), "président" | "présidente" | "president" | "presidente")
+}
+
+/// `dateSeance` : `20250107150000000` (AAAAMMJJHHMM…) ou date ISO.
+fn parse_date_seance(raw: &str) -> Option<NaiveDate> {
+    let raw = raw.trim();
+    raw.get(..8)
+        .and_then(|d| NaiveDate::parse_from_str(d, "%Y%m%d").ok())
+        .or_else(|| raw.get(..10).and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok()))
+}
+
+fn normalize_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// ─── Rattachement aux dossiers ─────────────────────────────────────────────
+
+/// Longueur minimale (caractères normalisés) d'un titre de dossier cherché dans un titre de point.
+const MIN_TITRE_CONTENU: usize = 20;
+
+/// Rattache chaque intervention au dossier dont le titre correspond au point débattu :
+/// titre identique (après normalisation), sinon le plus long titre de dossier contenu dans
+/// celui du point. Un titre partagé par plusieurs dossiers ne rattache rien.
+pub fn attribute_dossiers(interventions: &mut [Intervention], dossiers: &HashMap<String, Dossier>) {
+    let mut by_titre: HashMap<String, Vec<&str>> = HashMap::new();
+    for d in dossiers.values() {
+        let titre = normalize_titre(&d.titre);
+        if !titre.is_empty() {
+            by_titre.entry(titre).or_default().push(d.id.as_str());
+        }
+    }
+    let unique = |ids: &Vec<&str>| {
+        let first = ids[0];
+        ids.iter().all(|id| *id == first).then(|| first.to_string())
+    };
+
+    let mut resolved: HashMap<String, Option<String>> = HashMap::new();
+    for i in interventions.iter_mut() {
+        let Some(point) = &i.point else { continue };
+        let dossier = resolved
+            .entry(point.clone())
+            .or_insert_with(|| {
+                let point = normalize_titre(point);
+                if let Some(ids) = by_titre.get(&point) {
+                    return unique(ids);
+                }
+                let longest = by_titre
+                    .iter()
+                    .filter(|(t, _)| t.chars().count() >= MIN_TITRE_CONTENU && point.contains(t.as_str()))
+                    .map(|(t, ids)| (t.len(), ids))
+                    .max_by_key(|(len, _)| *len)?;
+                let mut candidates: Vec<&str> = by_titre
+                    .iter()
+                    .filter(|(t, _)| t.len() == longest.0 && point.contains(t.as_str()))
+                    .flat_map(|(_, ids)| ids.iter().copied())
+                    .collect();
+                candidates.sort_unstable();
+                candidates.dedup();
+                unique(&candidates)
+            })
+            .clone();
+        i.dossier_ref = dossier;
+    }
+}
+
+/// Minuscules, lettres et chiffres seulement, mots séparés par une espace.
+fn normalize_titre(titre: &str) -> String {
+    titre
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reader_decodes_entities_cdata_and_empty_tags() {
+        let xml = r#"<?xml version="1.0"?><!-- c --><ns:a x='1 &amp; 2'><b/>L&apos;&#233;t&#xE9; &lt;<![CDATA[<i>]]></ns:a>"#;
+        let mut reader = XmlReader::new(xml);
+        let mut events = Vec::new();
+        while let Some(e) = reader.next_event().unwrap() {
+            events.push(e);
+        }
+        assert_eq!(
+            events,
+            vec![
+                XmlEvent::Start { name: "a", attrs: vec![("x", Cow::Borrowed("1 & 2"))] },
+                XmlEvent::Start { name: "b", attrs: vec![] },
+                XmlEvent::End { name: "b" },
+                XmlEvent::Text(Cow::Borrowed("L'été <")),
+                XmlEvent::Text(Cow::Borrowed("<i>")),
+                XmlEvent::End { name: "a" },
+            ]
+        );
+    }
+
+    #[test]
+    fn reader_rejects_truncated_or_mismatched_xml() {
+        for xml in ["<a><b></a>", "<a><b></b>", "<a x=\"1></a>"] {
+            let mut reader = XmlReader::new(xml);
+            let result = std::iter::from_fn(|| reader.next_event().transpose()).collect::<Result<Vec<_>>>();
+            assert!(result.is_err(), "{xml}");
+        }
+    }
+
+    const COMPTE_RENDU: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<compteRendu xmlns="http://schemas.assemblee-nationale.fr/referentiel">
+  <uid>CRSANR5L17S2025O1N001</uid>
+  <metadonnees><dateSeance>20250107150000000</dateSeance></metadonnees>
+  <contenu>
+    <point nivpoint="1"><texte>Projet de loi de finances pour 2025</texte>
+      <paragraphe id_acteur="PA1" roledebat="président"><texte>La parole est à M. Dupont.</texte></paragraphe>
+      <paragraphe id_acteur="PA2" roledebat=""><orateurs><orateur><nom>M. Dupont</nom></orateur></orateurs>
+        <texte>Madame la   ministre,</texte></paragraphe>
+      <paragraphe id_acteur="PA2"><texte>ce <italique>budget</italique>…</texte></paragraphe>
+      <point nivpoint="2"><texte>Article 1er</texte>
+        <paragraphe id_acteur=""><orateurs><orateur><id>3</id></orateur></orateurs><texte>Rejet.</texte></paragraphe>
+        <paragraphe id_acteur="PA2"><texte>Non.</texte></paragraphe>
+      </point>
+    </point>
+    <point nivpoint="1"><texte>Questions au Gouvernement</texte>
+      <paragraphe id_acteur="PA2"><texte>Question.</texte></paragraphe>
+    </point>
+  </contenu>
+</compteRendu>"#;
+
+    #[test]
+    fn compte_rendu_groups_paragraphs_by_speaker_and_point() {
+        let interventions = parse_compte_rendu(COMPTE_RENDU).unwrap();
+        let summary: Vec<(&str, Option<&str>, u32)> = interventions
+            .iter()
+            .map(|i| (i.acteur_id.as_str(), i.point.as_deref(), i.chars))
+            .collect();
+        let pldf = Some("Projet de loi de finances pour 2025");
+        assert_eq!(
+            summary,
+            vec![
+                // Présidence exclue ; deux paragraphes consécutifs de PA2 fusionnés
+                ("PA2", pldf, "Madame la ministre,".len() as u32 + "ce budget…".chars().count() as u32),
+                // Sous-point : même titre de niveau 1, acteur retrouvé par l'orateur
+                ("PA3", pldf, 6),
+                ("PA2", pldf, 4),
+                ("PA2", Some("Questions au Gouvernement"), 9),
+            ]
+        );
+        let date = NaiveDate::from_ymd_opt(2025, 1, 7);
+        assert!(interventions.iter().all(|i| i.date == date && i.compte_rendu == "CRSANR5L17S2025O1N001"));
+    }
+
+    #[test]
+    fn dossiers_matched_by_title_when_unambiguous() {
+        let dossier = |id: &str, titre: &str| {
+            (id.to_string(), Dossier {
+                id: id.to_string(),
+                titre: titre.to_string(),
+                date_depot: None,
+                statut: None,
+                legislature: None,
+                nature: None,
+                numero: None,
+                auteur_id: None,
+                cosignataires_ids: vec![],
+                source_url: None,
+                origin_chamber: None,
+                initiateur_organe_ref: None,
+                rapporteurs: vec![],
+            })
+        };
+        let dossiers: HashMap<String, Dossier> = [
+            dossier("DLR1", "Loi de finances pour 2025"),
+            dossier("DLR2", "Fin de vie"),
+            dossier("DLR3", "Simplification de la vie économique"),
+            dossier("DLR4", "Simplification de la vie économique"),
+        ]
+        .into_iter()
+        .collect();
+        let mut interventions: Vec<Intervention> = [
+            "Projet de loi de finances pour 2025 (suite)",
+            "Fin de vie",
+            "Simplification de la vie économique",
+            "Questions au Gouvernement",
+        ]
+        .iter()
+        .map(|point| Intervention {
+            acteur_id: "PA1".into(),
+            date: None,
+            compte_rendu: "CR".into(),
+            point: Some(point.to_string()),
+            dossier_ref: None,
+            chars: 1,
+        })
+        .collect();
+
+        attribute_dossiers(&mut interventions, &dossiers);
+        let refs: Vec<Option<&str>> = interventions.iter().map(|i| i.dossier_ref.as_deref()).collect();
+        assert_eq!(refs, vec![Some("DLR1"), Some("DLR2"), None, None]);
+    }
+}
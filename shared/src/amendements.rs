@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// `data/dossiers_min.json` : mapping ID dossier -> titre.
+pub type DossiersMin = BTreeMap<String, String>;
+
+/// `data/amendements/index.json` : liste des shards mensuels du calendrier.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AmendementsIndex {
+    pub schema_version: u32,
+    pub generated_at: String,
+    pub months: Vec<AmendementsMonthMeta>,
+    pub undated_count: usize,
+    pub undated_file: String,
+    #[serde(default)]
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AmendementsMonthMeta {
+    pub month: String,
+    pub days: usize,
+    pub events: usize,
+}
+
+/// `data/amendements/months/YYYY-MM.json`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AmendementsMonthFile {
+    pub schema_version: u32,
+    pub month: String,
+    /// days[YYYY-MM-DD] = [events...]
+    pub days: BTreeMap<String, Vec<AmendementEvent>>,
+}
+
+/// Évènement du calendrier ; noms de champs courts pour limiter la taille des shards.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AmendementEvent {
+    /// Type d'évènement: DEPOT | CIRCULATION | EXAMEN | SORT
+    pub t: String,
+    /// ID amendement
+    pub id: String,
+    /// Numéro (si présent)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub n: Option<String>,
+    /// ID auteur (député)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aid: Option<String>,
+    /// Type d'auteur (Député, Groupe, etc.)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aty: Option<String>,
+    /// Cosignataires IDs
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cos: Vec<String>,
+    /// ID dossier
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub did: Option<String>,
+    /// Article (ex: "Art. 3")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub art: Option<String>,
+    /// Libellé de sort (uniquement pour t=SORT)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub s: Option<String>,
+    /// true si adopté (uniquement utile pour t=SORT)
+    #[serde(default)]
+    pub ok: bool,
+    /// Mission visée
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mis: Option<String>,
+    /// Mission ref
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mref: Option<String>,
+    /// Exposé sommaire
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exp: Option<String>,
+}
@@ -0,0 +1,308 @@
+//! Compteurs journaliers par député (`daily/deputes.json`) : le frontend en dérive les
+//! indicateurs d'une fenêtre `[début, fin]` quelconque, sans jeu de données précalculé.
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::DeputeStats;
+
+/// Compteurs d'un député pour un jour (`day` = nombre de jours depuis `day_start`).
+/// Les compteurs nuls ne sont pas écrits, pour garder le fichier compact.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct DayCounts {
+    pub day: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub votes_exprimes: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub pour: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub contre: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub abst: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub non_votant: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub amd_authored: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub amd_adopted: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub amd_cosigned: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub interventions: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub interventions_chars: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub questions_ecrites: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub questions_orales: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub questions_gouvernement: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub questions_repondues: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub commission_reunions: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub commission_presences: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub commission_excuses: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub votes_solennels_exprimes: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub votes_ordinaires_exprimes: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub mises_au_point: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub votes_par_delegation: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub loyalty_votes: u32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub dissent_count: u32,
+}
+
+fn is_zero(n: &u32) -> bool {
+    *n == 0
+}
+
+fn ratio(num: u32, den: u32) -> Option<f64> {
+    (den > 0).then(|| num as f64 / den as f64)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct DeputeDaily {
+    /// Périodes de mandat `[début, fin]` (inclusives) bornées à l'axe ; un scrutin
+    /// est éligible s'il tombe dans l'une d'elles.
+    #[serde(default)]
+    pub mandats: Vec<(NaiveDate, NaiveDate)>,
+    /// Jours avec au moins un compteur non nul, triés par `day`.
+    #[serde(default)]
+    pub jours: Vec<DayCounts>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DailyActivityFile {
+    pub schema_version: u32,
+    pub generated_at: String,
+    /// Premier jour de l'axe (début de législature).
+    pub day_start: NaiveDate,
+    pub days: usize,
+    /// Nombre de scrutins datés par jour (`scrutins[i]` = jour `day_start + i`).
+    pub scrutins: Vec<u32>,
+    /// Dont scrutins solennels / ordinaires, même indexation que `scrutins`.
+    #[serde(default)]
+    pub scrutins_solennels: Vec<u32>,
+    #[serde(default)]
+    pub scrutins_ordinaires: Vec<u32>,
+    #[serde(default)]
+    pub deputes: BTreeMap<String, DeputeDaily>,
+}
+
+/// Totaux d'un député sur une fenêtre, mêmes règles que `deputes_{P30,P180,LEG}.json`
+/// (hors amendements sans date).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RangeTotals {
+    /// Fenêtre effective (intersection avec le mandat) ; `None` si aucun jour de mandat.
+    pub effective: Option<(NaiveDate, NaiveDate)>,
+    pub scrutins_eligibles: u32,
+    pub votes_exprimes: u32,
+    pub pour: u32,
+    pub contre: u32,
+    pub abst: u32,
+    pub non_votant: u32,
+    pub amd_authored: u32,
+    pub amd_adopted: u32,
+    pub amd_cosigned: u32,
+    pub scrutins_solennels: u32,
+    pub scrutins_ordinaires: u32,
+    pub interventions: u32,
+    pub interventions_chars: u32,
+    pub questions_ecrites: u32,
+    pub questions_orales: u32,
+    pub questions_gouvernement: u32,
+    pub questions_repondues: u32,
+    pub commission_reunions: u32,
+    pub commission_presences: u32,
+    pub commission_excuses: u32,
+    pub votes_solennels_exprimes: u32,
+    pub votes_ordinaires_exprimes: u32,
+    pub mises_au_point: u32,
+    pub votes_par_delegation: u32,
+    pub loyalty_votes: u32,
+    pub dissent_count: u32,
+}
+
+impl RangeTotals {
+    /// Scrutins éligibles sans position exprimée ni non-votant.
+    pub fn absent(&self) -> u32 {
+        self.scrutins_eligibles
+            .saturating_sub(self.votes_exprimes)
+            .saturating_sub(self.non_votant)
+    }
+
+    pub fn participation_rate(&self) -> f64 {
+        if self.scrutins_eligibles > 0 {
+            self.votes_exprimes as f64 / self.scrutins_eligibles as f64
+        } else {
+            0.0
+        }
+    }
+
+    pub fn amd_adoption_rate(&self) -> Option<f64> {
+        (self.amd_authored > 0).then(|| self.amd_adopted as f64 / self.amd_authored as f64)
+    }
+
+    /// Taux de réponse hors questions au gouvernement (posées et répondues en séance).
+    pub fn questions_answer_rate(&self) -> Option<f64> {
+        ratio(self.questions_repondues, self.questions_ecrites + self.questions_orales)
+    }
+
+    pub fn commission_presence_rate(&self) -> Option<f64> {
+        ratio(self.commission_presences, self.commission_reunions)
+    }
+
+    pub fn loyalty_rate(&self) -> Option<f64> {
+        (self.loyalty_votes > 0).then(|| 1.0 - self.dissent_count as f64 / self.loyalty_votes as f64)
+    }
+}
+
+impl DailyActivityFile {
+    /// Dernier jour de l'axe (`None` si l'axe est vide).
+    pub fn day_end(&self) -> Option<NaiveDate> {
+        (self.days > 0).then(|| self.day_start + Duration::days(self.days as i64 - 1))
+    }
+
+    /// Totaux de `deputy_id` sur `[start, end]` (bornes incluses, ramenées à l'axe).
+    pub fn range_totals(&self, deputy_id: &str, start: NaiveDate, end: NaiveDate) -> RangeTotals {
+        let mut out = RangeTotals::default();
+        let (Some(dep), Some(day_end)) = (self.deputes.get(deputy_id), self.day_end()) else {
+            return out;
+        };
+        let (start, end) = (start.max(self.day_start), end.min(day_end));
+
+        let windows: Vec<(NaiveDate, NaiveDate)> = dep
+            .mandats
+            .iter()
+            .map(|&(s, e)| (s.max(start), e.min(end)))
+            .filter(|(s, e)| s <= e)
+            .collect();
+        let (Some(first), Some(last)) = (windows.first(), windows.last()) else {
+            return out;
+        };
+        out.effective = Some((first.0, last.1));
+
+        let index_of = |d: NaiveDate| (d - self.day_start).num_days() as usize;
+        // Fichiers antérieurs sans ventilation solennel / ordinaire : compteurs à zéro
+        let sum = |per_day: &[u32], s: NaiveDate, e: NaiveDate| -> u32 {
+            per_day.get(index_of(s)..=index_of(e)).map_or(0, |days| days.iter().sum())
+        };
+        for &(s, e) in &windows {
+            out.scrutins_eligibles += sum(&self.scrutins, s, e);
+            out.scrutins_solennels += sum(&self.scrutins_solennels, s, e);
+            out.scrutins_ordinaires += sum(&self.scrutins_ordinaires, s, e);
+        }
+
+        let in_windows = |day: u32| {
+            let date = self.day_start + Duration::days(day as i64);
+            windows.iter().any(|&(s, e)| date >= s && date <= e)
+        };
+        for c in dep.jours.iter().filter(|c| in_windows(c.day)) {
+            out.votes_exprimes += c.votes_exprimes;
+            out.pour += c.pour;
+            out.contre += c.contre;
+            out.abst += c.abst;
+            out.non_votant += c.non_votant;
+            out.amd_authored += c.amd_authored;
+            out.amd_adopted += c.amd_adopted;
+            out.amd_cosigned += c.amd_cosigned;
+            out.interventions += c.interventions;
+            out.interventions_chars = out.interventions_chars.saturating_add(c.interventions_chars);
+            out.questions_ecrites += c.questions_ecrites;
+            out.questions_orales += c.questions_orales;
+            out.questions_gouvernement += c.questions_gouvernement;
+            out.questions_repondues += c.questions_repondues;
+            out.commission_reunions += c.commission_reunions;
+            out.commission_presences += c.commission_presences;
+            out.commission_excuses += c.commission_excuses;
+            out.votes_solennels_exprimes += c.votes_solennels_exprimes;
+            out.votes_ordinaires_exprimes += c.votes_ordinaires_exprimes;
+            out.mises_au_point += c.mises_au_point;
+            out.votes_par_delegation += c.votes_par_delegation;
+            out.loyalty_votes += c.loyalty_votes;
+            out.dissent_count += c.dissent_count;
+        }
+        out
+    }
+
+    /// Recalcule `base` (ligne LEG : identité, mandat) sur `[start, end]`. Seules l'identité
+    /// et les appartenances sont reprises de `base` ; les indicateurs non additifs jour par
+    /// jour (top dossiers, rapports, derniers votes dissidents, co-signataires) restent à
+    /// leur valeur par défaut et ne sont pas affichés sur une période personnalisée.
+    pub fn stats_for_range(&self, base: &DeputeStats, start: NaiveDate, end: NaiveDate) -> DeputeStats {
+        let t = self.range_totals(&base.deputy_id, start, end);
+        let (period_start, period_end) = t.effective.unwrap_or((start, end));
+        DeputeStats {
+            period_start,
+            period_end,
+            scrutins_eligibles: t.scrutins_eligibles,
+            votes_exprimes: t.votes_exprimes,
+            non_votant: t.non_votant,
+            absent: t.absent(),
+            participation_rate: t.participation_rate(),
+            pour_count: t.pour,
+            contre_count: t.contre,
+            abst_count: t.abst,
+            amd_authored: t.amd_authored,
+            amd_adopted: t.amd_adopted,
+            amd_adoption_rate: t.amd_adoption_rate(),
+            amd_cosigned: t.amd_cosigned,
+            interventions_count: t.interventions,
+            interventions_chars: t.interventions_chars,
+            loyalty_votes: t.loyalty_votes,
+            dissent_count: t.dissent_count,
+            loyalty_rate: t.loyalty_rate(),
+            scrutins_solennels: t.scrutins_solennels,
+            votes_solennels_exprimes: t.votes_solennels_exprimes,
+            participation_rate_solennel: ratio(t.votes_solennels_exprimes, t.scrutins_solennels),
+            scrutins_ordinaires: t.scrutins_ordinaires,
+            votes_ordinaires_exprimes: t.votes_ordinaires_exprimes,
+            participation_rate_ordinaire: ratio(t.votes_ordinaires_exprimes, t.scrutins_ordinaires),
+            mises_au_point: t.mises_au_point,
+            votes_par_delegation: t.votes_par_delegation,
+            questions_ecrites: t.questions_ecrites,
+            questions_orales: t.questions_orales,
+            questions_gouvernement: t.questions_gouvernement,
+            questions_repondues: t.questions_repondues,
+            questions_answer_rate: t.questions_answer_rate(),
+            commission_reunions: t.commission_reunions,
+            commission_presences: t.commission_presences,
+            commission_excuses: t.commission_excuses,
+            commission_presence_rate: t.commission_presence_rate(),
+            deputy_id: base.deputy_id.clone(),
+            nom: base.nom.clone(),
+            prenom: base.prenom.clone(),
+            groupe_abrev: base.groupe_abrev.clone(),
+            groupe_nom: base.groupe_nom.clone(),
+            groupe_id: base.groupe_id.clone(),
+            parti_rattachement: base.parti_rattachement.clone(),
+            dept: base.dept.clone(),
+            circo: base.circo.clone(),
+            mandat_debut: base.mandat_debut,
+            mandat_fin: base.mandat_fin,
+            mandat_debut_legislature: base.mandat_debut_legislature,
+            mandat_assemblee_episodes: base.mandat_assemblee_episodes.clone(),
+            date_naissance: base.date_naissance,
+            sexe: base.sexe.clone(),
+            pays_naissance: base.pays_naissance.clone(),
+            profession: base.profession.clone(),
+            email_assemblee: base.email_assemblee.clone(),
+            site_web: base.site_web.clone(),
+            sites_web: base.sites_web.clone(),
+            sites_web_sources: base.sites_web_sources.clone(),
+            telephones: base.telephones.clone(),
+            uri_hatvp: base.uri_hatvp.clone(),
+            commissions: base.commissions.clone(),
+            groupes: base.groupes.clone(),
+            ..DeputeStats::default()
+        }
+    }
+}
@@ -0,0 +1,347 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum VotePosition {
+    Pour,
+    Contre,
+    Abstention,
+    NonVotant,
+    Absent,
+}
+
+impl VotePosition {
+    pub fn label(&self) -> &'static str {
+        match self {
+            VotePosition::Pour => "Pour",
+            VotePosition::Contre => "Contre",
+            VotePosition::Abstention => "Abstention",
+            VotePosition::NonVotant => "Non-votant",
+            VotePosition::Absent => "Absent",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SiteWebSource {
+    pub type_libelle: Option<String>,
+    pub val_elec: String,
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MandatAssembleeEpisode {
+    pub date_debut: NaiveDate,
+    #[serde(default)]
+    pub date_fin: Option<NaiveDate>,
+}
+
+/// Appartenance datée à un groupe politique, comme membre ou apparenté.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GroupeMembership {
+    pub organe_id: String,
+    #[serde(default)]
+    pub abrev: Option<String>,
+    pub libelle: String,
+    /// Qualité AN : « Membre », « Membre apparenté », « Président »… (vide si déduite).
+    #[serde(default)]
+    pub qualite: String,
+    #[serde(default)]
+    pub apparente: bool,
+    #[serde(default)]
+    pub date_debut: Option<NaiveDate>,
+    #[serde(default)]
+    pub date_fin: Option<NaiveDate>,
+    /// Période absente du dataset députés, déduite du groupe sous lequel le député est décompté
+    /// dans les scrutins.
+    #[serde(default)]
+    pub deduite: bool,
+}
+
+impl GroupeMembership {
+    pub fn covers(&self, date: NaiveDate) -> bool {
+        self.date_debut.is_none_or(|d| d <= date) && self.date_fin.is_none_or(|f| date <= f)
+    }
+}
+
+/// Groupe du député à `date` (appartenances triées par date de début : la plus récente l'emporte
+/// en cas de chevauchement).
+pub fn groupe_at(groupes: &[GroupeMembership], date: NaiveDate) -> Option<&GroupeMembership> {
+    groupes.iter().rev().find(|g| g.covers(date))
+}
+
+/// Appartenance à une commission (permanente `COMPER`, mixte paritaire `CMP`, d'enquête `CNPE`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CommissionMembership {
+    pub organe_id: String,
+    pub type_organe: String,
+    pub libelle: String,
+    #[serde(default)]
+    pub abrev: Option<String>,
+    /// Qualité AN : « Président », « Vice-président », « Secrétaire », « Rapporteur général »,
+    /// « Membre »…
+    pub qualite: String,
+    #[serde(default)]
+    pub date_debut: Option<NaiveDate>,
+    #[serde(default)]
+    pub date_fin: Option<NaiveDate>,
+}
+
+impl CommissionMembership {
+    /// Fonction au-delà de la simple appartenance (président, rapporteur, secrétaire…).
+    pub fn has_role(&self) -> bool {
+        !self.qualite.trim().to_lowercase().starts_with("membre")
+    }
+}
+
+/// Commission permanente en cours (au plus une par député).
+pub fn commission_permanente(commissions: &[CommissionMembership]) -> Option<&CommissionMembership> {
+    commissions.iter().find(|c| c.type_organe == "COMPER" && c.date_fin.is_none())
+}
+
+/// Entrée de `data/deputes.json` (et des chunks `deputes_pN.json`) : fiche de base pour le listing.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeputeInfo {
+    pub id: String,
+    pub nom: String,
+    pub prenom: String,
+    pub date_naissance: Option<NaiveDate>,
+    #[serde(default)]
+    pub sexe: Option<String>,
+    pub pays_naissance: Option<String>,
+    pub profession: Option<String>,
+    pub groupe_abrev: Option<String>,
+    pub groupe_nom: Option<String>,
+    pub parti_nom: Option<String>,
+    pub dept_code: Option<String>,
+    pub dept_nom: Option<String>,
+    pub circo: Option<String>,
+    pub mandat_debut: Option<NaiveDate>,
+    #[serde(default)]
+    pub mandat_fin: Option<NaiveDate>,
+    #[serde(default)]
+    pub mandat_debut_legislature: Option<NaiveDate>,
+    #[serde(default)]
+    pub mandat_assemblee_episodes: Vec<MandatAssembleeEpisode>,
+    #[serde(default)]
+    pub mandat_assemblee_episode_count: usize,
+    /// "Mandat AN épisode N: début → fin" (lisible dans les exports bruts).
+    #[serde(default)]
+    pub mandat_assemblee_episode_labels: Vec<String>,
+    pub email_assemblee: Option<String>,
+    pub site_web: Option<String>,
+    #[serde(default)]
+    pub sites_web: Vec<String>,
+    #[serde(default)]
+    pub sites_web_sources: Vec<SiteWebSource>,
+    #[serde(default)]
+    pub telephones: Vec<String>,
+    pub uri_hatvp: Option<String>,
+    /// Commissions en cours ou passées, permanentes d'abord.
+    #[serde(default)]
+    pub commissions: Vec<CommissionMembership>,
+    /// Parcours : groupes successifs, du plus ancien au plus récent.
+    #[serde(default)]
+    pub groupes: Vec<GroupeMembership>,
+}
+
+/// Entrée de `data/deputes_{P30,P180,LEG}.json` : indicateurs d'un député sur une période.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct DeputeStats {
+    pub deputy_id: String,
+    pub nom: String,
+    pub prenom: String,
+    pub groupe_abrev: Option<String>,
+    pub groupe_nom: Option<String>,
+    /// Organe (PO…) du groupe actuel, pour les liens vers la fiche groupe.
+    #[serde(default)]
+    pub groupe_id: Option<String>,
+    pub parti_rattachement: Option<String>,
+    pub dept: Option<String>,
+    pub circo: Option<String>,
+    pub mandat_debut: Option<NaiveDate>,
+    #[serde(default)]
+    pub mandat_fin: Option<NaiveDate>,
+    #[serde(default)]
+    pub mandat_debut_legislature: Option<NaiveDate>,
+    #[serde(default)]
+    pub mandat_assemblee_episodes: Vec<MandatAssembleeEpisode>,
+    pub date_naissance: Option<NaiveDate>,
+    #[serde(default)]
+    pub sexe: Option<String>,
+    pub pays_naissance: Option<String>,
+    pub profession: Option<String>,
+    pub email_assemblee: Option<String>,
+    pub site_web: Option<String>,
+    #[serde(default)]
+    pub sites_web: Vec<String>,
+    #[serde(default)]
+    pub sites_web_sources: Vec<SiteWebSource>,
+    #[serde(default)]
+    pub telephones: Vec<String>,
+    pub uri_hatvp: Option<String>,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub scrutins_eligibles: u32,
+    pub votes_exprimes: u32,
+    pub non_votant: u32,
+    pub absent: u32,
+    pub participation_rate: f64,
+    pub pour_count: u32,
+    pub contre_count: u32,
+    pub abst_count: u32,
+    pub amd_authored: u32,
+    pub amd_adopted: u32,
+    pub amd_adoption_rate: Option<f64>,
+    pub amd_cosigned: u32,
+    pub interventions_count: u32,
+    pub interventions_chars: u32,
+    pub top_dossiers: Vec<DossierScore>,
+    #[serde(default)]
+    pub top_cosignataires: Vec<TopCosignataire>,
+    #[serde(default)]
+    pub cosign_network: Option<CosignNetworkStats>,
+    /// Votes exprimés comparables à une position majoritaire (pour/contre/abstention) de leur groupe.
+    #[serde(default)]
+    pub loyalty_votes: u32,
+    #[serde(default)]
+    pub dissent_count: u32,
+    /// 1 − dissent_count / loyalty_votes (null si aucun vote comparable).
+    #[serde(default)]
+    pub loyalty_rate: Option<f64>,
+    #[serde(default)]
+    pub recent_dissents: Vec<DissentVote>,
+    /// Participation ventilée par type de vote (SPS = solennel, SPO = ordinaire ;
+    /// motions de censure et types inconnus n'entrent dans aucune des deux).
+    #[serde(default)]
+    pub scrutins_solennels: u32,
+    #[serde(default)]
+    pub votes_solennels_exprimes: u32,
+    /// votes_solennels_exprimes / scrutins_solennels (null si aucun scrutin solennel).
+    #[serde(default)]
+    pub participation_rate_solennel: Option<f64>,
+    #[serde(default)]
+    pub scrutins_ordinaires: u32,
+    #[serde(default)]
+    pub votes_ordinaires_exprimes: u32,
+    #[serde(default)]
+    pub participation_rate_ordinaire: Option<f64>,
+    /// Scrutins de la période ayant fait l'objet d'une mise au point du député.
+    #[serde(default)]
+    pub mises_au_point: u32,
+    /// Votes exprimés par délégation (le député a confié son vote à un collègue).
+    #[serde(default)]
+    pub votes_par_delegation: u32,
+    /// Questions posées sur la période (date de publication de la question), par type.
+    #[serde(default)]
+    pub questions_ecrites: u32,
+    #[serde(default)]
+    pub questions_orales: u32,
+    #[serde(default)]
+    pub questions_gouvernement: u32,
+    /// Questions écrites et orales sans débat de la période ayant reçu une réponse.
+    #[serde(default)]
+    pub questions_repondues: u32,
+    /// questions_repondues / (questions_ecrites + questions_orales) ; les questions au
+    /// Gouvernement, posées et répondues en séance, n'entrent pas dans le taux.
+    #[serde(default)]
+    pub questions_answer_rate: Option<f64>,
+    #[serde(default)]
+    pub commissions: Vec<CommissionMembership>,
+    #[serde(default)]
+    pub groupes: Vec<GroupeMembership>,
+    /// Réunions de commission de la période où le député était convoqué (présent, absent ou
+    /// excusé), et sa présence à ces réunions.
+    #[serde(default)]
+    pub commission_reunions: u32,
+    #[serde(default)]
+    pub commission_presences: u32,
+    #[serde(default)]
+    pub commission_excuses: u32,
+    /// commission_presences / commission_reunions (null si aucune réunion).
+    #[serde(default)]
+    pub commission_presence_rate: Option<f64>,
+    /// Dossiers dont le député a été nommé rapporteur sur la période : au fond (y compris
+    /// rapporteur général), pour avis, spécial (budget).
+    #[serde(default)]
+    pub rapporteur_fond: u32,
+    #[serde(default)]
+    pub rapporteur_avis: u32,
+    #[serde(default)]
+    pub rapporteur_special: u32,
+}
+
+/// Vote d'un député différent de la position majoritaire de son groupe.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DissentVote {
+    pub scrutin_id: String,
+    pub numero: u32,
+    pub date: NaiveDate,
+    pub titre: String,
+    pub position: VotePosition,
+    pub groupe_position: VotePosition,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DossierScore {
+    pub dossier_id: String,
+    pub titre: String,
+    pub votes: u32,
+    pub amendements: u32,
+    pub interventions: u32,
+    /// Rôle de rapporteur du député sur le dossier (`fond`, `special` ou `avis`), s'il en a un.
+    #[serde(default)]
+    pub rapporteur: Option<String>,
+    pub score: u32,
+}
+
+/// Libellé d'un rôle de rapporteur : `fond`, `avis`, `special`.
+pub fn rapporteur_role_label(code: &str) -> &'static str {
+    match code {
+        "avis" => "Rapporteur pour avis",
+        "special" => "Rapporteur spécial",
+        _ => "Rapporteur",
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TopCosignataire {
+    pub deputy_id: String,
+    pub nom: String,
+    pub prenom: String,
+    pub groupe_abrev: Option<String>,
+    #[serde(alias = "co_signature_count")]
+    pub co_signed_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CosignNetworkStats {
+    pub total_cosignatures: u32,
+    pub unique_cosignataires: u32,
+    pub in_group_count: u32,
+    pub out_group_count: u32,
+    #[serde(default)]
+    pub in_group: Vec<CosignPeer>,
+    #[serde(default)]
+    pub out_group_groups: Vec<CosignGroupBucket>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CosignPeer {
+    pub deputy_id: String,
+    pub nom: String,
+    pub prenom: String,
+    pub groupe_abrev: Option<String>,
+    pub groupe_nom: Option<String>,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CosignGroupBucket {
+    pub groupe_abrev: Option<String>,
+    pub groupe_nom: Option<String>,
+    pub count_total: u32,
+    #[serde(default)]
+    pub members: Vec<CosignPeer>,
+}
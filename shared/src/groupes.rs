@@ -0,0 +1,106 @@
+//! Groupes parlementaires : index (`groupes/index.json`) et fiche par groupe (`groupes/<id>.json`).
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct GroupesIndex {
+    pub schema_version: u32,
+    pub generated_at: String,
+    pub total: usize,
+    /// Triés par nombre de membres décroissant.
+    #[serde(default)]
+    pub groupes: Vec<GroupeIndexEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GroupeIndexEntry {
+    pub id: String,
+    #[serde(default)]
+    pub abrev: Option<String>,
+    pub libelle: String,
+    pub membres: usize,
+    pub file: String,
+}
+
+/// Fiche d'un groupe parlementaire (`groupes/<id>.json`), fenêtre LEG.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GroupeDetail {
+    pub schema_version: u32,
+    pub generated_at: String,
+    pub id: String,
+    #[serde(default)]
+    pub abrev: Option<String>,
+    pub libelle: String,
+    #[serde(default)]
+    pub couleur: Option<String>,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    /// Membres actuels (triés par nom) avec leurs indicateurs LEG.
+    #[serde(default)]
+    pub membres: Vec<GroupeMembre>,
+    /// Changements de groupe observés dans les ventilations de scrutins (du plus récent au plus ancien).
+    #[serde(default)]
+    pub mouvements: Vec<GroupeMouvement>,
+    /// Médiane des taux de participation des membres ayant au moins un scrutin éligible.
+    #[serde(default)]
+    pub participation_median: Option<f64>,
+    /// Amendements déposés sur la période par des députés membres du groupe à la date de dépôt
+    /// (anciens membres compris).
+    pub amd_authored: u32,
+    pub amd_adopted: u32,
+    #[serde(default)]
+    pub amd_adoption_rate: Option<f64>,
+    #[serde(default)]
+    pub cohesion: GroupeCohesion,
+    /// Propositions de loi (agrégat `positions-groupes/ppl`), absent si aucune.
+    #[serde(default)]
+    pub ppl: Option<GroupePpl>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GroupeMembre {
+    pub deputy_id: String,
+    pub nom: String,
+    pub prenom: String,
+    pub scrutins_eligibles: u32,
+    pub participation_rate: f64,
+    pub amd_authored: u32,
+    pub amd_adopted: u32,
+    #[serde(default)]
+    pub loyalty_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GroupeMouvement {
+    pub deputy_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nom: Option<String>,
+    /// Date du premier scrutin décompté dans le nouveau groupe.
+    pub date: NaiveDate,
+    /// "arrivee" | "depart"
+    pub sens: String,
+    /// Groupe quitté (arrivée) ou rejoint (départ).
+    pub autre_groupe_ref: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub autre_groupe_abrev: Option<String>,
+}
+
+/// Cohésion sur les scrutins : part des votes exprimés du groupe alignés sur sa position la plus fréquente.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct GroupeCohesion {
+    pub scrutins: u32,
+    #[serde(default)]
+    pub cohesion_moyenne: Option<f64>,
+    /// Scrutins où tous les votes exprimés du groupe sont identiques.
+    pub unanimes: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GroupePpl {
+    pub ppl_count: usize,
+    pub authored_ppl_count: usize,
+    pub cosigned_only_ppl_count: usize,
+    /// Shard PPL relatif à `positions-groupes/ppl/`.
+    pub file: String,
+}
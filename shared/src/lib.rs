@@ -0,0 +1,26 @@
+//! Contrats de données partagés entre le pipeline (écriture des JSON sous `data/`)
+//! et le frontend (lecture). Tout champ exporté est défini ici une seule fois.
+
+pub mod amendements;
+pub mod daily;
+pub mod deputes;
+pub mod groupes;
+pub mod manifest;
+pub mod ppl;
+pub mod questions;
+pub mod scrutins;
+pub mod series;
+pub mod sessions;
+pub mod status;
+
+pub use amendements::*;
+pub use daily::*;
+pub use deputes::*;
+pub use groupes::*;
+pub use manifest::*;
+pub use ppl::*;
+pub use questions::*;
+pub use scrutins::*;
+pub use series::*;
+pub use sessions::*;
+pub use status::*;
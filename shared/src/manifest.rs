@@ -0,0 +1,40 @@
+//! Manifeste `data/manifest.json` : inventaire des fichiers exportés et version du contrat.
+
+use serde::{Deserialize, Serialize};
+
+/// Version du contrat de données. À incrémenter à chaque changement incompatible d'un JSON
+/// exporté (champ renommé ou supprimé, type modifié) ; un ajout de champ `#[serde(default)]`
+/// reste compatible et ne la change pas.
+pub const DATA_SCHEMA_VERSION: u32 = 2;
+
+/// Chemin du manifeste, relatif à `data/`.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Manifest {
+    /// Version du contrat ([`DATA_SCHEMA_VERSION`] du pipeline qui a produit les données).
+    pub schema_version: u32,
+    pub generated_at: String,
+    pub total_bytes: u64,
+    /// Tous les fichiers de `data/` (hors manifeste), triés par chemin.
+    #[serde(default)]
+    pub files: Vec<ManifestFile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ManifestFile {
+    /// Chemin relatif à `data/`, séparateur `/`.
+    pub path: String,
+    /// `schema_version` (ou `version`) déclaré par le fichier, sinon version du contrat.
+    pub schema_version: u32,
+    pub bytes: u64,
+    /// SHA-256 du contenu, en hexadécimal.
+    pub sha256: String,
+}
+
+impl Manifest {
+    /// Vrai si les données ont été produites pour la version de contrat compilée ici.
+    pub fn is_compatible(&self) -> bool {
+        self.schema_version == DATA_SCHEMA_VERSION
+    }
+}
@@ -0,0 +1,104 @@
+//! Propositions de loi par groupe (`positions-groupes/ppl/`) et par député (`positions-deputes/ppl/`).
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupPplIndex {
+    pub version: u8,
+    pub generated_at: String,
+    pub total_groups: usize,
+    pub total_ppl_links: usize,
+    pub total_unique_ppl: usize,
+    #[serde(default)]
+    pub groups: Vec<GroupPplGroupIndexEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupPplGroupIndexEntry {
+    pub group_id: String,
+    pub group_label: String,
+    pub ppl_count: usize,
+    pub authored_ppl_count: usize,
+    pub cosigned_only_ppl_count: usize,
+    pub file: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupPplGroupShard {
+    pub version: u8,
+    pub generated_at: String,
+    pub group_id: String,
+    pub group_label: String,
+    pub total_entries: usize,
+    #[serde(default)]
+    pub items: Vec<GroupPplItemSummary>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupPplItemSummary {
+    pub ppl_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legislature: Option<u8>,
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deposit_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<String>,
+    pub author_count: u16,
+    pub cosigner_count: u16,
+    pub total_signers_from_group: u16,
+    pub has_author: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub signer_names_preview: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub signers_preview: Vec<SignerPreviewEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SignerPreviewEntry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deputy_id: Option<String>,
+    pub deputy_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DeputyPplShard {
+    pub version: u8,
+    pub generated_at: String,
+    pub deputy_id: String,
+    pub deputy_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_label: Option<String>,
+    pub total_entries: usize,
+    pub authored_count: usize,
+    pub cosigned_only_count: usize,
+    #[serde(default)]
+    pub items: Vec<DeputyPplItemSummary>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DeputyPplItemSummary {
+    pub ppl_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legislature: Option<u8>,
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deposit_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<String>,
+    pub is_author: bool,
+    pub is_cosigner: bool,
+}
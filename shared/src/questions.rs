@@ -0,0 +1,55 @@
+//! Questions au Gouvernement (écrites, orales sans débat, questions au Gouvernement) :
+//! un fichier par député (`questions/deputes/<id>.json`).
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Libellé d'un type de question : `QE` (écrite), `QOSD` (orale sans débat), `QAG` (au Gouvernement).
+pub fn question_type_label(code: &str) -> &'static str {
+    match code {
+        "QE" => "Question écrite",
+        "QOSD" => "Question orale sans débat",
+        "QAG" => "Question au Gouvernement",
+        _ => "Question",
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Question {
+    /// uid AN (`QANR5L17QE1234`).
+    pub id: String,
+    /// `QE`, `QOSD` ou `QAG`.
+    pub type_question: String,
+    #[serde(default)]
+    pub numero: Option<u32>,
+    pub auteur_id: String,
+    /// Ministère interrogé.
+    #[serde(default)]
+    pub ministere: Option<String>,
+    /// Rubrique d'indexation AN (ex. « énergie et carburants »).
+    #[serde(default)]
+    pub rubrique: Option<String>,
+    /// Analyse (objet résumé de la question).
+    #[serde(default)]
+    pub titre: Option<String>,
+    /// Publication de la question au Journal officiel.
+    #[serde(default)]
+    pub date_question: Option<NaiveDate>,
+    /// Publication de la réponse au Journal officiel.
+    #[serde(default)]
+    pub date_reponse: Option<NaiveDate>,
+    pub repondue: bool,
+    /// Libellé de clôture AN (« Réponse publiée », « Retrait », « Caducité »…).
+    #[serde(default)]
+    pub cloture: Option<String>,
+}
+
+/// `questions/deputes/<id>.json` : questions du député, la plus récente en premier.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeputeQuestions {
+    pub schema_version: u32,
+    pub generated_at: String,
+    pub deputy_id: String,
+    #[serde(default)]
+    pub questions: Vec<Question>,
+}
@@ -0,0 +1,122 @@
+//! Scrutins : index compact (`scrutins/index.json`) et détail par scrutin (`scrutins/detail/<id>.json`).
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::deputes::VotePosition;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ScrutinsIndex {
+    pub schema_version: u32,
+    pub generated_at: String,
+    pub total: usize,
+    /// Plus récent d'abord (date puis numéro décroissants).
+    #[serde(default)]
+    pub scrutins: Vec<ScrutinIndexEntry>,
+}
+
+/// Entrée compacte de l'index (page d'exploration) ; le détail est dans `detail/<id>.json`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ScrutinIndexEntry {
+    pub id: String,
+    pub numero: u32,
+    #[serde(default)]
+    pub date: Option<NaiveDate>,
+    pub titre: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dossier_ref: Option<String>,
+    /// Code AN (SPO, SPS, MOC…).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_vote: Option<String>,
+    pub pour: u32,
+    pub contre: u32,
+    pub abstentions: u32,
+    pub non_votants: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScrutinDetail {
+    pub schema_version: u32,
+    pub generated_at: String,
+    pub id: String,
+    pub numero: u32,
+    #[serde(default)]
+    pub date: Option<NaiveDate>,
+    pub titre: String,
+    #[serde(default)]
+    pub sort: Option<String>,
+    #[serde(default)]
+    pub dossier_ref: Option<String>,
+    #[serde(default)]
+    pub dossier_titre: Option<String>,
+    #[serde(default)]
+    pub type_vote: Option<String>,
+    #[serde(default)]
+    pub type_vote_libelle: Option<String>,
+    pub totals: VoteTotals,
+    #[serde(default)]
+    pub groupes: Vec<ScrutinDetailGroupe>,
+    /// Liste nominative par position, triée par nom.
+    #[serde(default)]
+    pub pour: Vec<ScrutinVotant>,
+    #[serde(default)]
+    pub contre: Vec<ScrutinVotant>,
+    #[serde(default)]
+    pub abstention: Vec<ScrutinVotant>,
+    #[serde(default)]
+    pub non_votant: Vec<ScrutinVotant>,
+    /// Mises au point : position enregistrée vs position voulue, triées par nom.
+    #[serde(default)]
+    pub mises_au_point: Vec<ScrutinMiseAuPoint>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct VoteTotals {
+    pub pour: u32,
+    pub contre: u32,
+    pub abstentions: u32,
+    pub non_votants: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScrutinDetailGroupe {
+    pub organe_ref: String,
+    #[serde(default)]
+    pub abrev: Option<String>,
+    #[serde(default)]
+    pub libelle: Option<String>,
+    #[serde(default)]
+    pub nombre_membres: Option<u32>,
+    #[serde(default)]
+    pub position_majoritaire: Option<VotePosition>,
+    pub pour: u32,
+    pub contre: u32,
+    pub abstentions: u32,
+    pub non_votants: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScrutinVotant {
+    pub deputy_id: String,
+    /// "Prénom Nom" si le député figure dans le référentiel, sinon absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nom: Option<String>,
+    /// Groupe (organeRef) sous lequel le vote est décompté.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub groupe_ref: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub par_delegation: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScrutinMiseAuPoint {
+    pub deputy_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nom: Option<String>,
+    /// Position du décompte nominatif (absente si le député n'y figure pas).
+    #[serde(default)]
+    pub position_enregistree: Option<VotePosition>,
+    pub position_voulue: VotePosition,
+}
@@ -0,0 +1,109 @@
+//! Séries hebdomadaires (semaines ISO) : `series/deputes/<id>.json`, `series/groupes.json`
+//! et l'aperçu `series/index.json` utilisé par le tableau d'accueil.
+
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Compteurs par semaine ; tous les vecteurs ont la longueur de l'axe (`weeks`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct WeeklyBuckets {
+    pub scrutins_eligibles: Vec<u32>,
+    pub votes_exprimes: Vec<u32>,
+    pub amd_authored: Vec<u32>,
+    pub amd_adopted: Vec<u32>,
+    pub amd_cosigned: Vec<u32>,
+}
+
+impl WeeklyBuckets {
+    pub fn with_weeks(weeks: usize) -> Self {
+        Self {
+            scrutins_eligibles: vec![0; weeks],
+            votes_exprimes: vec![0; weeks],
+            amd_authored: vec![0; weeks],
+            amd_adopted: vec![0; weeks],
+            amd_cosigned: vec![0; weeks],
+        }
+    }
+
+    /// Additionne `other` semaine par semaine (même axe).
+    pub fn add(&mut self, other: &WeeklyBuckets) {
+        let pairs = [
+            (&mut self.scrutins_eligibles, &other.scrutins_eligibles),
+            (&mut self.votes_exprimes, &other.votes_exprimes),
+            (&mut self.amd_authored, &other.amd_authored),
+            (&mut self.amd_adopted, &other.amd_adopted),
+            (&mut self.amd_cosigned, &other.amd_cosigned),
+        ];
+        for (acc, values) in pairs {
+            for (a, v) in acc.iter_mut().zip(values) {
+                *a += v;
+            }
+        }
+    }
+
+    /// Taux de participation par semaine (`None` : aucun scrutin éligible cette semaine-là).
+    pub fn participation(&self) -> Vec<Option<f64>> {
+        self.scrutins_eligibles
+            .iter()
+            .zip(&self.votes_exprimes)
+            .map(|(&e, &v)| (e > 0).then(|| v as f64 / e as f64))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeputeSeries {
+    pub schema_version: u32,
+    pub generated_at: String,
+    pub deputy_id: String,
+    /// Lundi de la première semaine ISO de l'axe (semaine du début de législature).
+    pub week_start: NaiveDate,
+    pub weeks: usize,
+    pub series: WeeklyBuckets,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GroupesSeries {
+    pub schema_version: u32,
+    pub generated_at: String,
+    pub week_start: NaiveDate,
+    pub weeks: usize,
+    #[serde(default)]
+    pub groupes: Vec<GroupeSeries>,
+}
+
+/// Somme des séries des députés, chaque jour étant attribué au groupe d'appartenance à la date.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GroupeSeries {
+    pub id: String,
+    #[serde(default)]
+    pub abrev: Option<String>,
+    /// Effectif actuel du groupe (ordre d'affichage) ; les semaines passées peuvent inclure
+    /// d'anciens membres.
+    pub membres_actuels: usize,
+    pub series: WeeklyBuckets,
+}
+
+/// Aperçu des dernières semaines pour tous les députés (sparklines du tableau d'accueil).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct SeriesIndex {
+    pub schema_version: u32,
+    pub generated_at: String,
+    pub week_start: NaiveDate,
+    pub weeks: usize,
+    /// Participation hebdomadaire en pourcentage entier, `null` sans scrutin éligible.
+    #[serde(default)]
+    pub participation: BTreeMap<String, Vec<Option<u8>>>,
+}
+
+/// Lundi de la semaine ISO contenant `date`.
+pub fn iso_week_monday(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// Libellé ISO ("2025-W03") de la `index`-ième semaine d'un axe commençant à `week_start`.
+pub fn iso_week_label(week_start: NaiveDate, index: usize) -> String {
+    let week = (week_start + Duration::weeks(index as i64)).iso_week();
+    format!("{}-W{:02}", week.year(), week.week())
+}
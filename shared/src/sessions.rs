@@ -0,0 +1,47 @@
+//! Sessions parlementaires : `sessions/index.json` et un fichier `DeputeStats` par session
+//! (`sessions/deputes_<id>.json`, même format que `deputes_{P30,P180,LEG}.json`).
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionKind {
+    /// Du premier jour ouvrable d'octobre au dernier jour ouvrable de juin (art. 28 C).
+    Ordinaire,
+    /// Convoquée par décret hors session ordinaire (en pratique juillet–septembre).
+    Extraordinaire,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Session {
+    /// "SO-2024-2025" (ordinaire) ou "SE-2025" (extraordinaire).
+    pub id: String,
+    pub label: String,
+    pub kind: SessionKind,
+    /// Bornes incluses, ramenées à [début de législature, date de génération].
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    /// Session non terminée à la date de génération.
+    #[serde(default)]
+    pub en_cours: bool,
+    /// Scrutins datés dans la session.
+    #[serde(default)]
+    pub scrutins: u32,
+}
+
+impl Session {
+    /// Chemin (relatif à `data/`) du fichier `DeputeStats` de la session.
+    pub fn stats_file(&self) -> String {
+        format!("sessions/deputes_{}.json", self.id)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct SessionsIndex {
+    pub schema_version: u32,
+    pub generated_at: String,
+    /// Sessions de la législature, de la plus récente à la plus ancienne.
+    #[serde(default)]
+    pub sessions: Vec<Session>,
+}
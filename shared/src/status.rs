@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// `data/status.json` : date de génération et sources téléchargées.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Status {
+    pub last_update: String,
+    pub last_update_readable: String,
+    pub legislature: u32,
+    /// Début de la fenêtre LEG (absent des status.json antérieurs).
+    #[serde(default)]
+    pub legislature_start: Option<NaiveDate>,
+    pub sources: Vec<SourceInfo>,
+    pub counts: Counts,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SourceInfo {
+    pub key: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Counts {
+    pub deputes: usize,
+}